@@ -6,8 +6,12 @@ use snafu::ResultExt as _;
 
 use openapi_nexus_parser::OpenApiParser;
 use openapi_nexus_transforms::{
-    TransformPipeline,
-    passes::{NamingConvention, NamingConventionPass, ReferenceResolutionPass, ValidationPass},
+    PipelineReport, TransformPipeline,
+    passes::{
+        DiscriminatorPass, EnumExtractionPass, NamingConvention, NamingConventionPass,
+        OperationIdPass, ReferenceResolutionPass, SchemaDeduplicationPass, SpecFilterPass,
+        TypeInferencePass, UnusedComponentsPass, ValidationPass,
+    },
 };
 
 use crate::error;
@@ -23,20 +27,52 @@ pub struct OpenApiCodeGenerator {
 impl OpenApiCodeGenerator {
     /// Create a new code generator with default configuration
     pub fn new() -> Self {
-        let pipeline = TransformPipeline::new()
-            .add_pass(ValidationPass::new())
-            .add_pass(ReferenceResolutionPass::new())
-            .add_pass(NamingConventionPass {
-                target_case: NamingConvention::CamelCase,
-            });
-
         Self {
-            transform_pipeline: pipeline,
+            transform_pipeline: Self::default_pipeline(),
             generator_registry: GeneratorRegistry::new(),
             language_pipelines: HashMap::new(),
         }
     }
 
+    /// The transformation pipeline applied when no custom one is configured
+    pub fn default_pipeline() -> TransformPipeline {
+        Self::build_pipeline(None)
+    }
+
+    /// The default pipeline with an operation filter applied
+    ///
+    /// The filter runs once operationIds are settled, so synthesized ids do not
+    /// depend on which operations are kept, and before schemas are renamed.
+    pub fn filtered_pipeline(filter: SpecFilterPass) -> TransformPipeline {
+        Self::build_pipeline(Some(filter))
+    }
+
+    fn build_pipeline(filter: Option<SpecFilterPass>) -> TransformPipeline {
+        let mut pipeline = TransformPipeline::new()
+            .add_pass(ValidationPass::new())
+            .add_pass(ReferenceResolutionPass::new())
+            .add_pass(TypeInferencePass::new())
+            .add_pass(EnumExtractionPass::new())
+            .add_pass(OperationIdPass::new())
+            .add_pass(SchemaDeduplicationPass::new())
+            .add_pass(DiscriminatorPass::new())
+            .add_pass(UnusedComponentsPass::new());
+
+        if let Some(filter) = filter {
+            pipeline = pipeline.add_pass(filter);
+        }
+
+        pipeline.add_pass(NamingConventionPass {
+            target_case: NamingConvention::CamelCase,
+        })
+    }
+
+    /// Replace the transformation pipeline used for languages without a custom one
+    pub fn with_transform_pipeline(mut self, pipeline: TransformPipeline) -> Self {
+        self.transform_pipeline = pipeline;
+        self
+    }
+
     /// Register a language generator
     pub fn register_language_generator<G>(
         &mut self,
@@ -66,6 +102,19 @@ impl OpenApiCodeGenerator {
         output_dir: P,
         languages: &[String],
     ) -> Result<(), error::Error> {
+        self.generate_from_file_with_reports(input_path, output_dir, languages)
+            .map(|_| ())
+    }
+
+    /// Generate code from an OpenAPI specification file and report, per
+    /// language, what the transformation pipeline did
+    pub fn generate_from_file_with_reports<P: AsRef<std::path::Path>>(
+        &self,
+        input_path: P,
+        output_dir: P,
+        languages: &[String],
+    ) -> Result<Vec<(String, PipelineReport)>, error::Error> {
+        let mut reports = Vec::new();
         tracing::info!(
             "Parsing OpenAPI specification from: {:?}",
             input_path.as_ref()
@@ -94,9 +143,11 @@ impl OpenApiCodeGenerator {
                 .unwrap_or(&self.transform_pipeline);
 
             tracing::info!("Applying transformations for {}", language);
-            pipeline
-                .transform(&mut language_openapi)
+            let report = pipeline
+                .transform_with_report_for(&mut language_openapi, language)
                 .context(error::TransformSnafu)?;
+            tracing::debug!("Transformation summary for {}:\n{}", language, report);
+            reports.push((language.clone(), report));
 
             // Get the generator and generate files
             let generator = self
@@ -122,7 +173,7 @@ impl OpenApiCodeGenerator {
             );
         }
 
-        Ok(())
+        Ok(reports)
     }
 }
 
@@ -131,3 +182,105 @@ impl Default for OpenApiCodeGenerator {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utoipa::openapi::{RefOr, Schema};
+
+    const PETS_AND_ERRORS: &str = r##"
+openapi: 3.1.0
+info:
+  title: Pets API
+  version: 1.0.0
+paths:
+  /pets/{petId}:
+    put:
+      operationId: updatePet
+      parameters:
+        - name: petId
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        '200':
+          description: Updated pet
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Pet'
+        '404':
+          description: Unknown pet
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/NotFoundError'
+        '409':
+          description: Conflicting update
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/ConflictError'
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: '#/components/schemas/Cat'
+        - $ref: '#/components/schemas/Dog'
+      discriminator:
+        propertyName: kind
+    Cat:
+      type: object
+      required: [kind]
+      properties:
+        kind:
+          type: string
+    Dog:
+      type: object
+      required: [kind]
+      properties:
+        kind:
+          type: string
+    NotFoundError:
+      type: object
+      properties:
+        message:
+          type: string
+    ConflictError:
+      type: object
+      properties:
+        message:
+          type: string
+"##;
+
+    #[test]
+    fn test_default_pipeline_keeps_identical_components() {
+        let mut openapi = OpenApiParser::new()
+            .parse_content(PETS_AND_ERRORS, Some("yaml"))
+            .unwrap()
+            .openapi;
+
+        OpenApiCodeGenerator::default_pipeline()
+            .transform(&mut openapi)
+            .unwrap();
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        // The naming convention pass leaves the components camelCased
+        for name in ["cat", "dog", "notFoundError", "conflictError"] {
+            assert!(schemas.contains_key(name), "{name} was merged");
+        }
+        let Some(RefOr::T(Schema::OneOf(pet))) = schemas.get("pet") else {
+            panic!("expected Pet to stay a oneOf");
+        };
+        assert_eq!(pet.items.len(), 2);
+        let mapping = &pet.discriminator.as_ref().unwrap().mapping;
+        for member in ["/cat", "/dog"] {
+            assert!(
+                mapping
+                    .values()
+                    .any(|target| target.to_lowercase().ends_with(member))
+            );
+        }
+    }
+}
@@ -1,6 +1,10 @@
 //! Analysis utilities for OpenAPI specifications
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
 use utoipa::openapi::request_body::RequestBody;
+use utoipa::openapi::schema::{Discriminator, SchemaType, Type};
 use utoipa::openapi::security::SecurityScheme;
 use utoipa::openapi::{OpenApi, RefOr, Schema, path::Operation};
 
@@ -16,6 +20,101 @@ pub struct CircularRef {
     pub cycle_start: String,
 }
 
+/// Reachability of reusable components from the operations of a specification
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentUsage {
+    /// Schemas transitively referenced by at least one operation
+    pub used_schemas: BTreeSet<String>,
+    /// Responses transitively referenced by at least one operation
+    pub used_responses: BTreeSet<String>,
+    /// Schemas defined in components but never reached from an operation
+    pub unused_schemas: BTreeSet<String>,
+    /// Responses defined in components but never reached from an operation
+    pub unused_responses: BTreeSet<String>,
+}
+
+impl ComponentUsage {
+    /// Whether every component is reachable
+    pub fn is_fully_used(&self) -> bool {
+        self.unused_schemas.is_empty() && self.unused_responses.is_empty()
+    }
+}
+
+/// A `oneOf`/`anyOf` component schema with a discriminator, resolved to a complete mapping
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiscriminatedUnion {
+    /// Name of the union component schema
+    pub schema_name: String,
+    /// Property whose value selects the member
+    pub property_name: String,
+    /// Discriminator value to member schema name, explicit and implicit entries combined
+    pub mapping: BTreeMap<String, String>,
+    /// Problems that keep the union from being decoded reliably
+    pub issues: Vec<DiscriminatorIssue>,
+}
+
+impl DiscriminatedUnion {
+    /// Whether the mapping is complete and every member declares the property correctly
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// A problem found while resolving a discriminator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscriminatorIssue {
+    /// An inline member has no name, so no value can map to it
+    UnmappedMember { index: usize },
+    /// A mapping entry points to a schema that is not a member of the union
+    UnknownTarget { value: String, target: String },
+    /// One value selects several members
+    AmbiguousValue { value: String, members: Vec<String> },
+    /// A member does not declare the discriminator property
+    MissingProperty { member: String },
+    /// A member declares the discriminator property but does not require it
+    OptionalProperty { member: String },
+    /// A member declares the discriminator property with a non-string type
+    NonStringProperty { member: String },
+}
+
+impl fmt::Display for DiscriminatorIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnmappedMember { index } => {
+                write!(f, "inline member #{} cannot be mapped", index)
+            }
+            Self::UnknownTarget { value, target } => {
+                write!(
+                    f,
+                    "value '{}' maps to '{}', which is not a member",
+                    value, target
+                )
+            }
+            Self::AmbiguousValue { value, members } => {
+                write!(
+                    f,
+                    "value '{}' maps to several members: {}",
+                    value,
+                    members.join(", ")
+                )
+            }
+            Self::MissingProperty { member } => {
+                write!(f, "member '{}' does not declare the property", member)
+            }
+            Self::OptionalProperty { member } => {
+                write!(f, "member '{}' does not require the property", member)
+            }
+            Self::NonStringProperty { member } => {
+                write!(
+                    f,
+                    "member '{}' declares the property with a non-string type",
+                    member
+                )
+            }
+        }
+    }
+}
+
 /// Analyze an OpenAPI specification and extract useful information
 pub struct Analyzer;
 
@@ -205,6 +304,283 @@ impl<'a> SchemaAnalyzer<'a> {
         Ok(schemas)
     }
 
+    /// Determine which components are reachable from the operations
+    ///
+    /// References are followed transitively through component schemas and
+    /// responses. utoipa does not model webhooks, so paths are the only roots.
+    pub fn analyze_component_usage(&self) -> ComponentUsage {
+        const SCHEMA_PREFIX: &str = "#/components/schemas/";
+        const RESPONSE_PREFIX: &str = "#/components/responses/";
+
+        let mut usage = ComponentUsage::default();
+        let Some(components) = &self.openapi.components else {
+            return usage;
+        };
+
+        let mut pending: Vec<String> = Utils::collect_ref_locations(&self.openapi.paths)
+            .into_iter()
+            .collect();
+
+        while let Some(location) = pending.pop() {
+            let refs = if let Some(name) = location.strip_prefix(SCHEMA_PREFIX) {
+                match components.schemas.get(name) {
+                    Some(schema) if usage.used_schemas.insert(name.to_string()) => {
+                        Utils::collect_ref_locations(schema)
+                    }
+                    _ => continue,
+                }
+            } else if let Some(name) = location.strip_prefix(RESPONSE_PREFIX) {
+                match components.responses.get(name) {
+                    Some(response) if usage.used_responses.insert(name.to_string()) => {
+                        Utils::collect_ref_locations(response)
+                    }
+                    _ => continue,
+                }
+            } else {
+                continue;
+            };
+            pending.extend(refs);
+        }
+
+        usage.unused_schemas = components
+            .schemas
+            .keys()
+            .filter(|name| !usage.used_schemas.contains(*name))
+            .cloned()
+            .collect();
+        usage.unused_responses = components
+            .responses
+            .keys()
+            .filter(|name| !usage.used_responses.contains(*name))
+            .cloned()
+            .collect();
+
+        usage
+    }
+
+    /// Group component schemas that are structurally identical
+    ///
+    /// Two schemas are identical when their serialized forms are equal. Each
+    /// returned group holds at least two names, sorted alphabetically; groups are
+    /// ordered by their first name.
+    pub fn find_duplicate_schemas(&self) -> Vec<Vec<String>> {
+        let mut groups: Vec<(serde_json::Value, Vec<String>)> = Vec::new();
+
+        for (name, schema) in self.find_all_schemas() {
+            let Ok(value) = serde_json::to_value(schema) else {
+                continue;
+            };
+            match groups.iter_mut().find(|(existing, _)| *existing == value) {
+                Some((_, names)) => names.push(name.clone()),
+                None => groups.push((value, vec![name.clone()])),
+            }
+        }
+
+        let mut duplicates: Vec<Vec<String>> = groups
+            .into_iter()
+            .map(|(_, mut names)| {
+                names.sort();
+                names
+            })
+            .filter(|names| names.len() > 1)
+            .collect();
+        duplicates.sort();
+        duplicates
+    }
+
+    /// Resolve the discriminators of all `oneOf`/`anyOf` component schemas
+    ///
+    /// Members without an explicit mapping entry are mapped implicitly by their
+    /// schema name, as the specification prescribes. Every member must declare the
+    /// discriminator property as a required string, directly or through `allOf`.
+    pub fn analyze_discriminated_unions(&self) -> Vec<DiscriminatedUnion> {
+        self.find_all_schemas()
+            .into_iter()
+            .filter_map(|(name, schema)| {
+                let (items, discriminator) = match schema {
+                    RefOr::T(Schema::OneOf(one_of)) => (&one_of.items, &one_of.discriminator),
+                    RefOr::T(Schema::AnyOf(any_of)) => (&any_of.items, &any_of.discriminator),
+                    _ => return None,
+                };
+                let discriminator = discriminator.as_ref()?;
+                Some(self.resolve_discriminated_union(name, items, discriminator))
+            })
+            .collect()
+    }
+
+    fn resolve_discriminated_union(
+        &self,
+        schema_name: &str,
+        items: &[RefOr<Schema>],
+        discriminator: &Discriminator,
+    ) -> DiscriminatedUnion {
+        const SCHEMA_PREFIX: &str = "#/components/schemas/";
+
+        let mut union = DiscriminatedUnion {
+            schema_name: schema_name.to_string(),
+            property_name: discriminator.property_name.clone(),
+            ..Default::default()
+        };
+
+        let mut members = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            match item {
+                RefOr::Ref(reference) => members.push(
+                    reference
+                        .ref_location
+                        .trim_start_matches(SCHEMA_PREFIX)
+                        .to_string(),
+                ),
+                RefOr::T(_) => union
+                    .issues
+                    .push(DiscriminatorIssue::UnmappedMember { index }),
+            }
+        }
+
+        // Explicit entries may name the target schema or reference it
+        let mut candidates: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for (value, target) in &discriminator.mapping {
+            let target = target.trim_start_matches(SCHEMA_PREFIX).to_string();
+            if members.contains(&target) {
+                candidates.entry(value.clone()).or_default().insert(target);
+            } else {
+                union.issues.push(DiscriminatorIssue::UnknownTarget {
+                    value: value.clone(),
+                    target,
+                });
+            }
+        }
+        let explicitly_mapped: BTreeSet<String> = candidates.values().flatten().cloned().collect();
+        for member in &members {
+            if !explicitly_mapped.contains(member) {
+                candidates
+                    .entry(member.clone())
+                    .or_default()
+                    .insert(member.clone());
+            }
+        }
+
+        for (value, targets) in candidates {
+            if targets.len() > 1 {
+                union.issues.push(DiscriminatorIssue::AmbiguousValue {
+                    value: value.clone(),
+                    members: targets.iter().cloned().collect(),
+                });
+            }
+            // Explicit entries win over implicit ones
+            let target = discriminator
+                .mapping
+                .get(&value)
+                .map(|target| target.trim_start_matches(SCHEMA_PREFIX).to_string())
+                .filter(|target| targets.contains(target))
+                .or_else(|| targets.into_iter().next());
+            if let Some(target) = target {
+                union.mapping.insert(value, target);
+            }
+        }
+
+        for member in &members {
+            let mut visited = BTreeSet::new();
+            let issue = match self.find_property(member, &discriminator.property_name, &mut visited)
+            {
+                None => Some(DiscriminatorIssue::MissingProperty {
+                    member: member.clone(),
+                }),
+                Some((_, false)) => Some(DiscriminatorIssue::OptionalProperty {
+                    member: member.clone(),
+                }),
+                Some((property, true)) if !self.is_string_schema(property) => {
+                    Some(DiscriminatorIssue::NonStringProperty {
+                        member: member.clone(),
+                    })
+                }
+                Some(_) => None,
+            };
+            union.issues.extend(issue);
+        }
+
+        union
+    }
+
+    /// Find a property of a component schema, looking through `allOf` parts
+    ///
+    /// Returns the property schema and whether any part requires it.
+    fn find_property(
+        &self,
+        schema_name: &str,
+        property: &str,
+        visited: &mut BTreeSet<String>,
+    ) -> Option<(&'a RefOr<Schema>, bool)> {
+        if !visited.insert(schema_name.to_string()) {
+            return None;
+        }
+        let schema = self.openapi.components.as_ref()?.schemas.get(schema_name)?;
+        self.find_property_in(schema, property, visited)
+    }
+
+    fn find_property_in(
+        &self,
+        schema: &'a RefOr<Schema>,
+        property: &str,
+        visited: &mut BTreeSet<String>,
+    ) -> Option<(&'a RefOr<Schema>, bool)> {
+        match schema {
+            RefOr::Ref(reference) => self.find_property(
+                reference
+                    .ref_location
+                    .trim_start_matches("#/components/schemas/"),
+                property,
+                visited,
+            ),
+            RefOr::T(Schema::Object(obj)) => obj
+                .properties
+                .get(property)
+                .map(|schema| (schema, obj.required.iter().any(|name| name == property))),
+            RefOr::T(Schema::AllOf(all_of)) => {
+                let found: Vec<_> = all_of
+                    .items
+                    .iter()
+                    .filter_map(|item| self.find_property_in(item, property, visited))
+                    .collect();
+                let (schema, _) = found.first()?;
+                Some((schema, found.iter().any(|(_, required)| *required)))
+            }
+            RefOr::T(_) => None,
+        }
+    }
+
+    /// Whether a schema is a string, following references
+    fn is_string_schema(&self, schema: &RefOr<Schema>) -> bool {
+        let mut schema = schema;
+        let mut visited = BTreeSet::new();
+        loop {
+            match schema {
+                RefOr::Ref(reference) => {
+                    let name = reference
+                        .ref_location
+                        .trim_start_matches("#/components/schemas/");
+                    let target = self
+                        .openapi
+                        .components
+                        .as_ref()
+                        .and_then(|components| components.schemas.get(name));
+                    match target {
+                        Some(target) if visited.insert(name) => schema = target,
+                        _ => return false,
+                    }
+                }
+                RefOr::T(Schema::Object(obj)) => {
+                    return match &obj.schema_type {
+                        SchemaType::Type(ty) => *ty == Type::String,
+                        SchemaType::Array(types) => types.contains(&Type::String),
+                        SchemaType::AnyValue => false,
+                    };
+                }
+                RefOr::T(_) => return false,
+            }
+        }
+    }
+
     /// Analyze dependencies for a specific schema
     pub fn analyze_schema_dependencies(&self, schema_name: &str) -> Result<Vec<String>, IrError> {
         let mut dependencies = Vec::new();
@@ -670,4 +1046,214 @@ mod tests {
         let circular_refs = analyzer.detect_circular_references().unwrap();
         assert_eq!(circular_refs.len(), 2);
     }
+
+    #[test]
+    fn test_analyze_component_usage() {
+        use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem};
+        use utoipa::openapi::{ContentBuilder, Ref, ResponseBuilder};
+
+        let mut openapi = create_test_openapi();
+        let components = openapi.components.as_mut().unwrap();
+        let mut pet = Object::new();
+        pet.properties.insert(
+            "tags".to_string(),
+            RefOr::T(Schema::Array(utoipa::openapi::schema::Array::new(
+                Ref::from_schema_name("Tag"),
+            ))),
+        );
+        components
+            .schemas
+            .insert("Pet".to_string(), RefOr::T(Schema::Object(pet)));
+        components
+            .schemas
+            .insert("Tag".to_string(), RefOr::T(Schema::Object(Object::new())));
+
+        let response = ResponseBuilder::new()
+            .description("A pet")
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(Ref::from_schema_name("Pet")))
+                    .build(),
+            )
+            .build();
+        let operation = OperationBuilder::new().response("200", response).build();
+        openapi.paths.paths.insert(
+            "/pets".to_string(),
+            PathItem::new(HttpMethod::Get, operation),
+        );
+
+        let usage = SchemaAnalyzer::new(&openapi).analyze_component_usage();
+
+        assert_eq!(
+            usage.used_schemas.iter().collect::<Vec<_>>(),
+            vec!["Pet", "Tag"]
+        );
+        assert_eq!(
+            usage.unused_schemas.iter().collect::<Vec<_>>(),
+            vec!["User"]
+        );
+        assert!(!usage.is_fully_used());
+    }
+
+    #[test]
+    fn test_find_duplicate_schemas() {
+        let mut openapi = create_test_openapi();
+        let components = openapi.components.as_mut().unwrap();
+        let mut error = Object::new();
+        error.properties.insert(
+            "message".to_string(),
+            RefOr::T(Schema::Object(Object::with_type(
+                utoipa::openapi::schema::Type::String,
+            ))),
+        );
+        for name in ["NotFound", "BadRequest"] {
+            components
+                .schemas
+                .insert(name.to_string(), RefOr::T(Schema::Object(error.clone())));
+        }
+
+        let duplicates = SchemaAnalyzer::new(&openapi).find_duplicate_schemas();
+
+        assert_eq!(duplicates, vec![vec!["BadRequest", "NotFound"]]);
+    }
+
+    #[test]
+    fn test_analyze_discriminated_unions() {
+        use utoipa::openapi::Ref;
+        use utoipa::openapi::schema::{AllOfBuilder, ObjectBuilder, OneOfBuilder};
+
+        let pet_type = |ty: Type, required: bool| {
+            let builder = ObjectBuilder::new().property("petType", Object::with_type(ty));
+            let builder = if required {
+                builder.required("petType")
+            } else {
+                builder
+            };
+            RefOr::T(Schema::Object(builder.build()))
+        };
+
+        let mut openapi = create_test_openapi();
+        let components = openapi.components.as_mut().unwrap();
+        components
+            .schemas
+            .insert("Base".to_string(), pet_type(Type::String, true));
+        components
+            .schemas
+            .insert("Dog".to_string(), pet_type(Type::String, true));
+        components.schemas.insert(
+            "Cat".to_string(),
+            RefOr::T(Schema::AllOf(
+                AllOfBuilder::new()
+                    .item(Ref::from_schema_name("Base"))
+                    .item(Object::new())
+                    .build(),
+            )),
+        );
+        components
+            .schemas
+            .insert("Lizard".to_string(), pet_type(Type::Integer, true));
+        components
+            .schemas
+            .insert("Fish".to_string(), pet_type(Type::String, false));
+
+        let mut discriminator = Discriminator::new("petType");
+        discriminator
+            .mapping
+            .insert("dog".to_string(), "#/components/schemas/Dog".to_string());
+        discriminator
+            .mapping
+            .insert("bird".to_string(), "Bird".to_string());
+        components.schemas.insert(
+            "Pet".to_string(),
+            RefOr::T(Schema::OneOf(
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("Dog"))
+                    .item(Ref::from_schema_name("Cat"))
+                    .item(Ref::from_schema_name("Lizard"))
+                    .item(Ref::from_schema_name("Fish"))
+                    .item(Object::new())
+                    .discriminator(Some(discriminator))
+                    .build(),
+            )),
+        );
+
+        let unions = SchemaAnalyzer::new(&openapi).analyze_discriminated_unions();
+
+        assert_eq!(unions.len(), 1);
+        let union = &unions[0];
+        assert_eq!(union.schema_name, "Pet");
+        assert_eq!(union.property_name, "petType");
+        assert_eq!(
+            union.mapping,
+            BTreeMap::from([
+                ("Cat".to_string(), "Cat".to_string()),
+                ("Fish".to_string(), "Fish".to_string()),
+                ("Lizard".to_string(), "Lizard".to_string()),
+                ("dog".to_string(), "Dog".to_string()),
+            ])
+        );
+        assert_eq!(
+            union.issues,
+            vec![
+                DiscriminatorIssue::UnmappedMember { index: 4 },
+                DiscriminatorIssue::UnknownTarget {
+                    value: "bird".to_string(),
+                    target: "Bird".to_string(),
+                },
+                DiscriminatorIssue::NonStringProperty {
+                    member: "Lizard".to_string(),
+                },
+                DiscriminatorIssue::OptionalProperty {
+                    member: "Fish".to_string(),
+                },
+            ]
+        );
+        assert!(!union.is_valid());
+    }
+
+    #[test]
+    fn test_discriminated_union_ambiguous_value() {
+        use utoipa::openapi::Ref;
+        use utoipa::openapi::schema::{ObjectBuilder, OneOfBuilder};
+
+        let mut openapi = create_test_openapi();
+        let components = openapi.components.as_mut().unwrap();
+        for name in ["Cat", "Dog"] {
+            components.schemas.insert(
+                name.to_string(),
+                RefOr::T(Schema::Object(
+                    ObjectBuilder::new()
+                        .property("kind", Object::with_type(Type::String))
+                        .required("kind")
+                        .build(),
+                )),
+            );
+        }
+        let mut discriminator = Discriminator::new("kind");
+        discriminator
+            .mapping
+            .insert("Cat".to_string(), "Dog".to_string());
+        components.schemas.insert(
+            "Pet".to_string(),
+            RefOr::T(Schema::OneOf(
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("Cat"))
+                    .item(Ref::from_schema_name("Dog"))
+                    .discriminator(Some(discriminator))
+                    .build(),
+            )),
+        );
+
+        let unions = SchemaAnalyzer::new(&openapi).analyze_discriminated_unions();
+
+        assert_eq!(unions[0].mapping["Cat"], "Dog");
+        assert_eq!(
+            unions[0].issues,
+            vec![DiscriminatorIssue::AmbiguousValue {
+                value: "Cat".to_string(),
+                members: vec!["Cat".to_string(), "Dog".to_string()],
+            }]
+        );
+    }
 }
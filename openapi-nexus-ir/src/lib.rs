@@ -53,7 +53,11 @@ pub use utoipa::openapi::{
 };
 
 // Re-export IR types
-pub use analysis::{Analyzer, CircularRef, SchemaAnalyzer};
+pub use analysis::{
+    Analyzer, CircularRef, ComponentUsage, DiscriminatedUnion, DiscriminatorIssue, SchemaAnalyzer,
+};
 pub use error::IrError;
-pub use traversal::{OpenApiTraverser, OpenApiVisitor};
+pub use traversal::{
+    OpenApiTraverser, OpenApiVisitor, escape_pointer_token, operation_slots_mut, operations_mut,
+};
 pub use utils::{ReferenceResolver, Utils};
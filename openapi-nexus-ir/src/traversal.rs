@@ -1,8 +1,9 @@
 //! Traversal utilities for OpenAPI specifications
 
 use utoipa::openapi::{
-    OpenApi, Paths, RefOr, Response, Schema,
+    OpenApi, PathItem, Paths, RefOr, Response, Schema,
     path::{Operation, Parameter},
+    schema::{AdditionalProperties, ArrayItems},
 };
 
 /// Visitor pattern for traversing OpenAPI specifications
@@ -107,6 +108,178 @@ impl OpenApiTraverser {
 
         Ok(())
     }
+
+    /// Walk every schema slot of an OpenAPI specification mutably
+    ///
+    /// The callback receives the JSON pointer of each slot together with the slot
+    /// itself before the walker descends into it, so callbacks may rewrite a slot
+    /// (e.g. replace an inline schema by a reference). References are reported but
+    /// never followed.
+    pub fn walk_schemas_mut<F>(openapi: &mut OpenApi, mut f: F)
+    where
+        F: FnMut(&str, &mut RefOr<Schema>),
+    {
+        if let Some(components) = openapi.components.as_mut() {
+            for (name, schema) in components.schemas.iter_mut() {
+                let pointer = format!("#/components/schemas/{}", escape_pointer_token(name));
+                walk_schema_ref_mut(&pointer, schema, &mut f);
+            }
+
+            for (name, response) in components.responses.iter_mut() {
+                if let RefOr::T(response) = response {
+                    let pointer = format!("#/components/responses/{}", escape_pointer_token(name));
+                    walk_response_mut(&pointer, response, &mut f);
+                }
+            }
+        }
+
+        for (path, path_item) in openapi.paths.paths.iter_mut() {
+            let pointer = format!("#/paths/{}", escape_pointer_token(path));
+
+            if let Some(parameters) = path_item.parameters.as_mut() {
+                walk_parameters_mut(&format!("{pointer}/parameters"), parameters, &mut f);
+            }
+
+            for (method, operation) in operations_mut(path_item) {
+                walk_operation_mut(&format!("{pointer}/{method}"), operation, &mut f);
+            }
+        }
+    }
+}
+
+/// Mutable operation slots of a path item together with their lowercase HTTP method
+pub fn operation_slots_mut(
+    path_item: &mut PathItem,
+) -> [(&'static str, &mut Option<Operation>); 8] {
+    [
+        ("get", &mut path_item.get),
+        ("put", &mut path_item.put),
+        ("post", &mut path_item.post),
+        ("delete", &mut path_item.delete),
+        ("options", &mut path_item.options),
+        ("head", &mut path_item.head),
+        ("patch", &mut path_item.patch),
+        ("trace", &mut path_item.trace),
+    ]
+}
+
+/// Iterate over the operations of a path item together with their lowercase HTTP method
+pub fn operations_mut(
+    path_item: &mut PathItem,
+) -> impl Iterator<Item = (&'static str, &mut Operation)> {
+    operation_slots_mut(path_item)
+        .into_iter()
+        .filter_map(|(method, operation)| operation.as_mut().map(|operation| (method, operation)))
+}
+
+/// Escape a single JSON pointer token (RFC 6901)
+pub fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn walk_operation_mut<F>(pointer: &str, operation: &mut Operation, f: &mut F)
+where
+    F: FnMut(&str, &mut RefOr<Schema>),
+{
+    if let Some(parameters) = operation.parameters.as_mut() {
+        walk_parameters_mut(&format!("{pointer}/parameters"), parameters, f);
+    }
+
+    if let Some(request_body) = operation.request_body.as_mut() {
+        for (media_type, content) in request_body.content.iter_mut() {
+            if let Some(schema) = content.schema.as_mut() {
+                let pointer = format!(
+                    "{pointer}/requestBody/content/{}/schema",
+                    escape_pointer_token(media_type)
+                );
+                walk_schema_ref_mut(&pointer, schema, f);
+            }
+        }
+    }
+
+    for (status, response) in operation.responses.responses.iter_mut() {
+        if let RefOr::T(response) = response {
+            let pointer = format!("{pointer}/responses/{}", escape_pointer_token(status));
+            walk_response_mut(&pointer, response, f);
+        }
+    }
+}
+
+fn walk_parameters_mut<F>(pointer: &str, parameters: &mut [Parameter], f: &mut F)
+where
+    F: FnMut(&str, &mut RefOr<Schema>),
+{
+    for (index, parameter) in parameters.iter_mut().enumerate() {
+        if let Some(schema) = parameter.schema.as_mut() {
+            walk_schema_ref_mut(&format!("{pointer}/{index}/schema"), schema, f);
+        }
+    }
+}
+
+fn walk_response_mut<F>(pointer: &str, response: &mut Response, f: &mut F)
+where
+    F: FnMut(&str, &mut RefOr<Schema>),
+{
+    for (name, header) in response.headers.iter_mut() {
+        let pointer = format!("{pointer}/headers/{}/schema", escape_pointer_token(name));
+        walk_schema_ref_mut(&pointer, &mut header.schema, f);
+    }
+
+    for (media_type, content) in response.content.iter_mut() {
+        if let Some(schema) = content.schema.as_mut() {
+            let pointer = format!(
+                "{pointer}/content/{}/schema",
+                escape_pointer_token(media_type)
+            );
+            walk_schema_ref_mut(&pointer, schema, f);
+        }
+    }
+}
+
+fn walk_schema_ref_mut<F>(pointer: &str, schema_ref: &mut RefOr<Schema>, f: &mut F)
+where
+    F: FnMut(&str, &mut RefOr<Schema>),
+{
+    f(pointer, schema_ref);
+
+    let RefOr::T(schema) = schema_ref else {
+        return;
+    };
+
+    match schema {
+        Schema::Object(object) => {
+            for (name, property) in object.properties.iter_mut() {
+                let pointer = format!("{pointer}/properties/{}", escape_pointer_token(name));
+                walk_schema_ref_mut(&pointer, property, f);
+            }
+            if let Some(additional) = object.additional_properties.as_deref_mut()
+                && let AdditionalProperties::RefOr(additional) = additional
+            {
+                walk_schema_ref_mut(&format!("{pointer}/additionalProperties"), additional, f);
+            }
+        }
+        Schema::Array(array) => {
+            if let ArrayItems::RefOrSchema(items) = &mut array.items {
+                walk_schema_ref_mut(&format!("{pointer}/items"), items.as_mut(), f);
+            }
+        }
+        Schema::OneOf(one_of) => {
+            for (index, item) in one_of.items.iter_mut().enumerate() {
+                walk_schema_ref_mut(&format!("{pointer}/oneOf/{index}"), item, f);
+            }
+        }
+        Schema::AllOf(all_of) => {
+            for (index, item) in all_of.items.iter_mut().enumerate() {
+                walk_schema_ref_mut(&format!("{pointer}/allOf/{index}"), item, f);
+            }
+        }
+        Schema::AnyOf(any_of) => {
+            for (index, item) in any_of.items.iter_mut().enumerate() {
+                walk_schema_ref_mut(&format!("{pointer}/anyOf/{index}"), item, f);
+            }
+        }
+        _ => {}
+    }
 }
 
 #[cfg(test)]
@@ -208,4 +381,43 @@ mod tests {
             panic!("Expected AnalysisError");
         }
     }
+
+    #[test]
+    fn test_walk_schemas_mut_reports_pointers() {
+        let mut openapi = create_test_openapi();
+        let mut pet = Object::new();
+        pet.properties.insert(
+            "tags".to_string(),
+            RefOr::T(Schema::Array(utoipa::openapi::schema::Array::new(
+                Object::new(),
+            ))),
+        );
+        openapi
+            .components
+            .as_mut()
+            .unwrap()
+            .schemas
+            .insert("Pet".to_string(), RefOr::T(Schema::Object(pet)));
+
+        let mut pointers = Vec::new();
+        OpenApiTraverser::walk_schemas_mut(&mut openapi, |pointer, _schema| {
+            pointers.push(pointer.to_string());
+        });
+
+        assert_eq!(
+            pointers,
+            vec![
+                "#/components/schemas/Pet",
+                "#/components/schemas/Pet/properties/tags",
+                "#/components/schemas/Pet/properties/tags/items",
+                "#/components/schemas/User",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escape_pointer_token() {
+        assert_eq!(escape_pointer_token("/pets/{id}"), "~1pets~1{id}");
+        assert_eq!(escape_pointer_token("a~b"), "a~0b");
+    }
 }
@@ -1,5 +1,7 @@
 //! Utility functions for working with OpenAPI specifications
 
+use std::collections::BTreeSet;
+
 use utoipa::openapi::{OpenApi, RefOr, Response, Schema, path::Parameter};
 
 use crate::error::IrError;
@@ -45,6 +47,41 @@ impl Utils {
 
         refs
     }
+
+    /// Collect every `$ref` location found anywhere in a serializable OpenAPI node
+    ///
+    /// Unlike [`Utils::extract_schema_refs`] this walks the serialized form, so it
+    /// sees references nested in array items, compositions, parameters and
+    /// responses alike.
+    pub fn collect_ref_locations<T: serde::Serialize>(node: &T) -> BTreeSet<String> {
+        fn walk(value: &serde_json::Value, refs: &mut BTreeSet<String>) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    for (key, value) in map {
+                        match value {
+                            serde_json::Value::String(location) if key == "$ref" => {
+                                refs.insert(location.clone());
+                            }
+                            _ => walk(value, refs),
+                        }
+                    }
+                }
+                serde_json::Value::Array(values) => {
+                    for value in values {
+                        walk(value, refs);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut refs = BTreeSet::new();
+        // utoipa types always serialize; a failure simply yields no references
+        if let Ok(value) = serde_json::to_value(node) {
+            walk(&value, &mut refs);
+        }
+        refs
+    }
 }
 
 /// Resolves OpenAPI references ($ref) within a specification
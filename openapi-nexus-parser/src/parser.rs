@@ -108,11 +108,111 @@ impl OpenApiParser {
     }
 
     fn parse_json(&self, content: &str) -> Result<OpenApi, Error> {
-        serde_json::from_str(content).context(JsonParseSnafu)
+        let mut document = serde_json::from_str(content).context(JsonParseSnafu)?;
+        Self::rewrite_document(&mut document);
+        serde_json::from_value(document).context(JsonParseSnafu)
     }
 
     fn parse_yaml(&self, content: &str) -> Result<OpenApi, Error> {
-        serde_norway::from_str(content).context(YamlParseSnafu)
+        let mut document = serde_norway::from_str(content).context(YamlParseSnafu)?;
+        Self::rewrite_document(&mut document);
+        let document = serde_norway::to_value(document).context(YamlParseSnafu)?;
+        serde_norway::from_value(document).context(YamlParseSnafu)
+    }
+
+    /// Rewrite the keywords of a parsed document the OpenAPI model has no
+    /// field for, before building the model from it
+    fn rewrite_document(document: &mut serde_json::Value) {
+        Self::rename_item_schemas(document);
+        Self::type_array_schemas(document);
+    }
+
+    /// Keep the `itemSchema` of media types, describing each item of a
+    /// sequential media type such as `text/event-stream`, as their
+    /// `x-itemSchema` extension since the OpenAPI model has no field for it
+    ///
+    /// Only the media types of request bodies and responses are rewritten, in
+    /// operations and in components.
+    fn rename_item_schemas(document: &mut serde_json::Value) {
+        if let Some(paths) = document
+            .get_mut("paths")
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            for operation in paths
+                .values_mut()
+                .filter_map(serde_json::Value::as_object_mut)
+                .flat_map(|path_item| path_item.values_mut())
+                .filter_map(serde_json::Value::as_object_mut)
+            {
+                if let Some(request_body) = operation.get_mut("requestBody") {
+                    Self::rename_body_item_schemas(request_body);
+                }
+                if let Some(responses) = operation
+                    .get_mut("responses")
+                    .and_then(serde_json::Value::as_object_mut)
+                {
+                    responses
+                        .values_mut()
+                        .for_each(Self::rename_body_item_schemas);
+                }
+            }
+        }
+
+        if let Some(components) = document
+            .get_mut("components")
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            for key in ["requestBodies", "responses"] {
+                if let Some(bodies) = components
+                    .get_mut(key)
+                    .and_then(serde_json::Value::as_object_mut)
+                {
+                    bodies.values_mut().for_each(Self::rename_body_item_schemas);
+                }
+            }
+        }
+    }
+
+    /// Rename `itemSchema` in the media types of a request body or response
+    fn rename_body_item_schemas(body: &mut serde_json::Value) {
+        let Some(content) = body
+            .get_mut("content")
+            .and_then(serde_json::Value::as_object_mut)
+        else {
+            return;
+        };
+        for media_type in content
+            .values_mut()
+            .filter_map(serde_json::Value::as_object_mut)
+        {
+            if let Some(item_schema) = media_type.remove("itemSchema") {
+                media_type.insert("x-itemSchema".to_string(), item_schema);
+            }
+        }
+    }
+
+    /// Type the schemas declaring `items` without a `type` as arrays
+    ///
+    /// The OpenAPI model reads a typeless schema as an object and drops its
+    /// `items`, losing the only hint of its type before type inference runs.
+    /// The schemas of operations, callbacks and components are rewritten,
+    /// along with those of their parameters and of the headers of responses.
+    fn type_array_schemas(document: &mut serde_json::Value) {
+        let Some(document) = document.as_object_mut() else {
+            return;
+        };
+        let mut schemas = Vec::new();
+        for (key, value) in document.iter_mut() {
+            match key.as_str() {
+                "paths" => schemas.extend(object_values(value).flat_map(path_item_schemas)),
+                "components" => schemas.extend(component_schemas(value)),
+                _ => {}
+            }
+        }
+
+        for schema in schemas {
+            type_array_schema(schema);
+        }
     }
 
     /// Validate an OpenAPI specification
@@ -222,3 +322,170 @@ impl Default for OpenApiParser {
         Self::new()
     }
 }
+
+/// Values of a JSON object, none for other values
+fn object_values(value: &mut serde_json::Value) -> impl Iterator<Item = &mut serde_json::Value> {
+    value
+        .as_object_mut()
+        .into_iter()
+        .flat_map(|map| map.values_mut())
+}
+
+/// Schemas of a list of parameters
+fn parameter_schemas(parameters: &mut serde_json::Value) -> Vec<&mut serde_json::Value> {
+    parameters
+        .as_array_mut()
+        .into_iter()
+        .flatten()
+        .filter_map(|parameter| parameter.get_mut("schema"))
+        .collect()
+}
+
+/// Schemas of the media types and headers of a request body or response
+fn body_schemas(body: &mut serde_json::Value) -> Vec<&mut serde_json::Value> {
+    let Some(body) = body.as_object_mut() else {
+        return Vec::new();
+    };
+    let mut schemas = Vec::new();
+    for (key, value) in body.iter_mut() {
+        match key.as_str() {
+            "content" => {
+                for media_type in object_values(value).filter_map(serde_json::Value::as_object_mut)
+                {
+                    for (key, value) in media_type.iter_mut() {
+                        if matches!(key.as_str(), "schema" | "x-itemSchema") {
+                            schemas.push(value);
+                        }
+                    }
+                }
+            }
+            "headers" => {
+                schemas.extend(object_values(value).filter_map(|header| header.get_mut("schema")))
+            }
+            _ => {}
+        }
+    }
+    schemas
+}
+
+/// Type a schema and its subschemas as arrays when they declare `items`
+/// without a `type`
+fn type_array_schema(schema: &mut serde_json::Value) {
+    let Some(schema) = schema.as_object_mut() else {
+        return;
+    };
+    if schema.contains_key("items") && !schema.contains_key("type") && !schema.contains_key("$ref")
+    {
+        schema.insert("type".to_string(), serde_json::json!("array"));
+    }
+
+    for (key, value) in schema.iter_mut() {
+        match key.as_str() {
+            "items"
+            | "additionalProperties"
+            | "not"
+            | "contains"
+            | "propertyNames"
+            | "if"
+            | "then"
+            | "else" => type_array_schema(value),
+            "properties" | "patternProperties" | "$defs" | "dependentSchemas" => {
+                object_values(value).for_each(type_array_schema)
+            }
+            "allOf" | "oneOf" | "anyOf" | "prefixItems" => value
+                .as_array_mut()
+                .into_iter()
+                .flatten()
+                .for_each(type_array_schema),
+            _ => {}
+        }
+    }
+}
+
+/// Schemas of the parameters, request bodies and responses of a path item,
+/// and those of the path items of its callbacks
+fn path_item_schemas(path_item: &mut serde_json::Value) -> Vec<&mut serde_json::Value> {
+    let mut schemas = Vec::new();
+    for (key, value) in path_item.as_object_mut().into_iter().flatten() {
+        if key == "parameters" {
+            schemas.extend(parameter_schemas(value));
+        } else if let Some(operation) = value.as_object_mut() {
+            for (key, value) in operation.iter_mut() {
+                match key.as_str() {
+                    "parameters" => schemas.extend(parameter_schemas(value)),
+                    "requestBody" => schemas.extend(body_schemas(value)),
+                    "responses" => schemas.extend(object_values(value).flat_map(body_schemas)),
+                    "callbacks" => schemas.extend(callback_schemas(value)),
+                    _ => {}
+                }
+            }
+        }
+    }
+    schemas
+}
+
+/// Schemas of the path items of a map of callbacks
+fn callback_schemas(callbacks: &mut serde_json::Value) -> Vec<&mut serde_json::Value> {
+    object_values(callbacks)
+        .flat_map(object_values)
+        .flat_map(path_item_schemas)
+        .collect()
+}
+
+/// Schemas of the components, and those of the component parameters, headers,
+/// request bodies, responses and callbacks
+fn component_schemas(components: &mut serde_json::Value) -> Vec<&mut serde_json::Value> {
+    let mut schemas = Vec::new();
+    for (key, value) in components.as_object_mut().into_iter().flatten() {
+        match key.as_str() {
+            "schemas" => schemas.extend(object_values(value)),
+            "parameters" | "headers" => schemas
+                .extend(object_values(value).filter_map(|parameter| parameter.get_mut("schema"))),
+            "requestBodies" | "responses" => {
+                schemas.extend(object_values(value).flat_map(body_schemas))
+            }
+            "callbacks" => schemas.extend(callback_schemas(value)),
+            _ => {}
+        }
+    }
+    schemas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_array_schemas_in_callbacks() {
+        let body = serde_json::json!({
+            "content": { "application/json": { "schema": { "items": { "type": "string" } } } },
+            "headers": { "X-Ids": { "schema": { "items": { "type": "integer" } } } }
+        });
+        let callbacks = serde_json::json!({
+            "done": { "{$request.body#/url}": { "post": {
+                "requestBody": body,
+                "responses": { "200": body }
+            } } }
+        });
+        let mut document = serde_json::json!({
+            "paths": { "/jobs": { "post": { "callbacks": callbacks } } },
+            "components": { "callbacks": callbacks }
+        });
+
+        OpenApiParser::type_array_schemas(&mut document);
+
+        for callbacks in [
+            &document["paths"]["/jobs"]["post"]["callbacks"],
+            &document["components"]["callbacks"],
+        ] {
+            let operation = &callbacks["done"]["{$request.body#/url}"]["post"];
+            for body in [&operation["requestBody"], &operation["responses"]["200"]] {
+                assert_eq!(
+                    body["content"]["application/json"]["schema"]["type"],
+                    "array"
+                );
+                assert_eq!(body["headers"]["X-Ids"]["schema"]["type"], "array");
+            }
+        }
+    }
+}
@@ -190,6 +190,160 @@ fn test_parse_content_json() {
     assert_eq!(parse_result.openapi.info.title, "Test API");
 }
 
+#[test]
+fn test_parse_content_item_schema() {
+    let content = r#"
+openapi: 3.1.0
+info:
+  title: Test API
+  version: 1.0.0
+paths:
+  /events:
+    get:
+      responses:
+        '200':
+          description: OK
+          content:
+            text/event-stream:
+              itemSchema:
+                type: string
+"#;
+
+    let parser = OpenApiParser::new();
+    let parse_result = parser.parse_content(content, Some("yaml")).unwrap();
+
+    let operation = parse_result.openapi.paths.paths["/events"]
+        .get
+        .as_ref()
+        .unwrap();
+    let utoipa::openapi::RefOr::T(response) = &operation.responses.responses["200"] else {
+        panic!("Expected an inline response");
+    };
+    let extensions = response.content["text/event-stream"]
+        .extensions
+        .as_ref()
+        .unwrap();
+    assert_eq!(
+        extensions.get("x-itemSchema"),
+        Some(&serde_json::json!({ "type": "string" }))
+    );
+}
+
+#[test]
+fn test_parse_content_item_schema_only_in_media_types() {
+    let content = r#"
+openapi: 3.1.0
+info:
+  title: Test API
+  version: 1.0.0
+paths:
+  /posts:
+    get:
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Post'
+components:
+  schemas:
+    Post:
+      type: object
+      properties:
+        content:
+          type: object
+          properties:
+            itemSchema:
+              type: string
+      example:
+        content:
+          text/plain:
+            itemSchema: kept
+"#;
+
+    let parser = OpenApiParser::new();
+    let parse_result = parser.parse_content(content, Some("yaml")).unwrap();
+
+    let schema =
+        serde_json::to_value(&parse_result.openapi.components.as_ref().unwrap().schemas["Post"])
+            .unwrap();
+    assert_eq!(
+        schema["properties"]["content"]["properties"]["itemSchema"],
+        serde_json::json!({ "type": "string" })
+    );
+    assert_eq!(
+        schema["example"]["content"]["text/plain"]["itemSchema"],
+        serde_json::json!("kept")
+    );
+}
+
+#[test]
+fn test_parse_content_typeless_items() {
+    let content = r#"
+openapi: 3.1.0
+info:
+  title: Test API
+  version: 1.0.0
+paths:
+  /tags:
+    get:
+      parameters:
+        - name: ids
+          in: query
+          required: false
+          schema:
+            items:
+              type: integer
+      responses:
+        '200':
+          description: OK
+          headers:
+            X-Tag-Ids:
+              schema:
+                items:
+                  type: string
+          content:
+            application/json:
+              schema:
+                items:
+                  $ref: '#/components/schemas/Tag'
+components:
+  schemas:
+    Tag:
+      type: object
+      properties:
+        aliases:
+          items:
+            type: string
+"#;
+
+    let parser = OpenApiParser::new();
+    let parse_result = parser.parse_content(content, Some("yaml")).unwrap();
+    let openapi = serde_json::to_value(&parse_result.openapi).unwrap();
+
+    let operation = &openapi["paths"]["/tags"]["get"];
+    assert_eq!(
+        operation["parameters"][0]["schema"],
+        serde_json::json!({ "type": "array", "items": { "type": "integer" } })
+    );
+    assert_eq!(
+        operation["responses"]["200"]["content"]["application/json"]["schema"],
+        serde_json::json!({
+            "type": "array",
+            "items": { "$ref": "#/components/schemas/Tag" }
+        })
+    );
+    assert_eq!(
+        operation["responses"]["200"]["headers"]["X-Tag-Ids"]["schema"],
+        serde_json::json!({ "type": "array", "items": { "type": "string" } })
+    );
+    assert_eq!(
+        openapi["components"]["schemas"]["Tag"]["properties"]["aliases"],
+        serde_json::json!({ "type": "array", "items": { "type": "string" } })
+    );
+}
+
 #[test]
 fn test_parse_content_auto_detect_yaml() {
     let content = r#"
@@ -8,6 +8,7 @@ use axum::{
     response::Json,
 };
 use serde::Deserialize;
+use utoipa::ToSchema;
 
 use crate::models::{
     ApiResponse, Category, ErrorResponse, Order, OrderStatus, Pet, PetStatus, Tag, User,
@@ -19,6 +20,14 @@ pub struct FindPetsByStatusQuery {
     pub status: String,
 }
 
+/// Multipart form for uploading a pet image
+#[derive(Debug, ToSchema)]
+pub struct UploadFileForm {
+    /// Image to upload
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
 /// Query parameters for finding pets by tags
 #[derive(Debug, Deserialize)]
 pub struct FindPetsByTagsQuery {
@@ -67,7 +76,7 @@ pub async fn update_pet(
     responses(
         (status = 200, description = "Successful operation", body = Pet),
         (status = 400, description = "Invalid input"),
-        (status = 422, description = "Validation exception")
+        (status = 422, description = "Validation exception", body = ErrorResponse)
     ),
     tag = "pet"
 )]
@@ -154,7 +163,7 @@ pub async fn find_pets_by_tags(
     responses(
         (status = 200, description = "successful operation", body = Pet),
         (status = 400, description = "Invalid ID supplied"),
-        (status = 404, description = "Pet not found")
+        (status = 404, description = "Pet not found", body = ErrorResponse)
     ),
     tag = "pet"
 )]
@@ -244,6 +253,7 @@ pub async fn delete_pet(
         ("petId" = i64, Path, description = "ID of pet to update"),
         ("additionalMetadata" = Option<String>, Query, description = "Additional Metadata")
     ),
+    request_body(content = inline(UploadFileForm), content_type = "multipart/form-data"),
     responses(
         (status = 200, description = "successful operation", body = ApiResponse)
     ),
@@ -0,0 +1,210 @@
+//! Instrumentation of transformation pipeline runs
+//!
+//! Records how long each pass takes and, optionally, which parts of the
+//! specification it changed, so slow or surprising passes can be pinpointed.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use openapi_nexus_ir::escape_pointer_token;
+use serde_json::Value;
+
+/// Instrumentation options for a pipeline run
+#[derive(Debug, Clone, Default)]
+pub struct Instrumentation {
+    /// Compute the structural changes made by each pass
+    pub diff: bool,
+    /// Write the specification to this directory before the first and after each pass
+    pub snapshot_dir: Option<PathBuf>,
+}
+
+impl Instrumentation {
+    /// Whether the specification has to be serialized around each pass
+    pub(crate) fn needs_serialization(&self) -> bool {
+        self.diff || self.snapshot_dir.is_some()
+    }
+}
+
+/// Structural difference between two versions of a specification
+///
+/// Entries are JSON pointers into the serialized specification. Whole subtrees
+/// that were added or removed are reported once, at their root.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl SpecDiff {
+    /// Compute the difference between two serialized specifications
+    pub fn between(before: &Value, after: &Value) -> Self {
+        let mut diff = Self::default();
+        diff.compare("#".to_string(), before, after);
+        diff
+    }
+
+    /// Whether both versions are identical
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn compare(&mut self, pointer: String, before: &Value, after: &Value) {
+        match (before, after) {
+            (Value::Object(before), Value::Object(after)) => {
+                for (key, before_value) in before {
+                    let child = format!("{}/{}", pointer, escape_pointer_token(key));
+                    match after.get(key) {
+                        Some(after_value) => self.compare(child, before_value, after_value),
+                        None => self.removed.push(child),
+                    }
+                }
+                for key in after.keys().filter(|key| !before.contains_key(*key)) {
+                    self.added
+                        .push(format!("{}/{}", pointer, escape_pointer_token(key)));
+                }
+            }
+            (Value::Array(before), Value::Array(after)) => {
+                for index in 0..before.len().max(after.len()) {
+                    let child = format!("{pointer}/{index}");
+                    match (before.get(index), after.get(index)) {
+                        (Some(before_value), Some(after_value)) => {
+                            self.compare(child, before_value, after_value)
+                        }
+                        (Some(_), None) => self.removed.push(child),
+                        (None, Some(_)) => self.added.push(child),
+                        (None, None) => {}
+                    }
+                }
+            }
+            (before, after) if before == after => {}
+            _ => self.changed.push(pointer),
+        }
+    }
+}
+
+/// What happened during one pass
+#[derive(Debug, Clone)]
+pub struct PassReport {
+    pub name: String,
+    pub duration: Duration,
+    /// Changes made by the pass, when diffing is enabled
+    pub diff: Option<SpecDiff>,
+    /// Snapshot written after the pass, when snapshots are enabled
+    pub snapshot: Option<PathBuf>,
+}
+
+/// What happened during a pipeline run, pass by pass
+#[derive(Debug, Clone, Default)]
+pub struct PipelineReport {
+    pub passes: Vec<PassReport>,
+}
+
+impl PipelineReport {
+    /// Total time spent in passes
+    pub fn total_duration(&self) -> Duration {
+        self.passes.iter().map(|pass| pass.duration).sum()
+    }
+}
+
+impl fmt::Display for PipelineReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .passes
+            .iter()
+            .map(|pass| pass.name.len())
+            .chain(["Pass".len()])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:<width$}  {:>10}  {:>7}  {:>7}  {:>7}",
+            "Pass", "Time", "Added", "Removed", "Changed"
+        )?;
+        for pass in &self.passes {
+            let counts = match &pass.diff {
+                Some(diff) => [diff.added.len(), diff.removed.len(), diff.changed.len()]
+                    .map(|count| count.to_string()),
+                None => ["-", "-", "-"].map(str::to_string),
+            };
+            writeln!(
+                f,
+                "{:<width$}  {:>10}  {:>7}  {:>7}  {:>7}",
+                pass.name,
+                format_duration(pass.duration),
+                counts[0],
+                counts[1],
+                counts[2]
+            )?;
+        }
+        write!(
+            f,
+            "{:<width$}  {:>10}",
+            "total",
+            format_duration(self.total_duration())
+        )
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PassReport, PipelineReport, SpecDiff};
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn test_spec_diff_reports_pointers() {
+        let before = json!({
+            "paths": {"/pets": {"get": {"operationId": "listPets"}}},
+            "tags": ["pets", "store"],
+        });
+        let after = json!({
+            "paths": {"/pets": {"get": {"operationId": "getPets", "summary": "List"}}},
+            "tags": ["pets"],
+        });
+
+        let diff = SpecDiff::between(&before, &after);
+
+        assert_eq!(diff.added, vec!["#/paths/~1pets/get/summary"]);
+        assert_eq!(diff.removed, vec!["#/tags/1"]);
+        assert_eq!(diff.changed, vec!["#/paths/~1pets/get/operationId"]);
+        assert!(SpecDiff::between(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn test_pipeline_report_summary() {
+        let report = PipelineReport {
+            passes: vec![
+                PassReport {
+                    name: "validation".to_string(),
+                    duration: Duration::from_micros(1500),
+                    diff: None,
+                    snapshot: None,
+                },
+                PassReport {
+                    name: "operation-id".to_string(),
+                    duration: Duration::from_micros(500),
+                    diff: Some(SpecDiff {
+                        added: vec!["#/paths/~1pets/get/operationId".to_string()],
+                        ..Default::default()
+                    }),
+                    snapshot: None,
+                },
+            ],
+        };
+
+        let summary = report.to_string();
+        let lines: Vec<_> = summary.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("validation"));
+        assert!(lines[1].contains("1.50ms"));
+        assert!(lines[2].ends_with("1        0        0"));
+        assert!(lines[3].contains("2.00ms"));
+    }
+}
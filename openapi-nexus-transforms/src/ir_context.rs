@@ -1,6 +1,8 @@
 //! Intermediate representation context for transformation passes
 
 use std::collections::HashMap;
+
+use openapi_nexus_ir::DiscriminatedUnion;
 use utoipa::openapi::OpenApi;
 
 /// Analysis results from the IR layer
@@ -8,7 +10,12 @@ use utoipa::openapi::OpenApi;
 pub struct SchemaAnalysis {
     pub dependencies: HashMap<String, Vec<String>>,
     pub circular_refs: Vec<Vec<String>>,
+    /// Type of each component schema, keyed by name
+    ///
+    /// Only the component roots are recorded, not their nested or inline schemas.
     pub schema_types: HashMap<String, String>,
+    /// Resolved discriminators, keyed by union schema name
+    pub discriminated_unions: HashMap<String, DiscriminatedUnion>,
 }
 
 /// Type mappings for code generation
@@ -42,6 +49,7 @@ impl IrContext {
                 dependencies: HashMap::new(),
                 circular_refs: Vec::new(),
                 schema_types: HashMap::new(),
+                discriminated_unions: HashMap::new(),
             },
             type_mappings: TypeMappings {
                 openapi_to_language: HashMap::new(),
@@ -68,6 +76,11 @@ impl IrContext {
         self.schema_analysis.dependencies.get(schema_name)
     }
 
+    /// Get the resolved discriminator of a union schema
+    pub fn get_discriminated_union(&self, schema_name: &str) -> Option<&DiscriminatedUnion> {
+        self.schema_analysis.discriminated_unions.get(schema_name)
+    }
+
     /// Check if a schema has circular references
     pub fn has_circular_refs(&self, schema_name: &str) -> bool {
         self.schema_analysis
@@ -3,14 +3,17 @@
 //! This crate provides a pipeline of transformation passes that can be
 //! applied to OpenAPI specifications before code generation.
 
+pub mod instrumentation;
 pub mod ir_context;
 pub mod passes;
 pub mod pipeline;
 
+pub use instrumentation::{Instrumentation, PassReport, PipelineReport, SpecDiff};
 pub use ir_context::{CustomTypes, IrContext, SchemaAnalysis, TypeMappings};
 pub use passes::{
-    CircularReferenceDetectionPass, DependencyAnalysisPass, NamingConvention, NamingConventionPass,
-    PathNormalizationPass, ReferenceResolutionPass, SchemaNormalizationPass, TransformError,
-    TransformPass, TypeInferencePass, ValidationPass,
+    CircularReferenceDetectionPass, DependencyAnalysisPass, DiscriminatorPass, EnumExtractionPass,
+    FilterRules, NamingConvention, NamingConventionPass, OperationIdPass, PathNormalizationPass,
+    ReferenceResolutionPass, SchemaDeduplicationPass, SchemaNormalizationPass, SpecFilterPass,
+    TransformError, TransformPass, TypeInferencePass, UnusedComponentsPass, ValidationPass,
 };
 pub use pipeline::TransformPipeline;
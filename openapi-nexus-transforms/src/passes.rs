@@ -23,6 +23,9 @@ pub enum TransformError {
 
     #[snafu(display("Pass '{}' not found", pass))]
     PassNotFound { pass: String },
+
+    #[snafu(display("Failed to write snapshot '{}': {}", path, message))]
+    Snapshot { path: String, message: String },
 }
 
 /// OpenAPI-level transformation pass
@@ -66,6 +69,15 @@ pub trait AstTransformPass<T> {
 
 /// Base trait for transformation passes (for backward compatibility)
 pub trait TransformPass {
+    /// Get the name of this pass, naming reports and snapshots
+    ///
+    /// Defaults to the name of the implementing type.
+    fn name(&self) -> &str {
+        let type_name = std::any::type_name::<Self>();
+        let path = type_name.split('<').next().unwrap_or(type_name);
+        path.rsplit("::").next().unwrap_or(path)
+    }
+
     /// Apply the transformation to the OpenAPI specification
     fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError>;
 }
@@ -73,20 +85,32 @@ pub trait TransformPass {
 // Re-export all pass types from submodules
 pub mod circular_reference_detection;
 pub mod dependency_analysis;
+pub mod discriminator;
+pub mod enum_extraction;
 pub mod naming_convention;
+pub mod operation_id;
 pub mod path_normalization;
 pub mod reference_resolution;
+pub mod schema_deduplication;
 pub mod schema_normalization;
+pub mod spec_filter;
 pub mod type_inference;
+pub mod unused_components;
 pub mod validation;
 
 pub use circular_reference_detection::CircularReferenceDetectionPass;
 pub use dependency_analysis::DependencyAnalysisPass;
+pub use discriminator::DiscriminatorPass;
+pub use enum_extraction::EnumExtractionPass;
 pub use naming_convention::NamingConventionPass;
+pub use operation_id::OperationIdPass;
 pub use path_normalization::PathNormalizationPass;
 pub use reference_resolution::ReferenceResolutionPass;
+pub use schema_deduplication::SchemaDeduplicationPass;
 pub use schema_normalization::SchemaNormalizationPass;
+pub use spec_filter::{FilterRules, SpecFilterPass};
 pub use type_inference::TypeInferencePass;
+pub use unused_components::UnusedComponentsPass;
 pub use validation::ValidationPass;
 
 // Re-export naming convention enum
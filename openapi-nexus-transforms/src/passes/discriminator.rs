@@ -0,0 +1,201 @@
+//! Discriminator mapping inference and validation pass
+//!
+//! Resolves the discriminator of every `oneOf`/`anyOf` component schema into a
+//! complete value-to-member mapping, so generators never have to apply the
+//! implicit mapping rules themselves.
+
+use std::collections::HashMap;
+
+use openapi_nexus_ir::{DiscriminatedUnion, SchemaAnalyzer};
+use utoipa::openapi::{OpenApi, RefOr, Schema};
+
+use crate::ir_context::IrContext;
+
+use super::{IrTransformPass, OpenApiTransformPass, TransformError, TransformPass};
+
+/// Discriminator mapping inference and validation pass
+#[derive(Default)]
+pub struct DiscriminatorPass {
+    /// Fail instead of warning when a discriminator is ambiguous or incomplete
+    pub strict_mode: bool,
+}
+
+impl DiscriminatorPass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve all discriminators and write their complete mappings back
+    fn resolve_discriminators(
+        &self,
+        openapi: &mut OpenApi,
+    ) -> Result<HashMap<String, DiscriminatedUnion>, TransformError> {
+        let unions = SchemaAnalyzer::new(openapi).analyze_discriminated_unions();
+
+        let mut problems = Vec::new();
+        for union in &unions {
+            for issue in &union.issues {
+                tracing::warn!("Discriminator of '{}': {}", union.schema_name, issue);
+                problems.push(format!("{}: {}", union.schema_name, issue));
+            }
+        }
+        if self.strict_mode && !problems.is_empty() {
+            return Err(TransformError::PassFailed {
+                pass: "discriminator".to_string(),
+                error: problems.join("; "),
+            });
+        }
+
+        if let Some(components) = openapi.components.as_mut() {
+            for union in &unions {
+                let discriminator = match components.schemas.get_mut(&union.schema_name) {
+                    Some(RefOr::T(Schema::OneOf(one_of))) => one_of.discriminator.as_mut(),
+                    Some(RefOr::T(Schema::AnyOf(any_of))) => any_of.discriminator.as_mut(),
+                    _ => None,
+                };
+                if let Some(discriminator) = discriminator {
+                    discriminator.mapping = union
+                        .mapping
+                        .iter()
+                        .map(|(value, member)| {
+                            (value.clone(), format!("#/components/schemas/{member}"))
+                        })
+                        .collect();
+                }
+            }
+        }
+
+        Ok(unions
+            .into_iter()
+            .map(|union| (union.schema_name.clone(), union))
+            .collect())
+    }
+}
+
+impl OpenApiTransformPass for DiscriminatorPass {
+    fn name(&self) -> &str {
+        "discriminator"
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        tracing::debug!("Resolving discriminator mappings");
+        self.resolve_discriminators(openapi)?;
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        vec!["reference-resolution"]
+    }
+}
+
+impl IrTransformPass for DiscriminatorPass {
+    fn name(&self) -> &str {
+        "discriminator"
+    }
+
+    fn transform(&self, ir: &mut IrContext) -> Result<(), TransformError> {
+        tracing::debug!("Resolving discriminator mappings");
+        ir.schema_analysis.discriminated_unions = self.resolve_discriminators(&mut ir.openapi)?;
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        vec!["reference-resolution"]
+    }
+}
+
+impl TransformPass for DiscriminatorPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        <Self as OpenApiTransformPass>::transform(self, openapi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiscriminatorPass, IrTransformPass, OpenApiTransformPass};
+    use crate::ir_context::IrContext;
+    use utoipa::openapi::schema::{Discriminator, Object, ObjectBuilder, OneOfBuilder, Type};
+    use utoipa::openapi::{Components, Info, OpenApi, Paths, Ref, RefOr, Schema};
+
+    fn create_test_openapi(kind_type: Type) -> OpenApi {
+        let mut components = Components::new();
+        for name in ["Cat", "Dog"] {
+            components.schemas.insert(
+                name.to_string(),
+                RefOr::T(Schema::Object(
+                    ObjectBuilder::new()
+                        .property("kind", Object::with_type(kind_type.clone()))
+                        .required("kind")
+                        .build(),
+                )),
+            );
+        }
+        let mut discriminator = Discriminator::new("kind");
+        discriminator
+            .mapping
+            .insert("dog".to_string(), "#/components/schemas/Dog".to_string());
+        components.schemas.insert(
+            "Pet".to_string(),
+            RefOr::T(Schema::OneOf(
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("Cat"))
+                    .item(Ref::from_schema_name("Dog"))
+                    .discriminator(Some(discriminator))
+                    .build(),
+            )),
+        );
+
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+        openapi
+    }
+
+    #[test]
+    fn test_discriminator_pass_name() {
+        let pass = DiscriminatorPass::new();
+        assert_eq!(OpenApiTransformPass::name(&pass), "discriminator");
+    }
+
+    #[test]
+    fn test_discriminator_pass_dependencies() {
+        let pass = DiscriminatorPass::new();
+        assert_eq!(
+            OpenApiTransformPass::dependencies(&pass),
+            vec!["reference-resolution"]
+        );
+    }
+
+    #[test]
+    fn test_completes_mapping_and_records_analysis() {
+        let mut ir = IrContext::new(create_test_openapi(Type::String));
+
+        IrTransformPass::transform(&DiscriminatorPass::new(), &mut ir).unwrap();
+
+        let union = ir.get_discriminated_union("Pet").unwrap();
+        assert!(union.is_valid());
+        assert_eq!(union.mapping["Cat"], "Cat");
+        assert_eq!(union.mapping["dog"], "Dog");
+
+        let RefOr::T(Schema::OneOf(one_of)) =
+            &ir.openapi.components.as_ref().unwrap().schemas["Pet"]
+        else {
+            panic!("expected oneOf schema");
+        };
+        let mapping = &one_of.discriminator.as_ref().unwrap().mapping;
+        assert_eq!(mapping["Cat"], "#/components/schemas/Cat");
+        assert_eq!(mapping["dog"], "#/components/schemas/Dog");
+    }
+
+    #[test]
+    fn test_invalid_members_fail_in_strict_mode() {
+        let mut openapi = create_test_openapi(Type::Integer);
+        assert!(OpenApiTransformPass::transform(&DiscriminatorPass::new(), &mut openapi).is_ok());
+
+        let pass = DiscriminatorPass { strict_mode: true };
+        assert!(OpenApiTransformPass::transform(&pass, &mut openapi).is_err());
+    }
+}
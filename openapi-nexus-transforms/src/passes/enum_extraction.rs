@@ -0,0 +1,475 @@
+//! Inline enum extraction transformation pass
+//!
+//! Hoists enums declared inline (typically on object properties) into named
+//! component schemas, so generators emit one enum per concept instead of
+//! repeating anonymous literal unions.
+
+use std::collections::BTreeSet;
+
+use heck::ToPascalCase;
+use openapi_nexus_ir::OpenApiTraverser;
+use serde_json::Value;
+use utoipa::openapi::schema::{AllOf, Object, OneOf, SchemaType, Type};
+use utoipa::openapi::{Components, OpenApi, Ref, RefOr, Schema};
+
+use super::{OpenApiTransformPass, TransformError, TransformPass};
+
+const SCHEMA_PREFIX: &str = "#/components/schemas/";
+
+/// Extension overriding the name of a hoisted enum
+const ENUM_NAME_EXTENSION: &str = "x-enum-name";
+
+/// Extension naming the members of an enum
+const ENUM_VARNAMES_EXTENSION: &str = "x-enum-varnames";
+
+/// OpenAPI 3.0 nullability, kept as an extension by the parser
+const NULLABLE_EXTENSION: &str = "nullable";
+
+/// Inline enum extraction transformation pass
+///
+/// Enums nested in component schemas are named after their parent schema and
+/// property path (`Pet.status` becomes `PetStatus`). Enums elsewhere, e.g. in
+/// operation parameters, are only hoisted when they carry `x-enum-name`.
+/// Enums with the same type, values and member names share one component.
+/// The description, default and nullability of each occurrence stay with the
+/// reference that replaces it.
+pub struct EnumExtractionPass;
+
+impl Default for EnumExtractionPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnumExtractionPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Identity of an enum: its type without `null`, its values and member names
+fn enum_key(schema: &Schema) -> Option<Value> {
+    let Schema::Object(obj) = schema else {
+        return None;
+    };
+    let values = obj
+        .enum_values
+        .as_ref()
+        .filter(|values| !values.is_empty())?;
+    let schema_type = serde_json::to_value(non_null_type(&obj.schema_type)).ok()?;
+    let varnames = obj
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.get(ENUM_VARNAMES_EXTENSION))
+        .cloned()
+        .unwrap_or(Value::Null);
+    Some(Value::Array(vec![
+        schema_type,
+        Value::Array(values.clone()),
+        varnames,
+    ]))
+}
+
+/// A schema type with `null` removed
+fn non_null_type(schema_type: &SchemaType) -> SchemaType {
+    match schema_type {
+        SchemaType::Array(types) => {
+            let mut types: Vec<Type> = types
+                .iter()
+                .filter(|ty| **ty != Type::Null)
+                .cloned()
+                .collect();
+            match types.len() {
+                0 => SchemaType::AnyValue,
+                1 => SchemaType::Type(types.remove(0)),
+                _ => SchemaType::Array(types),
+            }
+        }
+        other => other.clone(),
+    }
+}
+
+/// Whether an occurrence allows `null`, by type or by the 3.0 keyword
+fn is_nullable(obj: &Object) -> bool {
+    let by_type = match &obj.schema_type {
+        SchemaType::Array(types) => types.contains(&Type::Null),
+        SchemaType::Type(ty) => *ty == Type::Null,
+        SchemaType::AnyValue => false,
+    };
+    by_type
+        || obj
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get(NULLABLE_EXTENSION))
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+}
+
+/// The component an enum is hoisted into, without the nullability and
+/// default that belong to the occurrence
+fn component_schema(schema: &Schema) -> RefOr<Schema> {
+    let mut schema = schema.clone();
+    if let Schema::Object(obj) = &mut schema {
+        obj.schema_type = non_null_type(&obj.schema_type);
+        obj.default = None;
+        if let Some(extensions) = obj.extensions.as_mut() {
+            extensions.remove(NULLABLE_EXTENSION);
+        }
+    }
+    RefOr::T(schema)
+}
+
+/// The reference replacing an enum occurrence
+///
+/// The description rides on the `$ref`; a default or nullability needs a
+/// composition around it, `oneOf` with `null` or a single-item `allOf`.
+fn occurrence_ref(name: &str, schema: &Schema) -> RefOr<Schema> {
+    let Schema::Object(obj) = schema else {
+        return RefOr::Ref(Ref::from_schema_name(name));
+    };
+    let mut reference = Ref::from_schema_name(name);
+    if let Some(description) = &obj.description {
+        reference.description = description.clone();
+    }
+
+    if is_nullable(obj) {
+        let mut one_of = OneOf::new();
+        one_of.items = vec![
+            RefOr::Ref(reference),
+            RefOr::T(Schema::Object(Object::with_type(Type::Null))),
+        ];
+        one_of.description = obj.description.clone();
+        one_of.default = obj.default.clone();
+        RefOr::T(Schema::OneOf(one_of))
+    } else if obj.default.is_some() {
+        let mut all_of = AllOf::new();
+        all_of.items = vec![RefOr::Ref(reference)];
+        all_of.description = obj.description.clone();
+        all_of.default = obj.default.clone();
+        RefOr::T(Schema::AllOf(all_of))
+    } else {
+        RefOr::Ref(reference)
+    }
+}
+
+/// Name for an enum nested in a component schema, derived from its JSON pointer
+///
+/// Structural keywords and composition indices are skipped, so
+/// `#/components/schemas/Pet/properties/tags/items` yields `PetTags`.
+fn name_from_pointer(pointer: &str) -> Option<String> {
+    let rest = pointer.strip_prefix(SCHEMA_PREFIX)?;
+    let name = rest
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .filter(|token| {
+            !matches!(
+                token.as_str(),
+                "properties" | "items" | "additionalProperties" | "allOf" | "oneOf" | "anyOf"
+            ) && token.parse::<usize>().is_err()
+        })
+        .map(|token| token.to_pascal_case())
+        .collect::<String>();
+    Some(name)
+}
+
+impl OpenApiTransformPass for EnumExtractionPass {
+    fn name(&self) -> &str {
+        "enum-extraction"
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        tracing::debug!("Extracting inline enums into components");
+
+        let components = openapi.components.get_or_insert_with(Components::new);
+        let mut taken: BTreeSet<String> = components.schemas.keys().cloned().collect();
+
+        // Enum components that already exist are reused for identical inline
+        // enums, unless they carry a nullability or default of their own
+        let mut known: Vec<(Value, String)> = components
+            .schemas
+            .iter()
+            .filter_map(|(name, schema)| match schema {
+                RefOr::T(schema @ Schema::Object(obj))
+                    if !is_nullable(obj) && obj.default.is_none() =>
+                {
+                    enum_key(schema).map(|key| (key, name.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        let mut hoisted = Vec::new();
+
+        OpenApiTraverser::walk_schemas_mut(openapi, |pointer, schema_ref| {
+            let RefOr::T(schema) = schema_ref else {
+                return;
+            };
+            let Some(key) = enum_key(schema) else {
+                return;
+            };
+            // Component roots are already named
+            if pointer
+                .strip_prefix(SCHEMA_PREFIX)
+                .is_some_and(|rest| !rest.contains('/'))
+            {
+                return;
+            }
+
+            if let Some((_, name)) = known.iter().find(|(existing, _)| *existing == key) {
+                tracing::debug!("Replacing inline enum at {} with '{}'", pointer, name);
+                *schema_ref = occurrence_ref(name, schema);
+                return;
+            }
+
+            let explicit_name = match schema {
+                Schema::Object(obj) => obj
+                    .extensions
+                    .as_ref()
+                    .and_then(|extensions| extensions.get(ENUM_NAME_EXTENSION))
+                    .and_then(Value::as_str)
+                    .map(ToPascalCase::to_pascal_case),
+                _ => None,
+            };
+            let Some(base_name) = explicit_name.or_else(|| name_from_pointer(pointer)) else {
+                return;
+            };
+
+            let name = if taken.contains(&base_name) {
+                (2..)
+                    .map(|n| format!("{base_name}{n}"))
+                    .find(|candidate| !taken.contains(candidate))
+                    .expect("unbounded suffix search always yields a candidate")
+            } else {
+                base_name
+            };
+
+            tracing::debug!("Hoisting inline enum at {} into '{}'", pointer, name);
+            let component = component_schema(schema);
+            *schema_ref = occurrence_ref(&name, schema);
+            taken.insert(name.clone());
+            known.push((key, name.clone()));
+            hoisted.push((name, component));
+        });
+
+        if !hoisted.is_empty() {
+            tracing::info!("Extracted {} inline enums into components", hoisted.len());
+        }
+        if let Some(components) = openapi.components.as_mut() {
+            components.schemas.extend(hoisted);
+        }
+
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        vec!["type-inference"]
+    }
+}
+
+impl TransformPass for EnumExtractionPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        <Self as OpenApiTransformPass>::transform(self, openapi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EnumExtractionPass, OpenApiTransformPass, name_from_pointer};
+    use serde_json::json;
+    use utoipa::openapi::schema::{Object, SchemaType, Type};
+    use utoipa::openapi::{Components, Info, OpenApi, Paths, RefOr, Schema};
+
+    fn status_enum() -> RefOr<Schema> {
+        let mut status = Object::with_type(Type::String);
+        status.enum_values = Some(vec![json!("active"), json!("inactive")]);
+        RefOr::T(Schema::Object(status))
+    }
+
+    fn object_with_status(property: &str, status: RefOr<Schema>) -> RefOr<Schema> {
+        let mut obj = Object::new();
+        obj.properties.insert(property.to_string(), status);
+        RefOr::T(Schema::Object(obj))
+    }
+
+    fn property_ref(openapi: &OpenApi, schema: &str, property: &str) -> String {
+        let RefOr::T(Schema::Object(obj)) = &openapi.components.as_ref().unwrap().schemas[schema]
+        else {
+            panic!("expected object schema");
+        };
+        match &obj.properties[property] {
+            RefOr::Ref(reference) => reference.ref_location.clone(),
+            RefOr::T(_) => panic!("expected reference"),
+        }
+    }
+
+    #[test]
+    fn test_enum_extraction_pass_name() {
+        let pass = EnumExtractionPass::new();
+        assert_eq!(pass.name(), "enum-extraction");
+    }
+
+    #[test]
+    fn test_enum_extraction_pass_dependencies() {
+        let pass = EnumExtractionPass::new();
+        assert_eq!(pass.dependencies(), vec!["type-inference"]);
+    }
+
+    #[test]
+    fn test_name_from_pointer() {
+        assert_eq!(
+            name_from_pointer("#/components/schemas/Pet/properties/status").as_deref(),
+            Some("PetStatus")
+        );
+        assert_eq!(
+            name_from_pointer("#/components/schemas/Pet/properties/tags/items").as_deref(),
+            Some("PetTags")
+        );
+        assert_eq!(
+            name_from_pointer("#/paths/~1pets/get/parameters/0/schema"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hoists_and_deduplicates_inline_enums() {
+        let mut components = Components::new();
+        components.schemas.insert(
+            "Account".to_string(),
+            object_with_status("status", status_enum()),
+        );
+        components.schemas.insert(
+            "User".to_string(),
+            object_with_status("state", status_enum()),
+        );
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+
+        EnumExtractionPass::new().transform(&mut openapi).unwrap();
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("AccountStatus"));
+        assert!(!schemas.contains_key("UserState"));
+        assert_eq!(
+            property_ref(&openapi, "Account", "status"),
+            "#/components/schemas/AccountStatus"
+        );
+        assert_eq!(
+            property_ref(&openapi, "User", "state"),
+            "#/components/schemas/AccountStatus"
+        );
+    }
+
+    #[test]
+    fn test_honors_enum_name_extension() {
+        let mut status = status_enum();
+        if let RefOr::T(Schema::Object(obj)) = &mut status {
+            obj.extensions = Some(
+                [("x-enum-name", json!("lifecycle state"))]
+                    .into_iter()
+                    .collect(),
+            );
+        }
+        let mut components = Components::new();
+        components
+            .schemas
+            .insert("Account".to_string(), object_with_status("status", status));
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+
+        EnumExtractionPass::new().transform(&mut openapi).unwrap();
+
+        assert_eq!(
+            property_ref(&openapi, "Account", "status"),
+            "#/components/schemas/LifecycleState"
+        );
+    }
+
+    #[test]
+    fn test_deduplicated_occurrences_keep_their_siblings() {
+        let mut described = status_enum();
+        if let RefOr::T(Schema::Object(obj)) = &mut described {
+            obj.description = Some("State of the user".to_string());
+            obj.default = Some(json!("active"));
+        }
+        let mut nullable = status_enum();
+        if let RefOr::T(Schema::Object(obj)) = &mut nullable {
+            obj.schema_type = SchemaType::from_iter([Type::String, Type::Null]);
+        }
+        let mut components = Components::new();
+        components.schemas.insert(
+            "Account".to_string(),
+            object_with_status("status", status_enum()),
+        );
+        components
+            .schemas
+            .insert("User".to_string(), object_with_status("state", described));
+        components
+            .schemas
+            .insert("Team".to_string(), object_with_status("state", nullable));
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+
+        EnumExtractionPass::new().transform(&mut openapi).unwrap();
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        assert_eq!(schemas.len(), 4);
+        let state = |schema: &str| {
+            let RefOr::T(Schema::Object(obj)) = &schemas[schema] else {
+                panic!("expected object schema");
+            };
+            serde_json::to_value(&obj.properties["state"]).unwrap()
+        };
+        assert_eq!(
+            state("User"),
+            json!({
+                "allOf": [{
+                    "$ref": "#/components/schemas/AccountStatus",
+                    "description": "State of the user"
+                }],
+                "description": "State of the user",
+                "default": "active"
+            })
+        );
+        assert_eq!(
+            state("Team"),
+            json!({
+                "oneOf": [
+                    { "$ref": "#/components/schemas/AccountStatus" },
+                    { "type": "null" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_keeps_enums_with_different_member_names_apart() {
+        let mut renamed = status_enum();
+        if let RefOr::T(Schema::Object(obj)) = &mut renamed {
+            obj.extensions = Some(
+                [("x-enum-varnames", json!(["On", "Off"]))]
+                    .into_iter()
+                    .collect(),
+            );
+        }
+        let mut components = Components::new();
+        components.schemas.insert(
+            "Account".to_string(),
+            object_with_status("status", status_enum()),
+        );
+        components
+            .schemas
+            .insert("User".to_string(), object_with_status("state", renamed));
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+
+        EnumExtractionPass::new().transform(&mut openapi).unwrap();
+
+        assert_eq!(
+            property_ref(&openapi, "User", "state"),
+            "#/components/schemas/UserState"
+        );
+    }
+}
@@ -65,6 +65,10 @@ impl OpenApiTransformPass for NamingConventionPass {
 }
 
 impl TransformPass for NamingConventionPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
     fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
         <Self as OpenApiTransformPass>::transform(self, openapi)
     }
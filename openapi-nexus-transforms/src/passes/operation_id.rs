@@ -0,0 +1,214 @@
+//! OperationId synthesis transformation pass
+//!
+//! Generators derive method names from `operationId`, so operations without one
+//! (or with colliding ones) end up with unstable names. This pass synthesizes
+//! missing ids from the HTTP method and path template and makes every id unique.
+
+use std::collections::HashSet;
+
+use heck::{ToLowerCamelCase, ToPascalCase};
+use openapi_nexus_ir::operations_mut;
+use utoipa::openapi::OpenApi;
+
+use super::{OpenApiTransformPass, TransformError, TransformPass};
+
+/// OperationId synthesis and uniqueness enforcement pass
+pub struct OperationIdPass;
+
+impl Default for OperationIdPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OperationIdPass {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Synthesize an operationId from the HTTP method and path template
+    ///
+    /// `GET /pets/{petId}/photos` becomes `getPetsByPetIdPhotos`.
+    pub fn synthesize(method: &str, path: &str) -> String {
+        let mut id = method.to_lowercase();
+
+        for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+            match segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+            {
+                Some(param) => {
+                    id.push_str("By");
+                    id.push_str(&param.to_pascal_case());
+                }
+                None => id.push_str(&segment.to_pascal_case()),
+            }
+        }
+
+        if id == method.to_lowercase() {
+            id.push_str("Root");
+        }
+
+        id.to_lower_camel_case()
+    }
+}
+
+/// Key under which two operationIds are considered the same
+///
+/// Generators case-convert ids, so `get_pet` and `getPet` would collide.
+fn collision_key(id: &str) -> String {
+    id.to_lower_camel_case()
+}
+
+/// Append the smallest numeric suffix (starting at 2) that makes `id` unique
+fn disambiguate(id: &str, taken: &HashSet<String>) -> String {
+    (2..)
+        .map(|n| format!("{id}{n}"))
+        .find(|candidate| !taken.contains(&collision_key(candidate)))
+        .expect("unbounded suffix search always yields a candidate")
+}
+
+impl OpenApiTransformPass for OperationIdPass {
+    fn name(&self) -> &str {
+        "operation-id"
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        tracing::debug!("Synthesizing and deduplicating operationIds");
+
+        let mut taken = HashSet::new();
+
+        // Explicit ids are claimed first, in path/method order, so that adding an
+        // operation without an id never renames an existing one
+        for (path, path_item) in openapi.paths.paths.iter_mut() {
+            for (method, operation) in operations_mut(path_item) {
+                let Some(id) = operation.operation_id.as_mut() else {
+                    continue;
+                };
+                if taken.insert(collision_key(id)) {
+                    continue;
+                }
+
+                let renamed = disambiguate(id, &taken);
+                tracing::warn!(
+                    "Duplicate operationId '{}' on {} {}; renamed to '{}'",
+                    id,
+                    method.to_uppercase(),
+                    path,
+                    renamed
+                );
+                taken.insert(collision_key(&renamed));
+                *id = renamed;
+            }
+        }
+
+        for (path, path_item) in openapi.paths.paths.iter_mut() {
+            for (method, operation) in operations_mut(path_item) {
+                if operation.operation_id.is_some() {
+                    continue;
+                }
+
+                let mut id = Self::synthesize(method, path);
+                if taken.contains(&collision_key(&id)) {
+                    id = disambiguate(&id, &taken);
+                }
+                tracing::warn!(
+                    "Missing operationId on {} {}; synthesized '{}'",
+                    method.to_uppercase(),
+                    path,
+                    id
+                );
+                taken.insert(collision_key(&id));
+                operation.operation_id = Some(id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        vec!["path-normalization"]
+    }
+}
+
+impl TransformPass for OperationIdPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        <Self as OpenApiTransformPass>::transform(self, openapi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OpenApiTransformPass, OperationIdPass};
+    use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem};
+    use utoipa::openapi::{Info, OpenApi, Paths};
+
+    fn operation_id(openapi: &OpenApi, path: &str, method: &str) -> String {
+        let path_item = &openapi.paths.paths[path];
+        let operation = match method {
+            "get" => path_item.get.as_ref(),
+            "post" => path_item.post.as_ref(),
+            _ => None,
+        };
+        operation.unwrap().operation_id.clone().unwrap()
+    }
+
+    fn add_operation(openapi: &mut OpenApi, path: &str, method: HttpMethod, id: Option<&str>) {
+        let operation = OperationBuilder::new().operation_id(id).build();
+        let item = PathItem::new(method, operation);
+        match openapi.paths.paths.get_mut(path) {
+            Some(existing) => existing.merge_operations(item),
+            None => {
+                openapi.paths.paths.insert(path.to_string(), item);
+            }
+        }
+    }
+
+    #[test]
+    fn test_operation_id_pass_name() {
+        let pass = OperationIdPass::new();
+        assert_eq!(pass.name(), "operation-id");
+    }
+
+    #[test]
+    fn test_operation_id_pass_dependencies() {
+        let pass = OperationIdPass::new();
+        assert_eq!(pass.dependencies(), vec!["path-normalization"]);
+    }
+
+    #[test]
+    fn test_synthesize_from_method_and_path() {
+        assert_eq!(OperationIdPass::synthesize("get", "/pets"), "getPets");
+        assert_eq!(
+            OperationIdPass::synthesize("get", "/pets/{petId}/photos"),
+            "getPetsByPetIdPhotos"
+        );
+        assert_eq!(
+            OperationIdPass::synthesize("delete", "/store/order-items/{order_id}"),
+            "deleteStoreOrderItemsByOrderId"
+        );
+        assert_eq!(OperationIdPass::synthesize("get", "/"), "getRoot");
+    }
+
+    #[test]
+    fn test_fills_missing_and_disambiguates_duplicates() {
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        add_operation(&mut openapi, "/pets", HttpMethod::Get, None);
+        add_operation(&mut openapi, "/pets", HttpMethod::Post, Some("createPet"));
+        add_operation(&mut openapi, "/pets/{id}", HttpMethod::Get, Some("getPets"));
+        add_operation(&mut openapi, "/users", HttpMethod::Post, Some("create_pet"));
+
+        OpenApiTransformPass::transform(&OperationIdPass::new(), &mut openapi).unwrap();
+
+        // Explicit ids win over synthesized ones
+        assert_eq!(operation_id(&openapi, "/pets/{id}", "get"), "getPets");
+        assert_eq!(operation_id(&openapi, "/pets", "get"), "getPets2");
+        // Case-insensitive duplicates are renamed in path order
+        assert_eq!(operation_id(&openapi, "/pets", "post"), "createPet");
+        assert_eq!(operation_id(&openapi, "/users", "post"), "create_pet2");
+    }
+}
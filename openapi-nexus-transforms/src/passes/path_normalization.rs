@@ -58,6 +58,10 @@ impl OpenApiTransformPass for PathNormalizationPass {
 }
 
 impl TransformPass for PathNormalizationPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
     fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
         <Self as OpenApiTransformPass>::transform(self, openapi)
     }
@@ -61,6 +61,10 @@ impl OpenApiTransformPass for ReferenceResolutionPass {
 }
 
 impl TransformPass for ReferenceResolutionPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
     fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
         <Self as OpenApiTransformPass>::transform(self, openapi)
     }
@@ -0,0 +1,571 @@
+//! Schema deduplication transformation pass
+//!
+//! Specs frequently repeat the same object, either as separately named
+//! components or as the same inline body schema in several operations. This
+//! pass hoists repeated inline body schemas into one shared component and, when
+//! asked to, merges structurally identical named components, rewriting every
+//! reference to them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use heck::ToPascalCase;
+use openapi_nexus_ir::{OpenApiTraverser, SchemaAnalyzer, operations_mut};
+use serde_json::Value;
+use utoipa::openapi::path::Operation;
+use utoipa::openapi::{Components, OpenApi, Ref, RefOr, Schema};
+
+use super::{OpenApiTransformPass, OperationIdPass, TransformError, TransformPass};
+
+const SCHEMA_PREFIX: &str = "#/components/schemas/";
+
+/// Schema deduplication transformation pass
+pub struct SchemaDeduplicationPass {
+    /// Hoist inline body schemas that repeat across operations into a new
+    /// shared component
+    ///
+    /// Inline bodies are never pointed at an existing component, even one of
+    /// the same shape, and a body used once stays inline.
+    pub hoist_inline: bool,
+    /// Merge structurally identical named components into one
+    ///
+    /// Off by default: identical components are often distinct public types.
+    /// Members of `oneOf`/`anyOf` schemas and discriminator mapping targets are
+    /// never merged, and neither are components whose title or description
+    /// differ.
+    pub merge_components: bool,
+}
+
+impl Default for SchemaDeduplicationPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaDeduplicationPass {
+    pub fn new() -> Self {
+        Self {
+            hoist_inline: true,
+            merge_components: false,
+        }
+    }
+
+    /// Enable or disable merging of identical named components
+    pub fn with_merge_components(mut self, merge_components: bool) -> Self {
+        self.merge_components = merge_components;
+        self
+    }
+
+    /// Merge identical component schemas until none are left
+    ///
+    /// Merging can make further schemas identical (two objects that referenced
+    /// different duplicates now reference the same canonical schema), hence the loop.
+    fn merge_duplicate_components(openapi: &mut OpenApi) {
+        let protected = union_members(openapi);
+        loop {
+            let duplicates: Vec<Vec<String>> = SchemaAnalyzer::new(openapi)
+                .find_duplicate_schemas()
+                .into_iter()
+                .map(|group| {
+                    group
+                        .into_iter()
+                        .filter(|name| !protected.contains(name))
+                        .collect::<Vec<_>>()
+                })
+                .filter(|group| group.len() > 1)
+                .collect();
+            if duplicates.is_empty() {
+                break;
+            }
+
+            let mut renames = BTreeMap::new();
+            for group in duplicates {
+                let (canonical, others) = group.split_first().expect("groups are never empty");
+                for other in others {
+                    tracing::info!("Merging duplicate schema '{}' into '{}'", other, canonical);
+                    renames.insert(
+                        format!("{SCHEMA_PREFIX}{other}"),
+                        format!("{SCHEMA_PREFIX}{canonical}"),
+                    );
+                }
+            }
+
+            if let Some(components) = openapi.components.as_mut() {
+                components
+                    .schemas
+                    .retain(|name, _| !renames.contains_key(&format!("{SCHEMA_PREFIX}{name}")));
+            }
+
+            OpenApiTraverser::walk_schemas_mut(openapi, |_pointer, schema_ref| {
+                if let RefOr::Ref(reference) = schema_ref
+                    && let Some(target) = renames.get(&reference.ref_location)
+                {
+                    reference.ref_location = target.clone();
+                }
+            });
+        }
+    }
+
+    /// Replace repeated inline body schemas by references to a new shared component
+    fn hoist_inline_bodies(openapi: &mut OpenApi) {
+        // Structural groups in first-seen order, each with its suggested name
+        let mut groups: Vec<(Value, String, usize)> = Vec::new();
+        let mut occurrences = Vec::new();
+
+        for (path, path_item) in openapi.paths.paths.iter_mut() {
+            for (method, operation) in operations_mut(path_item) {
+                let operation_name = operation_name(method, path, operation);
+                for (kind, schema) in inline_body_schemas(operation) {
+                    let Ok(value) = serde_json::to_value(&*schema) else {
+                        occurrences.push(None);
+                        continue;
+                    };
+                    let index = match groups.iter().position(|(existing, ..)| *existing == value) {
+                        Some(index) => index,
+                        None => {
+                            let hint = suggested_name(schema, &operation_name, kind);
+                            groups.push((value, hint, 0));
+                            groups.len() - 1
+                        }
+                    };
+                    groups[index].2 += 1;
+                    occurrences.push(Some(index));
+                }
+            }
+        }
+
+        let components = openapi.components.get_or_insert_with(Components::new);
+
+        // Create the component of every group that repeats
+        let targets: Vec<Option<String>> = groups
+            .into_iter()
+            .map(|(value, hint, count)| {
+                if count < 2 {
+                    return None;
+                }
+                let name = unique_component_name(components, &hint);
+                let schema = serde_json::from_value(value).ok()?;
+                tracing::info!("Hoisting repeated inline schema into component '{}'", name);
+                components.schemas.insert(name.clone(), schema);
+                Some(name)
+            })
+            .collect();
+
+        let mut occurrences = occurrences.into_iter();
+        for path_item in openapi.paths.paths.values_mut() {
+            for (_, operation) in operations_mut(path_item) {
+                for (_, schema) in inline_body_schemas(operation) {
+                    if let Some(Some(index)) = occurrences.next()
+                        && let Some(name) = &targets[index]
+                    {
+                        *schema = RefOr::Ref(Ref::from_schema_name(name));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Components referenced as `oneOf`/`anyOf` members or discriminator mapping targets
+///
+/// Union members are told apart by name, so merging two of them would collapse
+/// the variants of the union.
+fn union_members(openapi: &mut OpenApi) -> BTreeSet<String> {
+    let mut members = BTreeSet::new();
+    OpenApiTraverser::walk_schemas_mut(openapi, |_pointer, schema_ref| {
+        let (items, discriminator) = match schema_ref {
+            RefOr::T(Schema::OneOf(one_of)) => (&one_of.items, &one_of.discriminator),
+            RefOr::T(Schema::AnyOf(any_of)) => (&any_of.items, &any_of.discriminator),
+            _ => return,
+        };
+        let item_refs = items.iter().filter_map(|item| match item {
+            RefOr::Ref(reference) => Some(reference.ref_location.as_str()),
+            RefOr::T(_) => None,
+        });
+        let mapping_refs = discriminator
+            .iter()
+            .flat_map(|discriminator| discriminator.mapping.values())
+            .map(String::as_str);
+        members.extend(
+            item_refs
+                .chain(mapping_refs)
+                .filter_map(|location| location.strip_prefix(SCHEMA_PREFIX))
+                .map(str::to_string),
+        );
+    });
+    members
+}
+
+/// Where an inline body schema was found
+#[derive(Clone, Copy)]
+enum BodyKind {
+    Request,
+    Response,
+}
+
+/// Inline object schemas of request and response bodies, in a stable order
+fn inline_body_schemas(operation: &mut Operation) -> Vec<(BodyKind, &mut RefOr<Schema>)> {
+    let request = operation
+        .request_body
+        .iter_mut()
+        .flat_map(|body| body.content.values_mut())
+        .filter_map(|content| content.schema.as_mut())
+        .map(|schema| (BodyKind::Request, schema));
+    let responses = operation
+        .responses
+        .responses
+        .values_mut()
+        .filter_map(|response| match response {
+            RefOr::T(response) => Some(response),
+            RefOr::Ref(_) => None,
+        })
+        .flat_map(|response| response.content.values_mut())
+        .filter_map(|content| content.schema.as_mut())
+        .map(|schema| (BodyKind::Response, schema));
+
+    request
+        .chain(responses)
+        .filter(|(_, schema)| {
+            matches!(schema, RefOr::T(Schema::Object(obj)) if !obj.properties.is_empty())
+        })
+        .collect()
+}
+
+fn operation_name(method: &str, path: &str, operation: &Operation) -> String {
+    operation
+        .operation_id
+        .clone()
+        .unwrap_or_else(|| OperationIdPass::synthesize(method, path))
+}
+
+/// Component name for a hoisted schema: its title, or the operation plus body kind
+fn suggested_name(schema: &RefOr<Schema>, operation_name: &str, kind: BodyKind) -> String {
+    if let RefOr::T(Schema::Object(obj)) = schema
+        && let Some(title) = &obj.title
+    {
+        return title.to_pascal_case();
+    }
+    let suffix = match kind {
+        BodyKind::Request => "Request",
+        BodyKind::Response => "Response",
+    };
+    format!("{}{}", operation_name.to_pascal_case(), suffix)
+}
+
+fn unique_component_name(components: &Components, hint: &str) -> String {
+    if !components.schemas.contains_key(hint) {
+        return hint.to_string();
+    }
+    (2..)
+        .map(|n| format!("{hint}{n}"))
+        .find(|candidate| !components.schemas.contains_key(candidate))
+        .expect("unbounded suffix search always yields a candidate")
+}
+
+impl OpenApiTransformPass for SchemaDeduplicationPass {
+    fn name(&self) -> &str {
+        "schema-deduplication"
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        tracing::debug!("Deduplicating structurally identical schemas");
+
+        if self.merge_components {
+            Self::merge_duplicate_components(openapi);
+        }
+        if self.hoist_inline {
+            Self::hoist_inline_bodies(openapi);
+        }
+
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        vec!["operation-id"]
+    }
+}
+
+impl TransformPass for SchemaDeduplicationPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        <Self as OpenApiTransformPass>::transform(self, openapi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OpenApiTransformPass, SchemaDeduplicationPass};
+    use serde_json::json;
+    use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem};
+    use utoipa::openapi::schema::{Object, Type};
+    use utoipa::openapi::{
+        Components, ContentBuilder, Info, OpenApi, Paths, Ref, RefOr, ResponseBuilder, Schema,
+    };
+
+    fn message_object() -> Object {
+        let mut obj = Object::new();
+        obj.properties.insert(
+            "message".to_string(),
+            RefOr::T(Schema::Object(Object::with_type(Type::String))),
+        );
+        obj
+    }
+
+    fn operation_returning(id: &str, schema: RefOr<Schema>) -> utoipa::openapi::path::Operation {
+        let response = ResponseBuilder::new()
+            .description("ok")
+            .content(
+                "application/json",
+                ContentBuilder::new().schema(Some(schema)).build(),
+            )
+            .build();
+        OperationBuilder::new()
+            .operation_id(Some(id))
+            .response("200", response)
+            .build()
+    }
+
+    fn response_schema(openapi: &OpenApi, path: &str) -> RefOr<Schema> {
+        let operation = openapi.paths.paths[path].get.as_ref().unwrap();
+        let RefOr::T(response) = &operation.responses.responses["200"] else {
+            panic!("expected inline response");
+        };
+        response.content["application/json"].schema.clone().unwrap()
+    }
+
+    fn ref_location(schema: &RefOr<Schema>) -> &str {
+        match schema {
+            RefOr::Ref(reference) => &reference.ref_location,
+            RefOr::T(_) => panic!("expected reference"),
+        }
+    }
+
+    #[test]
+    fn test_schema_deduplication_pass_name() {
+        let pass = SchemaDeduplicationPass::new();
+        assert_eq!(pass.name(), "schema-deduplication");
+    }
+
+    #[test]
+    fn test_schema_deduplication_pass_dependencies() {
+        let pass = SchemaDeduplicationPass::new();
+        assert_eq!(pass.dependencies(), vec!["operation-id"]);
+    }
+
+    #[test]
+    fn test_merges_identical_components_and_rewrites_refs() {
+        let mut components = Components::new();
+        for name in ["NotFound", "BadRequest"] {
+            components
+                .schemas
+                .insert(name.to_string(), RefOr::T(Schema::Object(message_object())));
+        }
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+        openapi.paths.paths.insert(
+            "/pets".to_string(),
+            PathItem::new(
+                HttpMethod::Get,
+                operation_returning("listPets", Ref::from_schema_name("NotFound").into()),
+            ),
+        );
+
+        SchemaDeduplicationPass::new()
+            .with_merge_components(true)
+            .transform(&mut openapi)
+            .unwrap();
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("BadRequest"));
+        assert!(!schemas.contains_key("NotFound"));
+        assert_eq!(
+            ref_location(&response_schema(&openapi, "/pets")),
+            "#/components/schemas/BadRequest"
+        );
+    }
+
+    /// Spec with identical `oneOf` members and identical error components
+    fn pets_and_errors() -> OpenApi {
+        serde_json::from_value(json!({
+            "openapi": "3.1.0",
+            "info": { "title": "Test API", "version": "1.0.0" },
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "oneOf": [
+                            { "$ref": "#/components/schemas/Cat" },
+                            { "$ref": "#/components/schemas/Dog" }
+                        ],
+                        "discriminator": { "propertyName": "kind" }
+                    },
+                    "Cat": {
+                        "type": "object",
+                        "required": ["kind"],
+                        "properties": { "kind": { "type": "string" } }
+                    },
+                    "Dog": {
+                        "type": "object",
+                        "required": ["kind"],
+                        "properties": { "kind": { "type": "string" } }
+                    },
+                    "NotFoundError": {
+                        "type": "object",
+                        "properties": { "message": { "type": "string" } }
+                    },
+                    "ConflictError": {
+                        "type": "object",
+                        "properties": { "message": { "type": "string" } }
+                    }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_keeps_identical_components_by_default() {
+        let mut openapi = pets_and_errors();
+
+        SchemaDeduplicationPass::new()
+            .transform(&mut openapi)
+            .unwrap();
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        for name in ["Cat", "Dog", "NotFoundError", "ConflictError"] {
+            assert!(schemas.contains_key(name), "{name} was merged");
+        }
+    }
+
+    #[test]
+    fn test_never_merges_union_members() {
+        let mut openapi = pets_and_errors();
+
+        SchemaDeduplicationPass::new()
+            .with_merge_components(true)
+            .transform(&mut openapi)
+            .unwrap();
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("Cat"));
+        assert!(schemas.contains_key("Dog"));
+        assert!(schemas.contains_key("ConflictError"));
+        assert!(!schemas.contains_key("NotFoundError"));
+    }
+
+    #[test]
+    fn test_never_merges_components_with_different_titles() {
+        let mut openapi = pets_and_errors();
+        let schemas = &mut openapi.components.as_mut().unwrap().schemas;
+        for name in ["NotFoundError", "ConflictError"] {
+            if let Some(RefOr::T(Schema::Object(obj))) = schemas.get_mut(name) {
+                obj.title = Some(name.to_string());
+            }
+        }
+
+        SchemaDeduplicationPass::new()
+            .with_merge_components(true)
+            .transform(&mut openapi)
+            .unwrap();
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("NotFoundError"));
+        assert!(schemas.contains_key("ConflictError"));
+    }
+
+    #[test]
+    fn test_keeps_single_inline_body_matching_a_component() {
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.paths.paths.insert(
+            "/status".to_string(),
+            PathItem::new(
+                HttpMethod::Get,
+                operation_returning("getStatus", RefOr::T(Schema::Object(message_object()))),
+            ),
+        );
+        let mut components = Components::new();
+        components.schemas.insert(
+            "ErrorMessage".to_string(),
+            RefOr::T(Schema::Object(message_object())),
+        );
+        openapi.components = Some(components);
+
+        SchemaDeduplicationPass::new()
+            .transform(&mut openapi)
+            .unwrap();
+
+        assert!(matches!(
+            response_schema(&openapi, "/status"),
+            RefOr::T(Schema::Object(_))
+        ));
+        assert_eq!(openapi.components.as_ref().unwrap().schemas.len(), 1);
+    }
+
+    #[test]
+    fn test_hoists_repeated_inline_bodies_into_a_new_component() {
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        for (path, id) in [("/pets", "listPets"), ("/users", "listUsers")] {
+            openapi.paths.paths.insert(
+                path.to_string(),
+                PathItem::new(
+                    HttpMethod::Get,
+                    operation_returning(id, RefOr::T(Schema::Object(message_object()))),
+                ),
+            );
+        }
+        let mut components = Components::new();
+        components.schemas.insert(
+            "ErrorMessage".to_string(),
+            RefOr::T(Schema::Object(message_object())),
+        );
+        openapi.components = Some(components);
+
+        SchemaDeduplicationPass::new()
+            .transform(&mut openapi)
+            .unwrap();
+
+        for path in ["/pets", "/users"] {
+            assert_eq!(
+                ref_location(&response_schema(&openapi, path)),
+                "#/components/schemas/ListPetsResponse"
+            );
+        }
+        assert!(
+            openapi
+                .components
+                .as_ref()
+                .unwrap()
+                .schemas
+                .contains_key("ErrorMessage")
+        );
+    }
+
+    #[test]
+    fn test_hoists_repeated_inline_bodies() {
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        for (path, id) in [("/pets", "listPets"), ("/users", "listUsers")] {
+            openapi.paths.paths.insert(
+                path.to_string(),
+                PathItem::new(
+                    HttpMethod::Get,
+                    operation_returning(id, RefOr::T(Schema::Object(message_object()))),
+                ),
+            );
+        }
+
+        SchemaDeduplicationPass::new()
+            .transform(&mut openapi)
+            .unwrap();
+
+        assert_eq!(
+            ref_location(&response_schema(&openapi, "/pets")),
+            "#/components/schemas/ListPetsResponse"
+        );
+        assert_eq!(
+            ref_location(&response_schema(&openapi, "/users")),
+            "#/components/schemas/ListPetsResponse"
+        );
+    }
+}
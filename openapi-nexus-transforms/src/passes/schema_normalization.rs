@@ -37,20 +37,16 @@ impl OpenApiTransformPass for SchemaNormalizationPass {
             for (_name, schema_ref) in components.schemas.iter_mut() {
                 if let utoipa::openapi::RefOr::T(schema) = schema_ref {
                     match schema {
-                        utoipa::openapi::Schema::Object(_obj_schema) => {
-                            // Normalize object properties
-                            if self.normalize_objects {
-                                // Ensure properties are sorted for consistency
-                                // This helps with deterministic output
-                                tracing::debug!("Normalizing object schema properties");
-                            }
+                        // Normalize object properties
+                        utoipa::openapi::Schema::Object(_obj_schema) if self.normalize_objects => {
+                            // Ensure properties are sorted for consistency
+                            // This helps with deterministic output
+                            tracing::debug!("Normalizing object schema properties");
                         }
-                        utoipa::openapi::Schema::Array(_arr_schema) => {
-                            // Normalize array schemas
-                            if self.normalize_arrays {
-                                tracing::debug!("Normalizing array schema");
-                                // Ensure array items are properly defined
-                            }
+                        // Normalize array schemas
+                        utoipa::openapi::Schema::Array(_arr_schema) if self.normalize_arrays => {
+                            tracing::debug!("Normalizing array schema");
+                            // Ensure array items are properly defined
                         }
                         _ => {
                             // Other schema types don't need normalization
@@ -69,6 +65,10 @@ impl OpenApiTransformPass for SchemaNormalizationPass {
 }
 
 impl TransformPass for SchemaNormalizationPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
     fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
         <Self as OpenApiTransformPass>::transform(self, openapi)
     }
@@ -0,0 +1,422 @@
+//! Spec filtering transformation pass
+//!
+//! Narrows a specification down to a subset of its operations, e.g. to publish
+//! separate public, partner and internal client packages from one spec.
+//! Components that are no longer reachable after filtering are pruned.
+
+use std::collections::BTreeMap;
+
+use openapi_nexus_ir::{ComponentUsage, SchemaAnalyzer, operation_slots_mut, operations_mut};
+use serde_json::Value;
+use utoipa::openapi::Deprecated;
+use utoipa::openapi::OpenApi;
+use utoipa::openapi::extensions::Extensions;
+use utoipa::openapi::path::Operation;
+
+use super::{OpenApiTransformPass, TransformError, TransformPass};
+
+/// A set of operation matching rules
+///
+/// Within one kind of criterion, such as tags, an operation only has to match
+/// one of the listed values. Include rules keep the operations matching every
+/// kind of criterion set, while exclude rules drop those matching any of them.
+#[derive(Debug, Clone, Default)]
+pub struct FilterRules {
+    /// Tags, matched exactly
+    pub tags: Vec<String>,
+    /// Path templates as glob patterns; `*` matches within one segment, `**` across segments
+    pub paths: Vec<String>,
+    /// OperationIds, matched exactly
+    pub operation_ids: Vec<String>,
+    /// HTTP methods, matched case-insensitively
+    pub methods: Vec<String>,
+    /// Match operations marked `deprecated: true`
+    pub deprecated: bool,
+    /// `x-` extensions and the value they must have, on the operation or its path item
+    pub extensions: BTreeMap<String, Value>,
+}
+
+impl FilterRules {
+    /// Whether no criteria are set
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+            && self.paths.is_empty()
+            && self.operation_ids.is_empty()
+            && self.methods.is_empty()
+            && !self.deprecated
+            && self.extensions.is_empty()
+    }
+
+    /// Whether an operation matches every kind of criterion set
+    fn matches_all(
+        &self,
+        path: &str,
+        method: &str,
+        path_extensions: Option<&Extensions>,
+        operation: &Operation,
+    ) -> bool {
+        self.criteria(path, method, path_extensions, operation)
+            .into_iter()
+            .flatten()
+            .all(|matched| matched)
+    }
+
+    /// Whether an operation matches any kind of criterion set
+    fn matches_any(
+        &self,
+        path: &str,
+        method: &str,
+        path_extensions: Option<&Extensions>,
+        operation: &Operation,
+    ) -> bool {
+        self.criteria(path, method, path_extensions, operation)
+            .into_iter()
+            .flatten()
+            .any(|matched| matched)
+    }
+
+    /// Whether an operation matches each kind of criterion, `None` for kinds
+    /// that are not set
+    fn criteria(
+        &self,
+        path: &str,
+        method: &str,
+        path_extensions: Option<&Extensions>,
+        operation: &Operation,
+    ) -> [Option<bool>; 6] {
+        let tag_match = operation
+            .tags
+            .iter()
+            .flatten()
+            .any(|tag| self.tags.contains(tag));
+        let path_match = self.paths.iter().any(|pattern| glob_match(pattern, path));
+        let operation_id_match = operation
+            .operation_id
+            .as_ref()
+            .is_some_and(|id| self.operation_ids.contains(id));
+        let method_match = self
+            .methods
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(method));
+        let deprecated_match = matches!(operation.deprecated, Some(Deprecated::True));
+        let extension_match = self.extensions.iter().any(|(key, expected)| {
+            [operation.extensions.as_ref(), path_extensions]
+                .into_iter()
+                .flatten()
+                .any(|extensions| extensions.get(key) == Some(expected))
+        });
+
+        [
+            (!self.tags.is_empty()).then_some(tag_match),
+            (!self.paths.is_empty()).then_some(path_match),
+            (!self.operation_ids.is_empty()).then_some(operation_id_match),
+            (!self.methods.is_empty()).then_some(method_match),
+            self.deprecated.then_some(deprecated_match),
+            (!self.extensions.is_empty()).then_some(extension_match),
+        ]
+    }
+}
+
+/// Spec filtering transformation pass
+///
+/// An operation is kept when it matches every kind of criterion of `include`
+/// (or `include` is empty) and no kind of criterion of `exclude`.
+#[derive(Debug, Clone)]
+pub struct SpecFilterPass {
+    pub include: FilterRules,
+    pub exclude: FilterRules,
+    /// Remove components that became unreachable through filtering
+    pub prune_components: bool,
+}
+
+impl Default for SpecFilterPass {
+    fn default() -> Self {
+        Self {
+            include: FilterRules::default(),
+            exclude: FilterRules::default(),
+            prune_components: true,
+        }
+    }
+}
+
+impl SpecFilterPass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn keeps(
+        &self,
+        path: &str,
+        method: &str,
+        path_extensions: Option<&Extensions>,
+        operation: &Operation,
+    ) -> bool {
+        (self.include.is_empty()
+            || self
+                .include
+                .matches_all(path, method, path_extensions, operation))
+            && !self
+                .exclude
+                .matches_any(path, method, path_extensions, operation)
+    }
+
+    fn prune_unreachable_components(openapi: &mut OpenApi, before: &ComponentUsage) {
+        let after = SchemaAnalyzer::new(openapi).analyze_component_usage();
+        let Some(components) = openapi.components.as_mut() else {
+            return;
+        };
+
+        // Only prune what filtering made unreachable; components that were unused
+        // to begin with are left alone
+        for name in after.unused_schemas.difference(&before.unused_schemas) {
+            tracing::debug!("Pruning unreachable schema '{}'", name);
+            components.schemas.remove(name);
+        }
+        for name in after.unused_responses.difference(&before.unused_responses) {
+            tracing::debug!("Pruning unreachable response '{}'", name);
+            components.responses.remove(name);
+        }
+    }
+}
+
+impl OpenApiTransformPass for SpecFilterPass {
+    fn name(&self) -> &str {
+        "spec-filter"
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        tracing::debug!("Filtering operations");
+
+        let before = SchemaAnalyzer::new(openapi).analyze_component_usage();
+        let mut removed = 0;
+
+        for (path, path_item) in openapi.paths.paths.iter_mut() {
+            let path_extensions = path_item.extensions.clone();
+            for (method, slot) in operation_slots_mut(path_item) {
+                let Some(operation) = slot.as_ref() else {
+                    continue;
+                };
+                if !self.keeps(path, method, path_extensions.as_ref(), operation) {
+                    tracing::debug!("Filtering out {} {}", method.to_uppercase(), path);
+                    *slot = None;
+                    removed += 1;
+                }
+            }
+        }
+
+        openapi
+            .paths
+            .paths
+            .retain(|_, path_item| operations_mut(path_item).next().is_some());
+
+        tracing::debug!("Filtered out {} operations", removed);
+
+        if self.prune_components {
+            Self::prune_unreachable_components(openapi, &before);
+        }
+
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        vec!["operation-id"]
+    }
+}
+
+impl TransformPass for SpecFilterPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        <Self as OpenApiTransformPass>::transform(self, openapi)
+    }
+}
+
+/// Match a path template against a glob pattern
+///
+/// `*` matches any run of characters except `/`, `**` matches any run of
+/// characters including `/`, and `?` matches a single non-`/` character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn matches(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern {
+            [] => text.is_empty(),
+            [b'*', b'*', rest @ ..] => (0..=text.len()).any(|i| matches(rest, &text[i..])),
+            [b'*', rest @ ..] => {
+                let segment_end = text.iter().position(|&c| c == b'/').unwrap_or(text.len());
+                (0..=segment_end).any(|i| matches(rest, &text[i..]))
+            }
+            [b'?', rest @ ..] => {
+                matches!(text.first(), Some(&c) if c != b'/') && matches(rest, &text[1..])
+            }
+            [c, rest @ ..] => text.first() == Some(c) && matches(rest, &text[1..]),
+        }
+    }
+
+    matches(pattern.as_bytes(), text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FilterRules, OpenApiTransformPass, SpecFilterPass, glob_match};
+    use serde_json::json;
+    use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem};
+    use utoipa::openapi::schema::Object;
+    use utoipa::openapi::{
+        Components, ContentBuilder, Info, OpenApi, Paths, Ref, RefOr, ResponseBuilder, Schema,
+    };
+
+    fn operation_returning(schema: &str, tag: &str) -> utoipa::openapi::path::Operation {
+        let response = ResponseBuilder::new()
+            .description("ok")
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(Ref::from_schema_name(schema)))
+                    .build(),
+            )
+            .build();
+        OperationBuilder::new()
+            .tag(tag)
+            .response("200", response)
+            .build()
+    }
+
+    fn create_test_openapi() -> OpenApi {
+        let mut components = Components::new();
+        for name in ["Pet", "AdminReport", "Orphan"] {
+            components
+                .schemas
+                .insert(name.to_string(), RefOr::T(Schema::Object(Object::new())));
+        }
+
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.paths.paths.insert(
+            "/pets".to_string(),
+            PathItem::new(HttpMethod::Get, operation_returning("Pet", "pets")),
+        );
+        let mut admin = operation_returning("AdminReport", "admin");
+        admin.extensions = Some([("x-internal", json!(true))].into_iter().collect());
+        openapi.paths.paths.insert(
+            "/admin/reports".to_string(),
+            PathItem::new(HttpMethod::Get, admin),
+        );
+        openapi.components = Some(components);
+        openapi
+    }
+
+    #[test]
+    fn test_spec_filter_pass_name() {
+        let pass = SpecFilterPass::new();
+        assert_eq!(pass.name(), "spec-filter");
+    }
+
+    #[test]
+    fn test_spec_filter_pass_dependencies() {
+        let pass = SpecFilterPass::new();
+        assert_eq!(pass.dependencies(), vec!["operation-id"]);
+    }
+
+    #[test]
+    fn test_exclude_by_extension_prunes_components() {
+        let mut openapi = create_test_openapi();
+        let pass = SpecFilterPass {
+            exclude: FilterRules {
+                extensions: [("x-internal".to_string(), json!(true))].into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        pass.transform(&mut openapi).unwrap();
+
+        assert!(!openapi.paths.paths.contains_key("/admin/reports"));
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("Pet"));
+        assert!(!schemas.contains_key("AdminReport"));
+        // Components unused before filtering are not touched
+        assert!(schemas.contains_key("Orphan"));
+    }
+
+    #[test]
+    fn test_include_by_tag_and_path_glob() {
+        let mut openapi = create_test_openapi();
+        let pass = SpecFilterPass {
+            include: FilterRules {
+                tags: vec!["admin".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        pass.transform(&mut openapi).unwrap();
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            vec!["/admin/reports"]
+        );
+
+        let mut openapi = create_test_openapi();
+        let pass = SpecFilterPass {
+            exclude: FilterRules {
+                paths: vec!["/admin/**".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        pass.transform(&mut openapi).unwrap();
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            vec!["/pets"]
+        );
+    }
+
+    #[test]
+    fn test_include_criteria_of_different_kinds_all_apply() {
+        let mut openapi = create_test_openapi();
+        openapi.paths.paths.insert(
+            "/pets/{petId}".to_string(),
+            PathItem::new(HttpMethod::Delete, operation_returning("Pet", "pets")),
+        );
+        let pass = SpecFilterPass {
+            include: FilterRules {
+                tags: vec!["pets".to_string(), "store".to_string()],
+                methods: vec!["get".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        pass.transform(&mut openapi).unwrap();
+
+        // Only GET operations tagged pets or store are kept
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            vec!["/pets"]
+        );
+    }
+
+    #[test]
+    fn test_exclude_criteria_of_different_kinds_any_apply() {
+        let mut openapi = create_test_openapi();
+        let pass = SpecFilterPass {
+            exclude: FilterRules {
+                tags: vec!["pets".to_string()],
+                extensions: [("x-internal".to_string(), json!(true))].into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        pass.transform(&mut openapi).unwrap();
+
+        assert!(openapi.paths.paths.is_empty());
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("/pets/*", "/pets/{petId}"));
+        assert!(!glob_match("/pets/*", "/pets/{petId}/photos"));
+        assert!(glob_match("/pets/**", "/pets/{petId}/photos"));
+        assert!(glob_match("/store/order?", "/store/orders"));
+        assert!(!glob_match("/store", "/store/orders"));
+    }
+}
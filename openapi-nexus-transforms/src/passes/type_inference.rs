@@ -1,12 +1,25 @@
 //! Type inference transformation pass
+//!
+//! Many specifications omit `type` and rely on `properties`, `enum`, `format` or
+//! validation keywords to imply it. This pass infers the missing type from those
+//! hints and writes it back to the specification.
+//!
+//! `items` is not among the hints: the OpenAPI model drops it from typeless
+//! schemas, so the parser types schemas declaring it as arrays instead.
+
+use openapi_nexus_ir::OpenApiTraverser;
+use serde_json::Value;
+use utoipa::openapi::schema::{KnownFormat, Object, SchemaFormat, SchemaType, Type};
+use utoipa::openapi::{OpenApi, RefOr, Schema};
 
 use crate::ir_context::IrContext;
 
-use super::{IrTransformPass, TransformError};
+use super::{IrTransformPass, TransformError, TransformPass};
 
 /// Type inference transformation pass
 #[derive(Default)]
 pub struct TypeInferencePass {
+    /// Fail instead of warning when the type hints of a schema conflict
     pub strict_mode: bool,
 }
 
@@ -14,6 +27,205 @@ impl TypeInferencePass {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Fill in the `type` of every typeless schema that carries enough hints
+    fn infer_missing_types(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        let mut conflicts = Vec::new();
+
+        OpenApiTraverser::walk_schemas_mut(openapi, |pointer, schema_ref| {
+            let RefOr::T(Schema::Object(obj)) = schema_ref else {
+                return;
+            };
+            if !obj.schema_type.is_any_value() {
+                return;
+            }
+
+            let hints = collect_type_hints(obj);
+            match resolve_type_hints(&hints) {
+                Some(inferred) => {
+                    tracing::debug!(
+                        "Inferred type '{}' for schema at {}",
+                        type_name(&inferred),
+                        pointer
+                    );
+                    obj.schema_type = if enum_allows_null(obj) {
+                        SchemaType::Array(vec![inferred, Type::Null])
+                    } else {
+                        SchemaType::Type(inferred)
+                    };
+                }
+                None if hints.is_empty() => {}
+                None => {
+                    let description = hints
+                        .iter()
+                        .map(|hint| format!("{} implies {}", hint.source, type_name(&hint.ty)))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    tracing::warn!(
+                        "Conflicting type hints for schema at {}: {}; leaving it untyped",
+                        pointer,
+                        description
+                    );
+                    conflicts.push(format!("{pointer} ({description})"));
+                }
+            }
+        });
+
+        if self.strict_mode && !conflicts.is_empty() {
+            return Err(TransformError::PassFailed {
+                pass: "type-inference".to_string(),
+                error: format!("conflicting type hints: {}", conflicts.join("; ")),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// A single structural hint about the type of a schema
+#[derive(Clone, PartialEq)]
+struct TypeHint {
+    ty: Type,
+    source: &'static str,
+}
+
+/// Gather all type hints from a typeless object schema
+fn collect_type_hints(obj: &Object) -> Vec<TypeHint> {
+    let mut hints = Vec::new();
+    let mut push = |ty: Type, source: &'static str| {
+        let hint = TypeHint { ty, source };
+        if !hints.contains(&hint) {
+            hints.push(hint);
+        }
+    };
+
+    if !obj.properties.is_empty()
+        || obj.additional_properties.is_some()
+        || !obj.required.is_empty()
+        || obj.max_properties.is_some()
+        || obj.min_properties.is_some()
+    {
+        push(Type::Object, "object keywords");
+    }
+
+    if obj.max_length.is_some()
+        || obj.min_length.is_some()
+        || obj.pattern.is_some()
+        || !obj.content_encoding.is_empty()
+        || !obj.content_media_type.is_empty()
+    {
+        push(Type::String, "string keywords");
+    }
+
+    if obj.minimum.is_some()
+        || obj.maximum.is_some()
+        || obj.exclusive_minimum.is_some()
+        || obj.exclusive_maximum.is_some()
+        || obj.multiple_of.is_some()
+    {
+        push(Type::Number, NUMERIC_KEYWORDS);
+    }
+
+    if let Some(SchemaFormat::KnownFormat(format)) = &obj.format {
+        push(format_type(format), "format");
+    }
+
+    if let Some(values) = &obj.enum_values {
+        for ty in values.iter().filter_map(value_type) {
+            push(ty, "enum values");
+        }
+    }
+
+    if let Some(ty) = obj.default.as_ref().and_then(value_type) {
+        push(ty, "default value");
+    }
+
+    hints
+}
+
+/// Source of numeric validation keywords, which fit both `integer` and `number`
+const NUMERIC_KEYWORDS: &str = "numeric keywords";
+
+/// Reduce a set of hints to a single type, or `None` when empty or conflicting
+fn resolve_type_hints(hints: &[TypeHint]) -> Option<Type> {
+    let first = hints.first()?;
+    let is_numeric = |ty: &Type| matches!(ty, Type::Integer | Type::Number);
+
+    if is_numeric(&first.ty) {
+        if !hints.iter().all(|hint| is_numeric(&hint.ty)) {
+            return None;
+        }
+        // An explicit fractional value or float format wins over integer hints
+        let explicit_number = hints
+            .iter()
+            .any(|hint| hint.ty == Type::Number && hint.source != NUMERIC_KEYWORDS);
+        let any_integer = hints.iter().any(|hint| hint.ty == Type::Integer);
+        return Some(if any_integer && !explicit_number {
+            Type::Integer
+        } else {
+            Type::Number
+        });
+    }
+
+    hints
+        .iter()
+        .all(|hint| hint.ty == first.ty)
+        .then(|| first.ty.clone())
+}
+
+/// Type implied by a known format
+fn format_type(format: &KnownFormat) -> Type {
+    match format {
+        KnownFormat::Int8
+        | KnownFormat::Int16
+        | KnownFormat::Int32
+        | KnownFormat::Int64
+        | KnownFormat::UInt8
+        | KnownFormat::UInt16
+        | KnownFormat::UInt32
+        | KnownFormat::UInt64 => Type::Integer,
+        KnownFormat::Float | KnownFormat::Double => Type::Number,
+        _ => Type::String,
+    }
+}
+
+/// Type of a JSON value, `None` for `null`
+fn value_type(value: &Value) -> Option<Type> {
+    match value {
+        Value::Null => None,
+        Value::Bool(_) => Some(Type::Boolean),
+        Value::Number(n) if n.is_i64() || n.is_u64() => Some(Type::Integer),
+        Value::Number(_) => Some(Type::Number),
+        Value::String(_) => Some(Type::String),
+        Value::Array(_) => Some(Type::Array),
+        Value::Object(_) => Some(Type::Object),
+    }
+}
+
+fn enum_allows_null(obj: &Object) -> bool {
+    obj.enum_values
+        .as_ref()
+        .is_some_and(|values| values.iter().any(Value::is_null))
+}
+
+fn type_name(ty: &Type) -> &'static str {
+    match ty {
+        Type::Object => "object",
+        Type::String => "string",
+        Type::Integer => "integer",
+        Type::Number => "number",
+        Type::Boolean => "boolean",
+        Type::Array => "array",
+        Type::Null => "null",
+    }
+}
+
+fn schema_type_name(schema_type: &SchemaType) -> String {
+    match schema_type {
+        SchemaType::Type(ty) => type_name(ty).to_string(),
+        SchemaType::Array(types) => types.iter().map(type_name).collect::<Vec<_>>().join("|"),
+        SchemaType::AnyValue => "unknown".to_string(),
+    }
 }
 
 impl IrTransformPass for TypeInferencePass {
@@ -26,26 +238,29 @@ impl IrTransformPass for TypeInferencePass {
 
         use openapi_nexus_ir::Analyzer;
 
+        self.infer_missing_types(&mut ir.openapi)?;
+
         // Get all schemas from the OpenAPI spec
         let schemas = Analyzer::get_all_schemas(&ir.openapi);
 
         for (name, schema_ref) in schemas {
-            // Infer the type based on the schema structure
+            // Record the (possibly inferred) type of each component schema
             let inferred_type = match schema_ref {
-                utoipa::openapi::RefOr::T(schema) => match schema {
-                    utoipa::openapi::Schema::Object(_) => "object",
-                    utoipa::openapi::Schema::Array(_) => "array",
-                    utoipa::openapi::Schema::OneOf(_) => "oneOf",
-                    utoipa::openapi::Schema::AllOf(_) => "allOf",
-                    _ => "unknown",
+                RefOr::T(schema) => match schema {
+                    Schema::Object(obj) => schema_type_name(&obj.schema_type),
+                    Schema::Array(_) => "array".to_string(),
+                    Schema::OneOf(_) => "oneOf".to_string(),
+                    Schema::AllOf(_) => "allOf".to_string(),
+                    Schema::AnyOf(_) => "anyOf".to_string(),
+                    _ => "unknown".to_string(),
                 },
-                utoipa::openapi::RefOr::Ref(_) => "reference",
+                RefOr::Ref(_) => "reference".to_string(),
             };
 
             tracing::debug!("Inferred type for schema '{}': {}", name, inferred_type);
             ir.schema_analysis
                 .schema_types
-                .insert(name.clone(), inferred_type.to_string());
+                .insert(name.clone(), inferred_type);
         }
 
         // Initialize type mappings for common languages
@@ -87,10 +302,46 @@ impl IrTransformPass for TypeInferencePass {
     }
 }
 
+impl TransformPass for TypeInferencePass {
+    fn name(&self) -> &str {
+        <Self as IrTransformPass>::name(self)
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        self.infer_missing_types(openapi)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{IrTransformPass, TypeInferencePass};
-    // utoipa types available for tests if needed
+    use crate::ir_context::IrContext;
+    use serde_json::json;
+    use utoipa::openapi::schema::{KnownFormat, Object, SchemaFormat, SchemaType, Type};
+    use utoipa::openapi::{Components, Info, OpenApi, Paths, RefOr, Schema};
+
+    fn context_with_schema(name: &str, schema: Object) -> IrContext {
+        let mut components = Components::new();
+        components
+            .schemas
+            .insert(name.to_string(), RefOr::T(Schema::Object(schema)));
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+        IrContext::new(openapi)
+    }
+
+    fn typeless() -> Object {
+        let mut obj = Object::new();
+        obj.schema_type = SchemaType::AnyValue;
+        obj
+    }
+
+    fn schema_type_of(ir: &IrContext, name: &str) -> SchemaType {
+        match &ir.openapi.components.as_ref().unwrap().schemas[name] {
+            RefOr::T(Schema::Object(obj)) => obj.schema_type.clone(),
+            _ => panic!("expected object schema"),
+        }
+    }
 
     #[test]
     fn test_type_inference_pass_name() {
@@ -104,4 +355,93 @@ mod tests {
         let deps = pass.dependencies();
         assert_eq!(deps, vec!["schema-normalization", "reference-resolution"]);
     }
+
+    #[test]
+    fn test_infers_object_from_properties() {
+        let mut pet = typeless();
+        pet.properties
+            .insert("name".to_string(), RefOr::T(Schema::Object(typeless())));
+        let mut ir = context_with_schema("Pet", pet);
+
+        TypeInferencePass::new().transform(&mut ir).unwrap();
+
+        assert!(schema_type_of(&ir, "Pet") == SchemaType::Type(Type::Object));
+        assert_eq!(ir.schema_analysis.schema_types["Pet"], "object");
+    }
+
+    #[test]
+    fn test_infers_enum_value_kinds() {
+        let mut status = typeless();
+        status.enum_values = Some(vec![json!("available"), json!("sold"), json!(null)]);
+        let mut priority = typeless();
+        priority.enum_values = Some(vec![json!(1), json!(2)]);
+        let mut ir = context_with_schema("Status", status);
+        ir.openapi
+            .components
+            .as_mut()
+            .unwrap()
+            .schemas
+            .insert("Priority".to_string(), RefOr::T(Schema::Object(priority)));
+
+        TypeInferencePass::new().transform(&mut ir).unwrap();
+
+        assert_eq!(ir.schema_analysis.schema_types["Status"], "string|null");
+        assert_eq!(ir.schema_analysis.schema_types["Priority"], "integer");
+    }
+
+    #[test]
+    fn test_infers_numeric_type_from_format() {
+        let mut id = typeless();
+        id.format = Some(SchemaFormat::KnownFormat(KnownFormat::Int64));
+        id.minimum = Some(1.into());
+        let mut ir = context_with_schema("Id", id);
+
+        TypeInferencePass::new().transform(&mut ir).unwrap();
+
+        assert!(schema_type_of(&ir, "Id") == SchemaType::Type(Type::Integer));
+    }
+
+    #[test]
+    fn test_conflicting_hints_leave_schema_untyped() {
+        let mut odd = typeless();
+        odd.format = Some(SchemaFormat::KnownFormat(KnownFormat::DateTime));
+        odd.maximum = Some(10.into());
+        let mut ir = context_with_schema("Odd", odd);
+
+        TypeInferencePass::new().transform(&mut ir).unwrap();
+
+        assert!(schema_type_of(&ir, "Odd").is_any_value());
+        assert_eq!(ir.schema_analysis.schema_types["Odd"], "unknown");
+    }
+
+    #[test]
+    fn test_conflicting_hints_fail_in_strict_mode() {
+        let mut odd = typeless();
+        odd.enum_values = Some(vec![json!("a"), json!(1)]);
+        let mut ir = context_with_schema("Odd", odd);
+
+        let pass = TypeInferencePass { strict_mode: true };
+        assert!(pass.transform(&mut ir).is_err());
+    }
+
+    #[test]
+    fn test_infers_nested_property_types() {
+        let mut name = typeless();
+        name.max_length = Some(64);
+        let mut pet = Object::new();
+        pet.properties
+            .insert("name".to_string(), RefOr::T(Schema::Object(name)));
+        let mut ir = context_with_schema("Pet", pet);
+
+        TypeInferencePass::new().transform(&mut ir).unwrap();
+
+        let RefOr::T(Schema::Object(pet)) = &ir.openapi.components.as_ref().unwrap().schemas["Pet"]
+        else {
+            panic!("expected object schema");
+        };
+        let RefOr::T(Schema::Object(name)) = &pet.properties["name"] else {
+            panic!("expected object schema");
+        };
+        assert!(name.schema_type == SchemaType::Type(Type::String));
+    }
 }
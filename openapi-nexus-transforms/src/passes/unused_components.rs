@@ -0,0 +1,124 @@
+//! Unused component detection transformation pass
+
+use openapi_nexus_ir::SchemaAnalyzer;
+use utoipa::openapi::OpenApi;
+
+use super::{OpenApiTransformPass, TransformError, TransformPass};
+
+/// Reports, and optionally removes, components not reachable from any operation
+pub struct UnusedComponentsPass {
+    /// Remove unreachable components instead of only reporting them
+    pub remove: bool,
+}
+
+impl Default for UnusedComponentsPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UnusedComponentsPass {
+    pub fn new() -> Self {
+        Self { remove: false }
+    }
+}
+
+impl OpenApiTransformPass for UnusedComponentsPass {
+    fn name(&self) -> &str {
+        "unused-components"
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        tracing::debug!("Detecting unused components");
+
+        let usage = SchemaAnalyzer::new(openapi).analyze_component_usage();
+        if usage.is_fully_used() {
+            return Ok(());
+        }
+
+        for name in &usage.unused_schemas {
+            tracing::info!("Schema '{}' is not reachable from any operation", name);
+        }
+        for name in &usage.unused_responses {
+            tracing::info!("Response '{}' is not reachable from any operation", name);
+        }
+
+        if self.remove
+            && let Some(components) = openapi.components.as_mut()
+        {
+            components
+                .schemas
+                .retain(|name, _| !usage.unused_schemas.contains(name));
+            components
+                .responses
+                .retain(|name, _| !usage.unused_responses.contains(name));
+            tracing::debug!(
+                "Removed {} unused schemas and {} unused responses",
+                usage.unused_schemas.len(),
+                usage.unused_responses.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<&str> {
+        vec!["reference-resolution"]
+    }
+}
+
+impl TransformPass for UnusedComponentsPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
+    fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
+        <Self as OpenApiTransformPass>::transform(self, openapi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OpenApiTransformPass, UnusedComponentsPass};
+    use utoipa::openapi::schema::Object;
+    use utoipa::openapi::{Components, Info, OpenApi, Paths, RefOr, Schema};
+
+    fn create_test_openapi() -> OpenApi {
+        let mut components = Components::new();
+        components.schemas.insert(
+            "Orphan".to_string(),
+            RefOr::T(Schema::Object(Object::new())),
+        );
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.components = Some(components);
+        openapi
+    }
+
+    #[test]
+    fn test_unused_components_pass_name() {
+        let pass = UnusedComponentsPass::new();
+        assert_eq!(pass.name(), "unused-components");
+    }
+
+    #[test]
+    fn test_unused_components_pass_dependencies() {
+        let pass = UnusedComponentsPass::new();
+        assert_eq!(pass.dependencies(), vec!["reference-resolution"]);
+    }
+
+    #[test]
+    fn test_reports_without_removing_by_default() {
+        let mut openapi = create_test_openapi();
+        UnusedComponentsPass::new().transform(&mut openapi).unwrap();
+        assert!(openapi.components.unwrap().schemas.contains_key("Orphan"));
+    }
+
+    #[test]
+    fn test_removes_when_enabled() {
+        let mut openapi = create_test_openapi();
+        UnusedComponentsPass { remove: true }
+            .transform(&mut openapi)
+            .unwrap();
+        assert!(openapi.components.unwrap().schemas.is_empty());
+    }
+}
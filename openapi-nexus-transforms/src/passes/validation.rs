@@ -55,6 +55,10 @@ impl OpenApiTransformPass for ValidationPass {
 }
 
 impl TransformPass for ValidationPass {
+    fn name(&self) -> &str {
+        <Self as OpenApiTransformPass>::name(self)
+    }
+
     fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
         <Self as OpenApiTransformPass>::transform(self, openapi)
     }
@@ -1,18 +1,28 @@
 //! Transformation pipeline for OpenAPI specifications
 
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde_json::Value;
 use utoipa::openapi::OpenApi;
 
+use crate::instrumentation::{Instrumentation, PassReport, PipelineReport, SpecDiff};
 use crate::passes::{TransformError, TransformPass};
 
 /// Pipeline for applying multiple transformation passes
 pub struct TransformPipeline {
     passes: Vec<Box<dyn TransformPass>>,
+    instrumentation: Instrumentation,
 }
 
 impl TransformPipeline {
     /// Create a new transformation pipeline
     pub fn new() -> Self {
-        Self { passes: Vec::new() }
+        Self {
+            passes: Vec::new(),
+            instrumentation: Instrumentation::default(),
+        }
     }
 
     /// Add a transformation pass to the pipeline
@@ -21,12 +31,108 @@ impl TransformPipeline {
         self
     }
 
+    /// Configure diffing and snapshots for pipeline runs
+    pub fn with_instrumentation(mut self, instrumentation: Instrumentation) -> Self {
+        self.instrumentation = instrumentation;
+        self
+    }
+
+    /// Names of the passes, in execution order
+    pub fn pass_names(&self) -> Vec<&str> {
+        self.passes.iter().map(|pass| pass.name()).collect()
+    }
+
     /// Apply all transformation passes to the OpenAPI specification
     pub fn transform(&self, openapi: &mut OpenApi) -> Result<(), TransformError> {
-        for pass in &self.passes {
+        self.transform_with_report(openapi).map(|_| ())
+    }
+
+    /// Apply all transformation passes and report what each of them did
+    pub fn transform_with_report(
+        &self,
+        openapi: &mut OpenApi,
+    ) -> Result<PipelineReport, TransformError> {
+        self.run(openapi, self.instrumentation.snapshot_dir.clone())
+    }
+
+    /// Apply all transformation passes for one target, such as a language, and
+    /// report what each of them did
+    ///
+    /// Snapshots go to a subdirectory named after the target, so runs for
+    /// several targets sharing the pipeline do not overwrite each other's.
+    pub fn transform_with_report_for(
+        &self,
+        openapi: &mut OpenApi,
+        target: &str,
+    ) -> Result<PipelineReport, TransformError> {
+        let snapshot_dir = self
+            .instrumentation
+            .snapshot_dir
+            .as_ref()
+            .map(|dir| dir.join(target));
+        self.run(openapi, snapshot_dir)
+    }
+
+    fn run(
+        &self,
+        openapi: &mut OpenApi,
+        snapshot_dir: Option<PathBuf>,
+    ) -> Result<PipelineReport, TransformError> {
+        let mut report = PipelineReport::default();
+
+        let mut before = if self.instrumentation.needs_serialization() {
+            Some(serialize(openapi)?)
+        } else {
+            None
+        };
+        if let (Some(dir), Some(value)) = (&snapshot_dir, &before) {
+            write_snapshot(dir, "00-input.json", value)?;
+        }
+
+        for (index, pass) in self.passes.iter().enumerate() {
+            let started = Instant::now();
             pass.transform(openapi)?;
+            let duration = started.elapsed();
+
+            let mut pass_report = PassReport {
+                name: pass.name().to_string(),
+                duration,
+                diff: None,
+                snapshot: None,
+            };
+
+            if let Some(previous) = before.take() {
+                let after = serialize(openapi)?;
+                if self.instrumentation.diff {
+                    pass_report.diff = Some(SpecDiff::between(&previous, &after));
+                }
+                if let Some(dir) = &snapshot_dir {
+                    let filename = format!("{:02}-{}.json", index + 1, pass.name());
+                    pass_report.snapshot = Some(write_snapshot(dir, &filename, &after)?);
+                }
+                before = Some(after);
+            }
+
+            match &pass_report.diff {
+                Some(diff) => tracing::debug!(
+                    "Pass '{}' took {:?}: {} added, {} removed, {} changed",
+                    pass_report.name,
+                    duration,
+                    diff.added.len(),
+                    diff.removed.len(),
+                    diff.changed.len()
+                ),
+                None => tracing::debug!("Pass '{}' took {:?}", pass_report.name, duration),
+            }
+            report.passes.push(pass_report);
         }
-        Ok(())
+
+        tracing::debug!(
+            "Transformation pipeline finished in {:?}",
+            report.total_duration()
+        );
+
+        Ok(report)
     }
 }
 
@@ -35,3 +141,140 @@ impl Default for TransformPipeline {
         Self::new()
     }
 }
+
+fn serialize(openapi: &OpenApi) -> Result<Value, TransformError> {
+    serde_json::to_value(openapi).map_err(|e| TransformError::Generic {
+        message: format!("Failed to serialize specification: {}", e),
+    })
+}
+
+fn write_snapshot(dir: &Path, filename: &str, value: &Value) -> Result<PathBuf, TransformError> {
+    let path = dir.join(filename);
+    let snapshot_error = |message: String| TransformError::Snapshot {
+        path: path.display().to_string(),
+        message,
+    };
+
+    fs::create_dir_all(dir).map_err(|e| snapshot_error(e.to_string()))?;
+    let content = serde_json::to_string_pretty(value).map_err(|e| snapshot_error(e.to_string()))?;
+    fs::write(&path, content).map_err(|e| snapshot_error(e.to_string()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransformPipeline;
+    use crate::instrumentation::Instrumentation;
+    use crate::passes::{OperationIdPass, TransformError, TransformPass, ValidationPass};
+    use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem};
+    use utoipa::openapi::{Info, OpenApi, Paths};
+
+    fn create_test_openapi() -> OpenApi {
+        let mut openapi = OpenApi::new(Info::new("Test API", "1.0.0"), Paths::new());
+        openapi.paths.paths.insert(
+            "/pets".to_string(),
+            PathItem::new(HttpMethod::Get, OperationBuilder::new().build()),
+        );
+        openapi
+    }
+
+    fn create_test_pipeline() -> TransformPipeline {
+        TransformPipeline::new()
+            .add_pass(ValidationPass::new())
+            .add_pass(OperationIdPass::new())
+    }
+
+    #[test]
+    fn test_pass_names() {
+        assert_eq!(
+            create_test_pipeline().pass_names(),
+            vec!["validation", "operation-id"]
+        );
+    }
+
+    /// Pass relying on the default name
+    struct SortTagsPass;
+
+    impl TransformPass for SortTagsPass {
+        fn transform(&self, _openapi: &mut OpenApi) -> Result<(), TransformError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_default_pass_name() {
+        let pipeline = create_test_pipeline().add_pass(SortTagsPass);
+        assert_eq!(
+            pipeline.pass_names(),
+            vec!["validation", "operation-id", "SortTagsPass"]
+        );
+    }
+
+    #[test]
+    fn test_report_without_instrumentation_has_timings_only() {
+        let mut openapi = create_test_openapi();
+        let report = create_test_pipeline()
+            .transform_with_report(&mut openapi)
+            .unwrap();
+
+        assert_eq!(report.passes.len(), 2);
+        assert!(report.passes.iter().all(|pass| pass.diff.is_none()));
+    }
+
+    #[test]
+    fn test_report_with_diff_and_snapshots() {
+        let dir =
+            std::env::temp_dir().join(format!("openapi_nexus_snapshots_{}", std::process::id()));
+        let pipeline = create_test_pipeline().with_instrumentation(Instrumentation {
+            diff: true,
+            snapshot_dir: Some(dir.clone()),
+        });
+
+        let mut openapi = create_test_openapi();
+        let report = pipeline.transform_with_report(&mut openapi).unwrap();
+
+        let validation = &report.passes[0];
+        assert!(validation.diff.as_ref().unwrap().is_empty());
+        let operation_id = &report.passes[1];
+        assert_eq!(
+            operation_id.diff.as_ref().unwrap().added,
+            vec!["#/paths/~1pets/get/operationId"]
+        );
+        assert!(dir.join("00-input.json").exists());
+        assert_eq!(
+            operation_id.snapshot.as_deref(),
+            Some(dir.join("02-operation-id.json").as_path())
+        );
+        assert!(dir.join("02-operation-id.json").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_snapshots_per_target() {
+        let dir = std::env::temp_dir().join(format!(
+            "openapi_nexus_target_snapshots_{}",
+            std::process::id()
+        ));
+        let pipeline = create_test_pipeline().with_instrumentation(Instrumentation {
+            diff: false,
+            snapshot_dir: Some(dir.clone()),
+        });
+
+        for target in ["typescript", "rust"] {
+            let mut openapi = create_test_openapi();
+            pipeline
+                .transform_with_report_for(&mut openapi, target)
+                .unwrap();
+        }
+
+        for target in ["typescript", "rust"] {
+            assert!(dir.join(target).join("00-input.json").exists());
+            assert!(dir.join(target).join("02-operation-id.json").exists());
+        }
+        assert!(!dir.join("00-input.json").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -8,14 +8,17 @@ pub mod ty;
 
 pub use class_definition::{
     TsClassDefinition, TsClassImportSpecifier, TsClassMethod, TsClassProperty, TsClassSignature,
-    TsImportStatement,
+    TsImportStatement, TsOperationError, TsOperationResult, TsOperationServers, TsResponseStatus,
+    TsServer, TsServerVariable, TsStatusResponse,
+};
+pub use common::{
+    TsDocComment, TsEnumVariant, TsGeneric, TsParameter, TsProperty, TsValidation, TsVisibility,
 };
-pub use common::{TsDocComment, TsEnumVariant, TsGeneric, TsParameter, TsProperty, TsVisibility};
 pub use file::{TsFile, TsFileCategory, TsFileContent};
 pub use import::{TsImport, TsImportSpecifier};
 pub use ts_expression::TsExpression;
 pub use ts_node::TsNode;
 pub use ty::{
-    TsEnumDefinition, TsInterfaceDefinition, TsInterfaceSignature, TsPrimitive,
+    TsDiscriminator, TsEnumDefinition, TsInterfaceDefinition, TsInterfaceSignature, TsPrimitive,
     TsTypeAliasDefinition, TsTypeDefinition,
 };
@@ -6,6 +6,10 @@ pub mod ts_class_method;
 pub mod ts_class_property;
 pub mod ts_class_signature;
 pub mod ts_import_statement;
+pub mod ts_operation_error;
+pub mod ts_operation_result;
+pub mod ts_server;
+pub mod ts_status_response;
 
 pub use ts_class_definition::TsClassDefinition;
 pub use ts_class_import_specifier::TsClassImportSpecifier;
@@ -13,3 +17,7 @@ pub use ts_class_method::TsClassMethod;
 pub use ts_class_property::TsClassProperty;
 pub use ts_class_signature::TsClassSignature;
 pub use ts_import_statement::TsImportStatement;
+pub use ts_operation_error::TsOperationError;
+pub use ts_operation_result::TsOperationResult;
+pub use ts_server::{TsOperationServers, TsServer, TsServerVariable};
+pub use ts_status_response::{TsResponseStatus, TsStatusResponse};
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use super::{TsClassMethod, TsClassProperty, TsImportStatement};
+use super::{
+    TsClassMethod, TsClassProperty, TsImportStatement, TsOperationError, TsOperationResult,
+    TsOperationServers,
+};
 use crate::ast::{TsClassSignature, TsDocComment, TsGeneric};
 
 /// TypeScript class definition for template rendering
@@ -11,6 +14,15 @@ pub struct TsClassDefinition {
     pub methods: Vec<TsClassMethod>,
     pub documentation: Option<TsDocComment>,
     pub imports: Vec<TsImportStatement>,
+    /// Typed errors of the operations, rendered before the class
+    #[serde(default)]
+    pub operation_errors: Vec<TsOperationError>,
+    /// Status-keyed results of operations with several success responses
+    #[serde(default)]
+    pub operation_results: Vec<TsOperationResult>,
+    /// Servers of operations that override the API's ones
+    #[serde(default)]
+    pub operation_servers: Vec<TsOperationServers>,
 }
 
 impl TsClassDefinition {
@@ -28,6 +40,9 @@ impl TsClassDefinition {
             methods: Vec::new(),
             documentation: None,
             imports: Vec::new(),
+            operation_errors: Vec::new(),
+            operation_results: Vec::new(),
+            operation_servers: Vec::new(),
         }
     }
 
@@ -55,6 +70,24 @@ impl TsClassDefinition {
         self
     }
 
+    /// Add typed operation errors
+    pub fn with_operation_errors(mut self, operation_errors: Vec<TsOperationError>) -> Self {
+        self.operation_errors.extend(operation_errors);
+        self
+    }
+
+    /// Add status-keyed operation results
+    pub fn with_operation_results(mut self, operation_results: Vec<TsOperationResult>) -> Self {
+        self.operation_results.extend(operation_results);
+        self
+    }
+
+    /// Add servers overriding the API's ones for some operations
+    pub fn with_operation_servers(mut self, operation_servers: Vec<TsOperationServers>) -> Self {
+        self.operation_servers.extend(operation_servers);
+        self
+    }
+
     /// Set extends clause
     pub fn with_extends(mut self, extends: String) -> Self {
         self.signature.extends = Some(extends);
@@ -14,6 +14,8 @@ pub struct TsClassMethod {
     pub visibility: TsVisibility,
     pub is_static: bool,
     pub is_async: bool,
+    #[serde(default)]
+    pub is_generator: bool,
     pub is_abstract: bool,
     pub documentation: Option<TsDocComment>,
     pub body_template: Option<String>,
@@ -30,6 +32,7 @@ impl TsClassMethod {
             visibility: TsVisibility::Public,
             is_static: false,
             is_async: false,
+            is_generator: false,
             is_abstract: false,
             documentation: None,
             body_template: None,
@@ -67,6 +70,12 @@ impl TsClassMethod {
         self
     }
 
+    /// Make a generator, yielding its values
+    pub fn with_generator(mut self) -> Self {
+        self.is_generator = true;
+        self
+    }
+
     /// Make abstract
     pub fn with_abstract(mut self) -> Self {
         self.is_abstract = true;
@@ -131,7 +140,12 @@ impl ToRcDocWithContext for TsClassMethod {
             ))
             .append(RcDoc::text(")"));
 
-        let mut signature_doc = RcDoc::text(self.name.clone()).append(params_doc);
+        let name = if self.is_generator {
+            format!("*{}", self.name)
+        } else {
+            self.name.clone()
+        };
+        let mut signature_doc = RcDoc::text(name).append(params_doc);
 
         // Return type
         if let Some(return_type) = &self.return_type {
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use super::ts_status_response::assign_status_types;
+use super::{TsResponseStatus, TsStatusResponse};
+
+/// Status classes that reach the error of an operation: every non-2xx one
+const ERROR_STATUS_CLASSES: [u16; 4] = [1, 3, 4, 5];
+
+/// Error responses of an API operation
+///
+/// Rendered as a `{name}Response` union keyed by status and a `{name}` class
+/// extending `ResponseError` that parses the body of each status. The last
+/// response is always the `default` one, which also covers undocumented statuses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsOperationError {
+    pub name: String,
+    /// Name of the API method the errors belong to
+    pub operation: String,
+    pub responses: Vec<TsStatusResponse>,
+}
+
+impl TsOperationError {
+    /// Create the errors of an operation from its documented error responses
+    ///
+    /// Responses are ordered codes first, then ranges, then `default`; without
+    /// a documented `default`, one with an untyped body is added. The status
+    /// type of each leaves out the statuses of the others.
+    pub fn new(name: String, operation: String, mut responses: Vec<TsStatusResponse>) -> Self {
+        responses.sort_by_key(|response| response.status);
+        if responses.last().map(|response| response.status) != Some(TsResponseStatus::Default) {
+            responses.push(TsStatusResponse {
+                status: TsResponseStatus::Default,
+                status_type: String::new(),
+                body_type: "unknown".to_string(),
+                parse_expr: "await readErrorBody(response)".to_string(),
+                description: None,
+            });
+        }
+        assign_status_types(&mut responses, &ERROR_STATUS_CLASSES);
+        Self {
+            name,
+            operation,
+            responses,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use super::TsStatusResponse;
+use super::ts_status_response::assign_status_types;
+
+/// Success responses of an API operation that documents more than one
+///
+/// Rendered as a `{name}` union keyed by status and a `{name}FromResponse`
+/// function that parses the body of each status. Undocumented statuses are
+/// rejected with a `ResponseError`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsOperationResult {
+    pub name: String,
+    /// Name of the API method the responses belong to
+    pub operation: String,
+    pub responses: Vec<TsStatusResponse>,
+}
+
+impl TsOperationResult {
+    /// Create the result of an operation from its documented success responses,
+    /// ordered codes first, then ranges, the status type of each leaving out
+    /// the statuses of the others
+    pub fn new(name: String, operation: String, mut responses: Vec<TsStatusResponse>) -> Self {
+        responses.sort_by_key(|response| response.status);
+        assign_status_types(&mut responses, &[2]);
+        Self {
+            name,
+            operation,
+            responses,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::Server;
+
+/// Server an API, or some of its operations, is available on
+///
+/// Rendered as a runtime `ServerConfiguration` whose URL template may contain
+/// `{variable}` placeholders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsServer {
+    pub url: String,
+    pub description: Option<String>,
+    pub variables: Vec<TsServerVariable>,
+}
+
+/// Variable of a server URL template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsServerVariable {
+    pub name: String,
+    pub default: String,
+    /// Allowed values, typed as a literal union when present
+    pub enum_values: Vec<String>,
+    pub description: Option<String>,
+}
+
+/// Servers overriding the API's ones for a single operation
+///
+/// Rendered as an exported `{name}` array the operation sends its requests to
+/// the first server of.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsOperationServers {
+    pub name: String,
+    /// Name of the API method the servers belong to
+    pub operation: String,
+    pub servers: Vec<TsServer>,
+}
+
+impl TsServer {
+    /// URL with every variable replaced by its default value
+    pub fn default_url(&self) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.clone(), |url, variable| {
+                url.replace(&format!("{{{}}}", variable.name), &variable.default)
+            })
+    }
+}
+
+impl From<&Server> for TsServer {
+    fn from(server: &Server) -> Self {
+        Self {
+            url: server.url.clone(),
+            description: server.description.clone(),
+            variables: server
+                .variables
+                .iter()
+                .flatten()
+                .map(|(name, variable)| TsServerVariable {
+                    name: name.clone(),
+                    default: variable.default_value.clone(),
+                    enum_values: variable.enum_values.clone().unwrap_or_default(),
+                    description: variable.description.clone(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+/// Status codes a documented response applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum TsResponseStatus {
+    /// A single status code, e.g. `404`
+    Code(u16),
+    /// A status class, e.g. `4XX`, given by its first digit
+    Range(u16),
+    /// The `default` response
+    Default,
+}
+
+impl TsResponseStatus {
+    /// TypeScript type of the statuses this response is parsed for, among the
+    /// status classes the responses cover
+    ///
+    /// Codes documented next to a range or `default` are left out of its type,
+    /// as are the classes of documented ranges, so that comparing the status
+    /// with a code narrows the union down to that code's response.
+    pub fn status_type(self, siblings: &[TsResponseStatus], classes: &[u16]) -> String {
+        let classes: Vec<u16> = match self {
+            TsResponseStatus::Code(code) => return code.to_string(),
+            TsResponseStatus::Range(class) => vec![class],
+            TsResponseStatus::Default => classes
+                .iter()
+                .copied()
+                .filter(|class| !siblings.contains(&TsResponseStatus::Range(*class)))
+                .collect(),
+        };
+        if classes.is_empty() {
+            return "never".to_string();
+        }
+        let codes: Vec<String> = siblings
+            .iter()
+            .filter_map(|status| match status {
+                TsResponseStatus::Code(code) if classes.contains(&(code / 100)) => {
+                    Some(code.to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        let class_names: Vec<String> = classes.iter().map(u16::to_string).collect();
+        let status_codes = format!("StatusCodes[{}]", class_names.join(" | "));
+        if codes.is_empty() {
+            status_codes
+        } else {
+            format!("Exclude<{}, {}>", status_codes, codes.join(" | "))
+        }
+    }
+}
+
+/// One documented response of an operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsStatusResponse {
+    pub status: TsResponseStatus,
+    /// TypeScript type of the status, set from the other documented responses
+    /// by the result or error the response belongs to
+    pub status_type: String,
+    /// TypeScript type of the parsed body
+    pub body_type: String,
+    /// Expression parsing the body of `response`
+    pub parse_expr: String,
+    pub description: Option<String>,
+}
+
+/// Set the status type of each response, among the given status classes
+pub(super) fn assign_status_types(responses: &mut [TsStatusResponse], classes: &[u16]) {
+    let statuses: Vec<TsResponseStatus> =
+        responses.iter().map(|response| response.status).collect();
+    for response in responses {
+        response.status_type = response.status.status_type(&statuses, classes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TsResponseStatus;
+
+    #[test]
+    fn test_status_types() {
+        let statuses = [
+            TsResponseStatus::Code(404),
+            TsResponseStatus::Code(503),
+            TsResponseStatus::Range(5),
+            TsResponseStatus::Default,
+        ];
+        let classes = [1, 3, 4, 5];
+        assert_eq!(statuses[0].status_type(&statuses, &classes), "404");
+        assert_eq!(
+            statuses[2].status_type(&statuses, &classes),
+            "Exclude<StatusCodes[5], 503>"
+        );
+        assert_eq!(
+            statuses[3].status_type(&statuses, &classes),
+            "Exclude<StatusCodes[1 | 3 | 4], 404>"
+        );
+        assert_eq!(
+            TsResponseStatus::Default.status_type(&[TsResponseStatus::Default], &[4]),
+            "StatusCodes[4]"
+        );
+        assert_eq!(
+            TsResponseStatus::Default.status_type(&[TsResponseStatus::Range(4)], &[4]),
+            "never"
+        );
+    }
+}
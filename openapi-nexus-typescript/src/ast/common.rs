@@ -5,6 +5,7 @@ pub mod ts_enum_variant;
 pub mod ts_generic;
 pub mod ts_parameter;
 pub mod ts_property;
+pub mod ts_validation;
 pub mod ts_visibility;
 
 pub use ts_doc_comment::TsDocComment;
@@ -12,4 +13,5 @@ pub use ts_enum_variant::TsEnumVariant;
 pub use ts_generic::TsGeneric;
 pub use ts_parameter::TsParameter;
 pub use ts_property::TsProperty;
+pub use ts_validation::TsValidation;
 pub use ts_visibility::TsVisibility;
@@ -1,7 +1,7 @@
 use pretty::RcDoc;
 use serde::{Deserialize, Serialize};
 
-use crate::ast::{TsDocComment, TsExpression};
+use crate::ast::{TsDocComment, TsExpression, TsValidation};
 use crate::emission::error::EmitError;
 use openapi_nexus_core::traits::{EmissionContext, ToRcDocWithContext};
 
@@ -12,6 +12,12 @@ pub struct TsProperty {
     pub type_expr: TsExpression,
     pub optional: bool,
     pub documentation: Option<TsDocComment>,
+    /// OpenAPI format of the value, or of its items for arrays
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Constraints the value is validated against at runtime
+    #[serde(default)]
+    pub validation: TsValidation,
 }
 
 impl TsProperty {
@@ -22,6 +28,8 @@ impl TsProperty {
             type_expr,
             optional: false,
             documentation: None,
+            format: None,
+            validation: TsValidation::default(),
         }
     }
 
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+
+/// Constraints a property value is validated against at runtime
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TsValidation {
+    pub max_length: Option<usize>,
+    pub min_length: Option<usize>,
+    pub pattern: Option<String>,
+    pub maximum: Option<serde_json::Number>,
+    pub exclusive_maximum: bool,
+    pub minimum: Option<serde_json::Number>,
+    pub exclusive_minimum: bool,
+    pub multiple_of: Option<serde_json::Number>,
+    pub max_items: Option<usize>,
+    pub min_items: Option<usize>,
+    pub unique_items: bool,
+    /// Model the value, or each of its items for arrays, is validated against
+    pub model: Option<String>,
+}
+
+impl TsValidation {
+    /// Whether the value is not checked at all
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Entry of the property in its model's validation attributes map, `None`
+    /// when the property has no constraints
+    pub fn attributes(&self) -> Option<String> {
+        let mut attributes = Vec::new();
+        let mut push = |name: &str, value: Option<String>| {
+            if let Some(value) = value {
+                attributes.push(format!("{}: {}", name, value));
+            }
+        };
+        push("maxLength", self.max_length.map(|n| n.to_string()));
+        push("minLength", self.min_length.map(|n| n.to_string()));
+        push(
+            "pattern",
+            self.pattern
+                .as_ref()
+                .map(|pattern| serde_json::Value::String(pattern.clone()).to_string()),
+        );
+        push("maximum", self.maximum.as_ref().map(|n| n.to_string()));
+        push(
+            "exclusiveMaximum",
+            self.exclusive_maximum.then(|| "true".to_string()),
+        );
+        push("minimum", self.minimum.as_ref().map(|n| n.to_string()));
+        push(
+            "exclusiveMinimum",
+            self.exclusive_minimum.then(|| "true".to_string()),
+        );
+        push(
+            "multipleOf",
+            self.multiple_of.as_ref().map(|n| n.to_string()),
+        );
+        push("maxItems", self.max_items.map(|n| n.to_string()));
+        push("minItems", self.min_items.map(|n| n.to_string()));
+        push("uniqueItems", self.unique_items.then(|| "true".to_string()));
+
+        (!attributes.is_empty()).then(|| format!("{{ {} }}", attributes.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: serde_json::Value) -> Option<serde_json::Number> {
+        match value {
+            serde_json::Value::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_no_attributes_without_constraints() {
+        assert!(TsValidation::default().is_empty());
+        assert_eq!(TsValidation::default().attributes(), None);
+
+        let model_only = TsValidation {
+            model: Some("Pet".to_string()),
+            ..TsValidation::default()
+        };
+        assert!(!model_only.is_empty());
+        assert_eq!(model_only.attributes(), None);
+    }
+
+    #[test]
+    fn test_string_attributes() {
+        let validation = TsValidation {
+            max_length: Some(8),
+            min_length: Some(1),
+            pattern: Some(r#"^"[a-z\d]+"$"#.to_string()),
+            ..TsValidation::default()
+        };
+        assert_eq!(
+            validation.attributes().as_deref(),
+            Some(r#"{ maxLength: 8, minLength: 1, pattern: "^\"[a-z\\d]+\"$" }"#)
+        );
+    }
+
+    #[test]
+    fn test_number_attributes() {
+        let inclusive = TsValidation {
+            maximum: number(serde_json::json!(2.5)),
+            minimum: number(serde_json::json!(-1)),
+            multiple_of: number(serde_json::json!(0.5)),
+            ..TsValidation::default()
+        };
+        assert_eq!(
+            inclusive.attributes().as_deref(),
+            Some("{ maximum: 2.5, minimum: -1, multipleOf: 0.5 }")
+        );
+
+        let exclusive = TsValidation {
+            maximum: number(serde_json::json!(100)),
+            exclusive_maximum: true,
+            minimum: number(serde_json::json!(0)),
+            exclusive_minimum: true,
+            ..TsValidation::default()
+        };
+        assert_eq!(
+            exclusive.attributes().as_deref(),
+            Some("{ maximum: 100, exclusiveMaximum: true, minimum: 0, exclusiveMinimum: true }")
+        );
+    }
+
+    #[test]
+    fn test_array_attributes() {
+        let validation = TsValidation {
+            max_items: Some(3),
+            min_items: Some(0),
+            unique_items: true,
+            model: Some("Tag".to_string()),
+            ..TsValidation::default()
+        };
+        assert_eq!(
+            validation.attributes().as_deref(),
+            Some("{ maxItems: 3, minItems: 0, uniqueItems: true }")
+        );
+    }
+}
@@ -1,15 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 /// TypeScript visibility modifier
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TsVisibility {
+    #[default]
     Public,
     Private,
     Protected,
 }
-
-impl Default for TsVisibility {
-    fn default() -> Self {
-        Self::Public
-    }
-}
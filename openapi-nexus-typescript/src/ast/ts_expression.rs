@@ -35,6 +35,7 @@ impl fmt::Display for TsExpression {
             TsExpression::Primitive(primitive) => match primitive {
                 TsPrimitive::String => write!(f, "string"),
                 TsPrimitive::Number => write!(f, "number"),
+                TsPrimitive::BigInt => write!(f, "bigint"),
                 TsPrimitive::Boolean => write!(f, "boolean"),
                 TsPrimitive::Any => write!(f, "any"),
                 TsPrimitive::Unknown => write!(f, "unknown"),
@@ -105,6 +106,7 @@ impl ToRcDocWithContext for TsExpression {
                 let s = match primitive {
                     TsPrimitive::String => "string",
                     TsPrimitive::Number => "number",
+                    TsPrimitive::BigInt => "bigint",
                     TsPrimitive::Boolean => "boolean",
                     TsPrimitive::Any => "any",
                     TsPrimitive::Unknown => "unknown",
@@ -11,6 +11,8 @@ pub enum TsNode {
     Import(TsImport),
     /// Class definition (for template rendering)
     Class(TsClassDefinition),
+    /// Standalone functions of the methods of a class definition (for template rendering)
+    Functions(TsClassDefinition),
     /// Type definition (interface, type alias, or enum)
     TypeDefinition(TsTypeDefinition),
 }
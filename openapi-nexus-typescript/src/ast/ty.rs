@@ -1,3 +1,4 @@
+pub mod ts_discriminator;
 pub mod ts_enum_definition;
 pub mod ts_interface_definition;
 pub mod ts_interface_signature;
@@ -5,6 +6,7 @@ pub mod ts_primitive;
 pub mod ts_type_alias_definition;
 pub mod ts_type_definition;
 
+pub use ts_discriminator::TsDiscriminator;
 pub use ts_enum_definition::TsEnumDefinition;
 pub use ts_interface_definition::TsInterfaceDefinition;
 pub use ts_interface_signature::TsInterfaceSignature;
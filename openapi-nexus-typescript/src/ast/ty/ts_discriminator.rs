@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// Discriminator of a union type alias
+///
+/// Drives the generated type guard and JSON helpers, which switch on the
+/// property value and delegate to the helpers of the selected member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TsDiscriminator {
+    /// Property whose value selects the member
+    pub property_name: String,
+    /// Discriminator value and the member type it selects
+    pub mapping: Vec<(String, String)>,
+}
+
+impl TsDiscriminator {
+    /// Create a new discriminator
+    pub fn new(property_name: String, mapping: Vec<(String, String)>) -> Self {
+        Self {
+            property_name,
+            mapping,
+        }
+    }
+
+    /// Member type names in mapping order, without duplicates
+    pub fn member_types(&self) -> Vec<&str> {
+        let mut members: Vec<&str> = Vec::new();
+        for (_, member) in &self.mapping {
+            if !members.contains(&member.as_str()) {
+                members.push(member);
+            }
+        }
+        members
+    }
+}
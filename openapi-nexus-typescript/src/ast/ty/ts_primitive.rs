@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 pub enum TsPrimitive {
     String,
     Number,
+    BigInt,
     Boolean,
     Null,
     Undefined,
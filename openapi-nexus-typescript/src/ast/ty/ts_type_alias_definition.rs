@@ -1,6 +1,7 @@
 use pretty::RcDoc;
 use serde::{Deserialize, Serialize};
 
+use super::ts_discriminator::TsDiscriminator;
 use crate::ast::TsDocComment;
 use crate::ast::{TsExpression, TsGeneric};
 use crate::emission::error::EmitError;
@@ -14,6 +15,9 @@ pub struct TsTypeAliasDefinition {
     pub type_expr: TsExpression,
     pub generics: Vec<TsGeneric>,
     pub documentation: Option<TsDocComment>,
+    /// Discriminator of a union alias, for narrowing and JSON helpers
+    #[serde(default)]
+    pub discriminator: Option<TsDiscriminator>,
 }
 
 impl TsTypeAliasDefinition {
@@ -24,6 +28,7 @@ impl TsTypeAliasDefinition {
             type_expr,
             generics: Vec::new(),
             documentation: None,
+            discriminator: None,
         }
     }
 
@@ -38,6 +43,12 @@ impl TsTypeAliasDefinition {
         self.documentation = Some(documentation);
         self
     }
+
+    /// Add a discriminator
+    pub fn with_discriminator(mut self, discriminator: TsDiscriminator) -> Self {
+        self.discriminator = Some(discriminator);
+        self
+    }
 }
 
 impl ToRcDocWithContext for TsTypeAliasDefinition {
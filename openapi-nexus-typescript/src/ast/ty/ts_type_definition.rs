@@ -1,9 +1,11 @@
 use pretty::RcDoc;
 use serde::{Deserialize, Serialize};
 
+use super::ts_discriminator::TsDiscriminator;
 use super::ts_enum_definition::TsEnumDefinition;
 use super::ts_interface_definition::TsInterfaceDefinition;
 use super::ts_type_alias_definition::TsTypeAliasDefinition;
+use crate::ast::{TsExpression, TsPrimitive, TsProperty};
 use crate::emission::error::EmitError;
 use crate::templating::TemplatingEmitter;
 use openapi_nexus_core::traits::{EmissionContext, ToRcDocWithContext};
@@ -36,7 +38,25 @@ impl ToRcDocWithContext for TsTypeDefinition {
                     RcDoc::line().append(RcDoc::line()),
                 ))
             }
-            TsTypeDefinition::TypeAlias(type_alias) => type_alias.to_rcdoc_with_context(context),
+            TsTypeDefinition::TypeAlias(type_alias) => {
+                let alias_doc = type_alias.to_rcdoc_with_context(context)?;
+
+                // Discriminated unions get helpers delegating to their members
+                match &type_alias.discriminator {
+                    Some(discriminator) => {
+                        let helpers_doc = emit_union_helpers_with_template(
+                            &type_alias.name,
+                            discriminator,
+                            context,
+                        )?;
+                        Ok(RcDoc::intersperse(
+                            vec![alias_doc, helpers_doc],
+                            RcDoc::line().append(RcDoc::line()),
+                        ))
+                    }
+                    None => Ok(alias_doc),
+                }
+            }
             TsTypeDefinition::Enum(enum_def) => enum_def.to_rcdoc_with_context(context),
         }
     }
@@ -64,6 +84,10 @@ fn emit_model_helpers_with_template(
                 "name": p.name,
                 "optional": p.optional,
                 "is_index_signature": p.name.starts_with('['),
+                "conversion": json_conversion(p),
+                "nullable": is_nullable(p),
+                "attributes": p.validation.attributes(),
+                "model": p.validation.model,
             })
         })
         .collect();
@@ -78,3 +102,79 @@ fn emit_model_helpers_with_template(
     let rendered = templating.emit_model_helpers(&data)?;
     Ok(RcDoc::text(rendered))
 }
+
+/// Conversion the JSON helpers apply to a property
+///
+/// Returns `date` or `date-time` for `Date` values, `bigint` or `int64-string`
+/// for int64 values not held in a `number`, suffixed with `[]` for arrays.
+pub(crate) fn json_conversion(property: &TsProperty) -> Option<String> {
+    let is_absent = |t: &TsExpression| {
+        matches!(
+            t,
+            TsExpression::Primitive(TsPrimitive::Null | TsPrimitive::Undefined)
+        )
+    };
+    let value_type = match &property.type_expr {
+        TsExpression::Union(types) => {
+            let mut present = types.iter().filter(|t| !is_absent(t));
+            match (present.next(), present.next()) {
+                (Some(value_type), None) => value_type,
+                _ => return None,
+            }
+        }
+        value_type => value_type,
+    };
+    let (item_type, suffix) = match value_type {
+        TsExpression::Array(item) => (item.as_ref(), "[]"),
+        item => (item, ""),
+    };
+
+    let format = property.format.as_deref();
+    let conversion = match item_type {
+        TsExpression::Reference(name) if name == "Date" => match format {
+            Some("date") => "date",
+            _ => "date-time",
+        },
+        TsExpression::Primitive(TsPrimitive::BigInt) => "bigint",
+        TsExpression::Primitive(TsPrimitive::String) if format == Some("int64") => "int64-string",
+        _ => return None,
+    };
+    Some(format!("{}{}", conversion, suffix))
+}
+
+/// Whether `null` is a value of the property's type
+fn is_nullable(property: &TsProperty) -> bool {
+    let null = TsExpression::Primitive(TsPrimitive::Null);
+    match &property.type_expr {
+        TsExpression::Union(types) => types.contains(&null),
+        value_type => *value_type == null,
+    }
+}
+
+/// Emit discriminated union helper functions using the template engine
+fn emit_union_helpers_with_template(
+    name: &str,
+    discriminator: &TsDiscriminator,
+    context: &EmissionContext,
+) -> Result<RcDoc<'static, ()>, EmitError> {
+    let mapping: Vec<serde_json::Value> = discriminator
+        .mapping
+        .iter()
+        .map(|(value, member)| {
+            serde_json::json!({
+                "literal": serde_json::Value::String(value.clone()).to_string(),
+                "member": member,
+            })
+        })
+        .collect();
+
+    let data = serde_json::json!({
+        "name": name,
+        "property": discriminator.property_name,
+        "mapping": mapping,
+    });
+
+    let templating = TemplatingEmitter::new(context.max_line_width);
+    let rendered = templating.emit_union_helpers(&data)?;
+    Ok(RcDoc::text(rendered))
+}
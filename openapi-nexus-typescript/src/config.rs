@@ -4,9 +4,11 @@ pub mod emission_config;
 pub mod file_config;
 pub mod generator_config;
 pub mod package_config;
+pub mod type_mapping_config;
 
 // Re-export all types for convenience
-pub use emission_config::{EmissionConfig, IndentationStyle};
+pub use emission_config::{ClientStyle, EmissionConfig, IndentationStyle};
 pub use file_config::{FileConfig, NamingConvention};
 pub use generator_config::GeneratorConfig;
 pub use package_config::{PackageConfig, TypeScriptModule};
+pub use type_mapping_config::{Int64Strategy, TypeMappingConfig};
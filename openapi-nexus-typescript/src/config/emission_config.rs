@@ -9,6 +9,30 @@ pub struct EmissionConfig {
     pub use_prettier: bool,
     /// Indentation style
     pub indentation: IndentationStyle,
+    /// Whether to emit Zod schemas of the components in a `schemas` module
+    ///
+    /// API methods then check JSON responses against them when the runtime
+    /// configuration enables `validateResponses`.
+    pub zod_schemas: bool,
+    /// Shape of the generated API client
+    pub client_style: ClientStyle,
+    /// Whether to emit TanStack Query hooks of the operations in a `queries`
+    /// module
+    ///
+    /// GET operations get `useXQuery` hooks and the others `useXMutation`
+    /// hooks, unless their `x-tanstack-query` extension says otherwise.
+    pub tanstack_query: bool,
+}
+
+/// Shapes of the generated API client
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClientStyle {
+    /// One class per tag, extending `BaseAPI`
+    #[default]
+    Classes,
+    /// One module per operation, exporting standalone functions that take a
+    /// `Client`, so bundlers can drop the operations that are not used
+    Functions,
 }
 
 /// Indentation styles
@@ -24,6 +48,9 @@ impl Default for EmissionConfig {
             include_documentation: true,
             use_prettier: false,
             indentation: IndentationStyle::Spaces(2),
+            zod_schemas: false,
+            client_style: ClientStyle::Classes,
+            tanstack_query: false,
         }
     }
 }
@@ -9,4 +9,6 @@ pub struct GeneratorConfig {
     pub emission_config: super::EmissionConfig,
     /// Package generation configuration
     pub package_config: super::PackageConfig,
+    /// Type mapping configuration
+    pub type_mapping_config: super::TypeMappingConfig,
 }
@@ -1,5 +1,7 @@
 //! NPM package generation configuration
 
+use std::collections::BTreeMap;
+
 /// Configuration for npm package generation
 #[derive(Debug, Clone)]
 pub struct PackageConfig {
@@ -15,6 +17,19 @@ pub struct PackageConfig {
     pub generate_esm_config: bool,
     /// Whether to include build scripts in package.json
     pub include_build_scripts: bool,
+    /// Dependencies of the package, mapping names to version ranges
+    pub dependencies: BTreeMap<String, String>,
+    /// Peer dependencies of the package, which the consuming application
+    /// provides so that a single copy of them is shared
+    pub peer_dependencies: BTreeMap<String, String>,
+    /// Whether importing the modules of the package has side effects
+    ///
+    /// `"sideEffects": false` is declared otherwise, so bundlers can drop the
+    /// modules that are re-exported but never used.
+    pub side_effects: bool,
+    /// Subpath exports of the package besides its root, mapping subpaths such
+    /// as `./queries` to modules
+    pub exports: BTreeMap<String, String>,
 }
 
 /// TypeScript module systems
@@ -35,6 +50,10 @@ impl Default for PackageConfig {
             typescript_module: TypeScriptModule::CommonJS,
             generate_esm_config: true,
             include_build_scripts: false,
+            dependencies: BTreeMap::new(),
+            peer_dependencies: BTreeMap::new(),
+            side_effects: true,
+            exports: BTreeMap::new(),
         }
     }
 }
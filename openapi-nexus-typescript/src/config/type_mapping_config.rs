@@ -0,0 +1,37 @@
+//! Type mapping configuration
+
+/// Configuration for mapping OpenAPI types to TypeScript types
+#[derive(Debug, Clone, Default)]
+pub struct TypeMappingConfig {
+    /// Map `date` and `date-time` strings to `Date`
+    ///
+    /// The generated JSON helpers and API methods convert between `Date` and the
+    /// wire format, with `date` values serialized as `YYYY-MM-DD`.
+    pub use_date_type: bool,
+    /// TypeScript representation of `int64` integers
+    pub int64: Int64Strategy,
+    /// Return binary responses as a raw `ReadableStream` instead of a buffered `Blob`
+    pub stream_binary_responses: bool,
+}
+
+/// TypeScript representations of `int64` integers
+///
+/// `number` cannot represent integers above 2^53 exactly. The other strategies
+/// make the runtime parse and serialize JSON without going through `number`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Int64Strategy {
+    /// `number`, losing precision above 2^53
+    #[default]
+    Number,
+    /// `bigint`
+    BigInt,
+    /// `string` holding the decimal digits
+    String,
+}
+
+impl Int64Strategy {
+    /// Whether int64 values must bypass `number` when (de)serializing JSON
+    pub fn is_lossless(self) -> bool {
+        self != Self::Number
+    }
+}
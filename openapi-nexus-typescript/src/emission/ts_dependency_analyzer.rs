@@ -50,7 +50,7 @@ impl TsDependencyAnalyzer {
                     }
                 }
             }
-            TsNode::Class(_) => {
+            TsNode::Class(_) | TsNode::Functions(_) => {
                 // Classes handled separately - they use template-based emission
                 // Dependencies are managed through the template system
             }
@@ -84,23 +84,33 @@ impl TsLanguageEmitter {
         docs.push(RcDoc::text(self.get_default_header()));
 
         // Process nodes based on type
+        let mut previous_was_import = false;
         for node in nodes {
             match node {
                 TsNode::Class(class) => {
                     let class_code = self.templating.emit_class(class)?;
                     docs.push(RcDoc::text(class_code));
                 }
+                TsNode::Functions(class) => {
+                    let functions_code = self.templating.emit_functions(class)?;
+                    docs.push(RcDoc::text(functions_code));
+                }
                 TsNode::TypeDefinition(type_def) => {
                     // Use RcDoc-based emission for type definitions
                     let doc = type_def.to_rcdoc_with_context(context)?;
                     docs.push(doc);
                 }
                 TsNode::Import(import) => {
-                    // Use RcDoc-based emission for imports
+                    // Use RcDoc-based emission for imports; consecutive imports form one block
                     let doc = import.to_rcdoc_with_context(context)?;
-                    docs.push(doc);
+                    if previous_was_import && let Some(block) = docs.pop() {
+                        docs.push(block.append(RcDoc::hardline()).append(doc));
+                    } else {
+                        docs.push(doc);
+                    }
                 }
             }
+            previous_was_import = matches!(node, TsNode::Import(_));
         }
 
         let combined = RcDoc::intersperse(docs, RcDoc::line().append(RcDoc::line()));
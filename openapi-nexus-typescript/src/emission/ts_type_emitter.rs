@@ -29,6 +29,7 @@ impl TsTypeEmitter {
                 let type_name = match primitive {
                     TsPrimitive::String => "string",
                     TsPrimitive::Number => "number",
+                    TsPrimitive::BigInt => "bigint",
                     TsPrimitive::Boolean => "boolean",
                     TsPrimitive::Null => "null",
                     TsPrimitive::Undefined => "undefined",
@@ -4,17 +4,22 @@ pub mod api_class_generator;
 // pub mod api_client_generator;  // Disabled due to old AST dependencies
 pub mod file_generator;
 pub mod package_files_generator;
+pub mod pagination;
 pub mod parameter_extractor;
+pub mod query_generator;
 pub mod runtime_generator;
 // pub mod runtime_component_builder;  // Disabled due to old AST dependencies
 pub mod schema_context;
 pub mod schema_generator;
 pub mod template_generator;
 pub mod templates;
+pub mod zod_generator;
 
 pub use api_class_generator::ApiClassGenerator;
 pub use file_generator::{GeneratedFile, TypeScriptFileGenerator};
 pub use parameter_extractor::ParameterExtractor;
+pub use query_generator::QueryHooksGenerator;
 pub use runtime_generator::RuntimeGenerator;
 // pub use runtime_component_builder::RuntimeComponentBuilder;  // Disabled
 pub use template_generator::TemplateGenerator;
+pub use zod_generator::ZodSchemaGenerator;
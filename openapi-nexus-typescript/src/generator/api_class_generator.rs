@@ -1,22 +1,34 @@
 //! Individual API class generator for TypeScript
 
-use std::collections::BTreeSet;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::slice;
 
 use heck::{ToLowerCamelCase as _, ToPascalCase as _};
 use http::Method;
-use utoipa::openapi::RefOr;
+use utoipa::openapi::content::Content;
 use utoipa::openapi::path::Operation;
+use utoipa::openapi::response::Response;
 use utoipa::openapi::schema::{ArrayItems, Schema};
+use utoipa::openapi::{RefOr, SecurityRequirement};
 
 use crate::ast::{
     TsClassDefinition, TsClassMethod, TsDocComment, TsExpression, TsImportStatement, TsNode,
-    TsParameter,
+    TsOperationError, TsOperationResult, TsOperationServers, TsParameter, TsPrimitive,
+    TsResponseStatus, TsServer, TsStatusResponse,
 };
+use crate::config::{ClientStyle, TypeMappingConfig};
 use crate::core::GeneratorError;
-use crate::generator::parameter_extractor::{ParameterExtractor, ParameterInfo};
+use crate::generator::pagination::{Pagination, PaginationStrategy};
+use crate::generator::parameter_extractor::{
+    FormBody, FormEncoding, FormField, ParameterExtractor, ParameterInfo, SerializationStyle,
+    ValueShape,
+};
 use crate::generator::template_generator::{
-    ApiMethodData, ParameterData as TemplateParameterData, Template, TemplateGenerator,
+    ApiMethodData, FormBodyData, FormFieldData, PaginationData,
+    ParameterData as TemplateParameterData, Template, TemplateGenerator,
 };
+use crate::generator::zod_generator::ZodSchemaGenerator;
 use crate::utils::schema_mapper::SchemaMapper;
 use openapi_nexus_core::traits::{EmissionContext, ToRcDocWithContext};
 
@@ -27,6 +39,51 @@ pub struct ApiClassGenerator {
     schema_mapper: SchemaMapper,
     template_generator: TemplateGenerator,
     max_line_width: usize,
+    /// Return binary responses as `ReadableStream` instead of `Blob`
+    stream_binary_responses: bool,
+    /// Check JSON responses against the Zod schemas of their models
+    validate_responses: bool,
+    /// Whether operations are methods of classes or standalone functions
+    client_style: ClientStyle,
+    /// Security schemes the runtime can apply
+    security_schemes: BTreeSet<String>,
+    /// Security requirements of operations that do not declare their own,
+    /// each mapping scheme names to scopes
+    security: Vec<BTreeMap<String, Vec<String>>>,
+}
+
+/// How the successful response of an operation is read
+#[derive(Clone, Copy)]
+enum SuccessResponse<'a> {
+    /// JSON body, with its schema when declared
+    Json(Option<&'a RefOr<Schema>>),
+    /// `text/event-stream` body, read as Server-Sent Events
+    EventStream(&'a Content),
+    /// Newline-delimited JSON body of the given media type, read line by line
+    JsonLines(&'a str, &'a Content),
+    /// `text/*` body
+    Text,
+    /// Binary body, buffered
+    Blob,
+    /// Binary body, streamed
+    Stream,
+    /// No body
+    Void,
+}
+
+impl SuccessResponse<'_> {
+    /// Runtime class wrapping the response
+    fn wrapper(&self) -> &'static str {
+        match self {
+            SuccessResponse::Json(_) => "JSONApiResponse",
+            SuccessResponse::EventStream(_) => "EventStreamApiResponse",
+            SuccessResponse::JsonLines(..) => "JsonLinesApiResponse",
+            SuccessResponse::Text => "TextApiResponse",
+            SuccessResponse::Blob => "BlobApiResponse",
+            SuccessResponse::Stream => "StreamApiResponse",
+            SuccessResponse::Void => "VoidApiResponse",
+        }
+    }
 }
 
 impl ApiClassGenerator {
@@ -37,30 +94,79 @@ impl ApiClassGenerator {
             schema_mapper: SchemaMapper::new(),
             template_generator: TemplateGenerator::new(),
             max_line_width,
+            stream_binary_responses: false,
+            validate_responses: false,
+            client_style: ClientStyle::Classes,
+            security_schemes: BTreeSet::new(),
+            security: Vec::new(),
         }
     }
 
+    /// Use the given type mapping for operation parameters
+    pub fn with_type_mapping(mut self, type_mapping: TypeMappingConfig) -> Self {
+        self.stream_binary_responses = type_mapping.stream_binary_responses;
+        self.parameter_extractor = ParameterExtractor::with_type_mapping(type_mapping);
+        self
+    }
+
+    /// Check JSON responses against the Zod schemas of their models
+    pub fn with_response_validation(mut self, validate_responses: bool) -> Self {
+        self.validate_responses = validate_responses;
+        self
+    }
+
+    /// Generate operations as methods of classes or as standalone functions
+    pub fn with_client_style(mut self, client_style: ClientStyle) -> Self {
+        self.client_style = client_style;
+        self
+    }
+
+    /// Use the given models for request bodies, keyed by component schema name
+    pub fn with_request_models(mut self, request_models: BTreeMap<String, String>) -> Self {
+        self.parameter_extractor = self.parameter_extractor.with_request_models(request_models);
+        self
+    }
+
+    /// Apply the given security schemes, and requirements for operations without their own
+    pub fn with_security(
+        mut self,
+        security_schemes: BTreeSet<String>,
+        security: Vec<SecurityRequirement>,
+    ) -> Self {
+        self.security_schemes = security_schemes;
+        self.security = security.iter().map(Self::requirement_schemes).collect();
+        self
+    }
+
     /// Generate an API class for a specific tag with operations
+    ///
+    /// The component schemas resolve the items of paginated responses.
     pub fn generate_api_class(
         &self,
         tag: &str,
         operations: &[(String, String, Operation)],
+        schemas: &BTreeMap<String, RefOr<Schema>>,
     ) -> Result<TsNode, GeneratorError> {
         let class_name = format!("{}Api", tag.to_pascal_case());
         let interface_name = format!("{}Interface", class_name);
 
-        let mut methods = vec![
-            // Constructor method
-            TsClassMethod::new("constructor".to_string())
-                .with_parameters(vec![TsParameter::optional(
-                    "configuration".to_string(),
-                    Some(TsExpression::Reference("Configuration".to_string())),
-                )])
-                .with_docs(TsDocComment::new("Initialize the API client".to_string()))
-                .with_body_template("constructor_base_api".to_string(), None),
-        ];
+        let mut methods = Vec::new();
+        if self.client_style == ClientStyle::Classes {
+            methods.push(
+                TsClassMethod::new("constructor".to_string())
+                    .with_parameters(vec![TsParameter::optional(
+                        "configuration".to_string(),
+                        Some(TsExpression::Reference("Configuration".to_string())),
+                    )])
+                    .with_docs(TsDocComment::new("Initialize the API client".to_string()))
+                    .with_body_template("constructor_base_api".to_string(), None),
+            );
+        }
 
-        // Generate methods for each operation
+        // Generate methods for each operation, with the models of the page
+        // items of paginated ones
+        let mut item_models: BTreeSet<String> = BTreeSet::new();
+        let mut links_pages = false;
         for (path, method_name, operation) in operations {
             let http_method =
                 method_name
@@ -77,41 +183,256 @@ impl ApiClassGenerator {
             let convenience_method =
                 self.generate_operation_method_convenience(path, &http_method, operation)?;
             methods.push(convenience_method);
+
+            // Generate methods walking the pages and their items
+            if let Some(pagination) = Pagination::from_operation(operation)? {
+                let (pagination_methods, item_model) = self.generate_pagination_methods(
+                    path,
+                    &http_method,
+                    operation,
+                    &pagination,
+                    schemas,
+                )?;
+                methods.extend(pagination_methods);
+                item_models.extend(item_model);
+                links_pages |= pagination.strategy == PaginationStrategy::Link;
+            }
         }
 
-        // Collect model imports for FromJSON transformers
-        let mut model_imports: BTreeSet<String> = BTreeSet::new();
-        for (_path, method_name, operation) in operations {
+        // Collect model imports for FromJSON and ToJSON transformers and the
+        // validators checking their JSON, and whether parameters need the
+        // runtime's serialization helpers
+        let mut response_models: BTreeSet<String> = BTreeSet::new();
+        let mut request_models: BTreeSet<String> = BTreeSet::new();
+        let mut validated_models: BTreeSet<String> = BTreeSet::new();
+        let mut validates_items = false;
+        let mut serializes_parameters = false;
+        let mut styles_query = false;
+        let mut formats_dates = false;
+        for (path, method_name, operation) in operations {
             let http_method =
                 method_name
                     .parse::<Method>()
                     .map_err(|e| GeneratorError::Generic {
                         message: format!("Invalid HTTP method '{}': {}", method_name, e),
                     })?;
-            if let Some((_, model_name)) =
-                self.compute_transformer_and_model(&http_method, operation)
+            if let Some((model_name, is_array)) = self.response_model(&http_method, operation) {
+                validates_items |= is_array;
+                validated_models.insert(model_name.clone());
+                response_models.insert(model_name);
+            }
+            let extracted = self
+                .parameter_extractor
+                .extract_parameters(operation, path)?;
+            serializes_parameters |= !extracted.cookie_params.is_empty()
+                || extracted
+                    .path_params
+                    .iter()
+                    .chain(&extracted.header_params)
+                    .any(ParameterInfo::needs_serialization);
+            styles_query |= extracted.query_params.iter().any(Self::needs_query_style);
+            formats_dates |= extracted
+                .path_params
+                .iter()
+                .chain(&extracted.query_params)
+                .chain(&extracted.header_params)
+                .chain(&extracted.cookie_params)
+                .chain(&extracted.body_param)
+                .chain(
+                    extracted
+                        .form_body
+                        .iter()
+                        .flat_map(|form| form.fields.iter().map(|field| &field.param)),
+                )
+                .any(ParameterInfo::formats_date);
+            if let Some(body) = extracted.body_param
+                && let Some(model_name) = body.json_model
             {
-                model_imports.insert(model_name);
+                validates_items |= matches!(body.type_expr, TsExpression::Array(_));
+                validated_models.insert(model_name.clone());
+                request_models.insert(model_name);
             }
         }
 
-        // Create imports
+        // Typed errors, status-keyed results and servers of the operations,
+        // along with the models of their bodies; those of results are checked
+        let mut operation_errors = Vec::new();
+        let mut operation_results = Vec::new();
+        let mut operation_servers = Vec::new();
+        let mut result_models: BTreeSet<String> = BTreeSet::new();
+        for (path, method_name, operation) in operations {
+            let Ok(http_method) = method_name.parse::<Method>() else {
+                continue;
+            };
+            let error = self.operation_error(path, &http_method, operation);
+            let result = self.operation_result(path, &http_method, operation);
+            for (status_code, response_ref) in &operation.responses.responses {
+                let Some(success) = Self::response_status(status_code).map(Self::is_success_status)
+                else {
+                    continue;
+                };
+                let documented = if success {
+                    result.is_some()
+                } else {
+                    error.is_some()
+                };
+                let RefOr::T(response) = response_ref else {
+                    continue;
+                };
+                if !documented {
+                    continue;
+                }
+                if let Some(model_name) = self.response_body(response, success).2 {
+                    if success {
+                        validated_models.insert(model_name.clone());
+                        result_models.insert(model_name.clone());
+                    }
+                    response_models.insert(model_name);
+                }
+            }
+            operation_errors.extend(error);
+            operation_results.extend(result);
+            operation_servers.extend(self.operation_servers(path, &http_method, operation));
+        }
+
+        // Create imports, adding the response wrappers beyond JSON and void that are used
+        let mut runtime_import = TsImportStatement::new("../runtime/runtime".to_string());
+        if self.client_style == ClientStyle::Classes {
+            runtime_import = runtime_import.with_import("BaseAPI".to_string(), None);
+        }
+        runtime_import = runtime_import
+            .with_import("JSONApiResponse".to_string(), None)
+            .with_import("VoidApiResponse".to_string(), None);
+        let mut wrappers = BTreeSet::new();
+        for (path, method_name, operation) in operations {
+            if let Ok(http_method) = method_name.parse::<Method>() {
+                wrappers.insert(self.response_class(path, &http_method, operation));
+            }
+        }
+        for wrapper in [
+            "TextApiResponse",
+            "BlobApiResponse",
+            "StreamApiResponse",
+            "EventStreamApiResponse",
+            "JsonLinesApiResponse",
+            "StatusApiResponse",
+        ] {
+            if wrappers.contains(wrapper) {
+                runtime_import = runtime_import.with_import(wrapper.to_string(), None);
+            }
+        }
+        runtime_import = runtime_import.with_import("ResponseError".to_string(), None);
+        if styles_query {
+            runtime_import = runtime_import.with_import("QueryParameter".to_string(), None);
+        }
+        if serializes_parameters {
+            runtime_import = runtime_import.with_import("serializeParameter".to_string(), None);
+        }
+        if formats_dates {
+            runtime_import = runtime_import.with_import("formatDate".to_string(), None);
+        }
+        let status_responses: Vec<&TsStatusResponse> = operation_errors
+            .iter()
+            .flat_map(|error| &error.responses)
+            .chain(
+                operation_results
+                    .iter()
+                    .flat_map(|result| &result.responses),
+            )
+            .collect();
+        let parses_with = |helper: &str| {
+            status_responses
+                .iter()
+                .any(|response| response.parse_expr.contains(helper))
+        };
+        if validates_items || parses_with("validateItems(") {
+            runtime_import = runtime_import.with_import("validateItems".to_string(), None);
+        }
+        if !operation_servers.is_empty() {
+            runtime_import = runtime_import.with_import("ServerConfiguration".to_string(), None);
+        }
+        if links_pages {
+            runtime_import = runtime_import.with_import("nextPageLink".to_string(), None);
+        }
+        for helper in ["readJson", "readErrorBody"] {
+            if parses_with(helper) {
+                runtime_import = runtime_import.with_import(helper.to_string(), None);
+            }
+        }
+        if status_responses
+            .iter()
+            .any(|response| response.status_type.contains("StatusCodes"))
+        {
+            runtime_import = runtime_import.with_type_import("StatusCodes".to_string(), None);
+        }
+        if !operation_results.is_empty() {
+            runtime_import = runtime_import.with_type_import("ResponseCheck".to_string(), None);
+        }
+        runtime_import = match self.client_style {
+            ClientStyle::Classes => {
+                runtime_import.with_type_import("Configuration".to_string(), None)
+            }
+            ClientStyle::Functions => runtime_import.with_type_import("Client".to_string(), None),
+        };
         let mut imports = vec![
-            TsImportStatement::new("../runtime/runtime".to_string())
-                .with_import("BaseAPI".to_string(), None)
-                .with_import("JSONApiResponse".to_string(), None)
-                .with_import("VoidApiResponse".to_string(), None)
-                .with_import("ResponseError".to_string(), None)
-                .with_type_import("Configuration".to_string(), None)
-                .with_type_import("InitOverrideFunction".to_string(), None),
+            runtime_import
+                .with_type_import("InitOverrideFunction".to_string(), None)
+                .with_type_import("RequestOptions".to_string(), None),
         ];
 
-        // Add model helper imports
-        for name in model_imports {
-            imports.push(
-                TsImportStatement::new(format!("../models/{}", name))
-                    .with_import(format!("{}FromJSON", name), None),
+        // Add model helper imports, one statement per model file
+        let models: BTreeSet<&String> = response_models
+            .iter()
+            .chain(&request_models)
+            .chain(&item_models)
+            .collect();
+        for name in models {
+            let mut import = TsImportStatement::new(format!("../models/{}", name))
+                .with_type_import(name.clone(), None);
+            if response_models.contains(name) {
+                import = import.with_import(format!("{}FromJSON", name), None);
+            }
+            if request_models.contains(name) {
+                import = import.with_import(format!("{}ToJSON", name), None);
+            }
+            if validated_models.contains(name) {
+                import = import.with_import(format!("{}Validate", name), None);
+            }
+            imports.push(import);
+        }
+
+        // Add the Zod schemas of the responses checked against them
+        let schema_models: BTreeSet<String> = operations
+            .iter()
+            .filter(|_| self.validate_responses)
+            .filter_map(|(_, method_name, operation)| {
+                let http_method = method_name.parse::<Method>().ok()?;
+                self.response_model(&http_method, operation)
+                    .map(|(model_name, _)| model_name)
+            })
+            .chain(
+                result_models
+                    .iter()
+                    .filter(|_| self.validate_responses)
+                    .cloned(),
+            )
+            .collect();
+        if !schema_models.is_empty() {
+            let import = schema_models.iter().fold(
+                TsImportStatement::new("../schemas".to_string()),
+                |import, name| import.with_import(ZodSchemaGenerator::schema_name(name), None),
             );
+            imports.push(import);
+        }
+
+        if self.client_style == ClientStyle::Functions {
+            let functions = TsClassDefinition::new(class_name)
+                .with_methods(methods)
+                .with_imports(imports)
+                .with_operation_errors(operation_errors)
+                .with_operation_results(operation_results)
+                .with_operation_servers(operation_servers);
+            return Ok(TsNode::Functions(functions));
         }
 
         let api_class = TsClassDefinition::new(class_name.clone())
@@ -122,11 +443,64 @@ impl ApiClassGenerator {
                 "API client for {} operations",
                 tag
             )))
-            .with_imports(imports);
+            .with_imports(imports)
+            .with_operation_errors(operation_errors)
+            .with_operation_results(operation_results)
+            .with_operation_servers(operation_servers);
 
         Ok(TsNode::Class(api_class))
     }
 
+    /// Generate the standalone functions of each operation, in a module of
+    /// their own keyed by the name of the operation, so that bundlers can
+    /// drop the unused ones
+    pub fn generate_api_functions(
+        &self,
+        operations: &[(String, String, Operation)],
+        schemas: &BTreeMap<String, RefOr<Schema>>,
+    ) -> Result<Vec<(String, TsNode)>, GeneratorError> {
+        operations
+            .iter()
+            .map(|operation| {
+                let (path, method_name, op) = operation;
+                let http_method =
+                    method_name
+                        .parse::<Method>()
+                        .map_err(|e| GeneratorError::Generic {
+                            message: format!("Invalid HTTP method '{}': {}", method_name, e),
+                        })?;
+                let name = self.generate_method_name(path, op, &http_method);
+                let node = self.generate_api_class(&name, slice::from_ref(operation), schemas)?;
+                Ok((name, node))
+            })
+            .collect()
+    }
+
+    /// Callback checking the JSON bodies of a status-keyed result, through the
+    /// API the response was received by
+    fn response_check(&self) -> String {
+        if self.validate_responses {
+            format!(
+                "(value, validate, schema) => {}.checkResponse(value, validate, schema)",
+                self.receiver()
+            )
+        } else {
+            format!(
+                "(value, validate) => {}.checkResponse(value, validate)",
+                self.receiver()
+            )
+        }
+    }
+
+    /// Object requests are made through: the API instance, or the client
+    /// passed to standalone functions
+    fn receiver(&self) -> &'static str {
+        match self.client_style {
+            ClientStyle::Classes => "this",
+            ClientStyle::Functions => "client",
+        }
+    }
+
     /// Generate a Raw method for a specific operation (returns ApiResponse wrapper)
     fn generate_operation_method_raw(
         &self,
@@ -139,7 +513,7 @@ impl ApiClassGenerator {
             self.generate_method_name(path, operation, http_method)
         );
         let parameters = self.generate_method_parameters(path, operation)?;
-        let return_type = self.generate_raw_return_type(http_method, operation)?;
+        let return_type = self.generate_raw_return_type(path, http_method, operation)?;
 
         // Determine template based on HTTP method
         let template_name = match http_method {
@@ -182,13 +556,22 @@ impl ApiClassGenerator {
         let base_name = self.generate_method_name(path, operation, http_method);
         let parameters = self.generate_method_parameters(path, operation)?;
 
+        // Streamed responses are iterated as they arrive rather than unwrapped
         let mut method = TsClassMethod::new(base_name)
             .with_parameters(parameters)
-            .with_async()
-            .with_body_template("api_method_convenience".to_string(), None);
+            .with_async();
+        method = match self.success_response(http_method, operation) {
+            SuccessResponse::EventStream(_) => method
+                .with_generator()
+                .with_body_template("api_method_event_stream".to_string(), None),
+            SuccessResponse::JsonLines(..) => method
+                .with_generator()
+                .with_body_template("api_method_json_lines".to_string(), None),
+            _ => method.with_body_template("api_method_convenience".to_string(), None),
+        };
 
         let convenience_return = self
-            .generate_convenience_return_type(http_method, operation)?
+            .generate_convenience_return_type(path, http_method, operation)?
             .unwrap_or_else(|| TsExpression::Reference("Promise<any>".to_string()));
         method = method.with_return_type(convenience_return);
 
@@ -203,6 +586,167 @@ impl ApiClassGenerator {
         Ok(method)
     }
 
+    /// Generate the methods of a paginated operation: `{name}AllPages` walking
+    /// its pages with the Raw method, and `{name}Paginated` iterating their
+    /// items, along with the model of the items
+    fn generate_pagination_methods(
+        &self,
+        path: &str,
+        http_method: &Method,
+        operation: &Operation,
+        pagination: &Pagination,
+        schemas: &BTreeMap<String, RefOr<Schema>>,
+    ) -> Result<(Vec<TsClassMethod>, Option<String>), GeneratorError> {
+        let base_name = self.generate_method_name(path, operation, http_method);
+        let response = self.success_response(http_method, operation);
+        let SuccessResponse::Json(Some(schema_ref)) = response else {
+            return Err(GeneratorError::Generic {
+                message: format!("x-pagination of {} needs a JSON response", base_name),
+            });
+        };
+        let (item_type, item_model) = self
+            .page_item_type(schema_ref, pagination.items.as_deref(), schemas)
+            .ok_or_else(|| GeneratorError::Generic {
+                message: format!(
+                    "x-pagination of {} does not lead to an array of items",
+                    base_name
+                ),
+            })?;
+
+        let query_params = self
+            .parameter_extractor
+            .extract_parameters(operation, path)?
+            .query_params;
+        let query_param = |name: &str| query_params.iter().find(|p| p.name == name);
+        let page_param = match pagination.page_param() {
+            Some(page_param) => {
+                Some(
+                    query_param(page_param).ok_or_else(|| GeneratorError::Generic {
+                        message: format!(
+                            "x-pagination of {} refers to a missing query parameter {}",
+                            base_name, page_param
+                        ),
+                    })?,
+                )
+            }
+            None => None,
+        };
+        // The next offset, converted to the type of the offset parameter
+        let next_offset = page_param
+            .filter(|_| pagination.strategy == PaginationStrategy::Offset)
+            .map(|param| {
+                let offset = format!("Number({} ?? 0) + pageItems.length", param.name);
+                match &param.type_expr {
+                    TsExpression::Primitive(TsPrimitive::Number) => offset,
+                    TsExpression::Primitive(TsPrimitive::BigInt) => format!("BigInt({})", offset),
+                    _ => format!("String({})", offset),
+                }
+            });
+
+        let data = PaginationData {
+            strategy: pagination.strategy,
+            raw_method: format!("{}Raw", base_name),
+            pages_method: format!("{}AllPages", base_name),
+            items_expr: pagination.items_expr("currentPage"),
+            cursor_expr: pagination.cursor_expr("currentPage"),
+            page_param: page_param.map(|param| param.name.clone()),
+            next_offset,
+            limit_param: query_param(&pagination.limit_param).map(|p| p.name.clone()),
+        };
+        let data = serde_json::to_value(data).map_err(|e| GeneratorError::Generic {
+            message: format!("Failed to serialize pagination data: {}", e),
+        })?;
+        let parameters = self.generate_method_parameters(path, operation)?;
+        let pages_docs = match pagination.page_param() {
+            Some(page_param) => format!(
+                "Walk the pages of `{}`, from the given `{}` on",
+                base_name, page_param
+            ),
+            None => format!(
+                "Walk the pages of `{}`, following the `next` links of their responses",
+                base_name
+            ),
+        };
+
+        let pages_method = TsClassMethod::new(format!("{}AllPages", base_name))
+            .with_parameters(parameters.clone())
+            .with_async()
+            .with_generator()
+            .with_return_type(TsExpression::Reference(format!(
+                "AsyncIterable<{}>",
+                self.success_value_type(response)
+            )))
+            .with_docs(TsDocComment::new(pages_docs))
+            .with_body_template("api_method_all_pages".to_string(), Some(data.clone()));
+        let items_method = TsClassMethod::new(format!("{}Paginated", base_name))
+            .with_parameters(parameters)
+            .with_async()
+            .with_generator()
+            .with_return_type(TsExpression::Reference(format!(
+                "AsyncIterable<{}>",
+                item_type
+            )))
+            .with_docs(TsDocComment::new(format!(
+                "Iterate the items of every page of `{}`",
+                base_name
+            )))
+            .with_body_template("api_method_paginated".to_string(), Some(data));
+
+        Ok((vec![pages_method, items_method], item_model))
+    }
+
+    /// Type of the items of a page, found at a dot-separated path of the
+    /// response, with their model; `None` when the path does not lead to an array
+    fn page_item_type(
+        &self,
+        schema_ref: &RefOr<Schema>,
+        items: Option<&str>,
+        schemas: &BTreeMap<String, RefOr<Schema>>,
+    ) -> Option<(String, Option<String>)> {
+        let mut schema = Self::resolve_schema(schema_ref, schemas)?;
+        for field in items.into_iter().flat_map(|items| items.split('.')) {
+            let Schema::Object(object) = schema else {
+                return None;
+            };
+            schema = Self::resolve_schema(object.properties.get(field)?, schemas)?;
+        }
+        let Schema::Array(array) = schema else {
+            return None;
+        };
+        let ArrayItems::RefOrSchema(item_ref) = &array.items else {
+            return None;
+        };
+        let model = match &**item_ref {
+            RefOr::Ref(reference) => reference
+                .ref_location
+                .strip_prefix("#/components/schemas/")
+                .map(str::to_string),
+            RefOr::T(_) => None,
+        };
+        Some((
+            self.schema_mapper
+                .map_ref_or_schema_to_type(item_ref)
+                .to_string(),
+            model,
+        ))
+    }
+
+    /// Schema a reference points to among the component schemas
+    fn resolve_schema<'a>(
+        schema_ref: &'a RefOr<Schema>,
+        schemas: &'a BTreeMap<String, RefOr<Schema>>,
+    ) -> Option<&'a Schema> {
+        match schema_ref {
+            RefOr::T(schema) => Some(schema),
+            RefOr::Ref(reference) => {
+                let name = reference
+                    .ref_location
+                    .strip_prefix("#/components/schemas/")?;
+                Self::resolve_schema(schemas.get(name)?, schemas)
+            }
+        }
+    }
+
     /// Create template data for method body generation
     fn create_method_template_data(
         &self,
@@ -210,81 +754,93 @@ impl ApiClassGenerator {
         http_method: &Method,
         operation: &Operation,
     ) -> Result<serde_json::Value, GeneratorError> {
-        let parameters = self.generate_method_parameters(path, operation)?;
-        let return_type = self.generate_raw_return_type(http_method, operation)?;
-
-        // Extract different parameter types
-        let mut path_params = Vec::new();
-        let mut query_params = Vec::new();
-        let mut header_params = Vec::new();
-        let mut body_param = None;
+        let extracted = self
+            .parameter_extractor
+            .extract_parameters(operation, path)?;
+        let return_type = self.generate_raw_return_type(path, http_method, operation)?;
 
         let ctx = EmissionContext {
             indent: 0,
             max_line_width: self.max_line_width,
         };
 
-        for param in &parameters {
-            if param.name.contains("path") {
-                path_params.push(TemplateParameterData {
-                    name: param.name.clone(),
-                    type_expr: param
-                        .type_expr
-                        .as_ref()
-                        .and_then(|t| t.to_rcdoc_with_context(&ctx).ok())
-                        .map(|doc| format!("{}", doc.pretty(self.max_line_width))),
-                    optional: param.optional,
-                });
-            } else if param.name.contains("query") {
-                query_params.push(TemplateParameterData {
-                    name: param.name.clone(),
-                    type_expr: param
-                        .type_expr
-                        .as_ref()
-                        .and_then(|t| t.to_rcdoc_with_context(&ctx).ok())
-                        .map(|doc| format!("{}", doc.pretty(self.max_line_width))),
-                    optional: param.optional,
-                });
-            } else if param.name.contains("header") {
-                header_params.push(TemplateParameterData {
-                    name: param.name.clone(),
-                    type_expr: param
-                        .type_expr
-                        .as_ref()
-                        .and_then(|t| t.to_rcdoc_with_context(&ctx).ok())
-                        .map(|doc| format!("{}", doc.pretty(self.max_line_width))),
-                    optional: param.optional,
-                });
-            } else if param.name == "body" {
-                body_param = Some(TemplateParameterData {
-                    name: param.name.clone(),
-                    type_expr: param
-                        .type_expr
-                        .as_ref()
-                        .and_then(|t| t.to_rcdoc_with_context(&ctx).ok())
-                        .map(|doc| format!("{}", doc.pretty(self.max_line_width))),
-                    optional: param.optional,
-                });
-            }
-        }
+        // Convert parameters to template format, serializing them per their location
+        let to_template = |p: &ParameterInfo, value_expr: String| TemplateParameterData {
+            name: p.name.clone(),
+            type_expr: p
+                .type_expr
+                .to_rcdoc_with_context(&ctx)
+                .ok()
+                .map(|doc| format!("{}", doc.pretty(self.max_line_width))),
+            optional: !p.required,
+            value_expr,
+        };
+        let path_params: Vec<_> = extracted
+            .path_params
+            .iter()
+            .map(|p| to_template(p, Self::path_value_expr(p)))
+            .collect();
+        let query_params: Vec<_> = extracted
+            .query_params
+            .iter()
+            .map(|p| to_template(p, Self::query_value_expr(p)))
+            .collect();
+        let header_params: Vec<_> = extracted
+            .header_params
+            .iter()
+            .map(|p| to_template(p, Self::header_value_expr(p)))
+            .collect();
+        let cookie_params: Vec<_> = extracted
+            .cookie_params
+            .iter()
+            .map(|p| to_template(p, p.serialize_expr(true)))
+            .collect();
+        let body_param = extracted
+            .body_param
+            .as_ref()
+            .map(|p| to_template(p, self.body_value_expr(p)));
 
-        let transformer = self
-            .compute_transformer_and_model(http_method, operation)
-            .map(|(expr, _)| expr);
+        let transformer = match self.operation_result(path, http_method, operation) {
+            Some(result) => Some(format!(
+                "(response) => {}FromResponse(response, {})",
+                result.name,
+                self.response_check()
+            )),
+            None => self
+                .compute_transformer_and_model(http_method, operation)
+                .map(|(expr, _)| expr),
+        };
 
+        let security = self.security_expr(operation);
         let method_data = ApiMethodData {
             method_name: self.generate_method_name(path, operation, http_method),
             http_method: http_method.to_string(),
             path: path.to_string(),
+            url_path: Self::url_path(path, &extracted.path_params),
             path_params,
             query_params,
             header_params,
+            cookie_params,
             body_param,
+            form_body: extracted.form_body.as_ref().map(Self::form_body_data),
+            content_type: extracted.content_type.clone(),
+            accept: self.accept(http_method, operation),
+            response_class: self
+                .response_class(path, http_method, operation)
+                .to_string(),
+            error_class: self
+                .operation_error(path, http_method, operation)
+                .map(|error| error.name),
             return_type: return_type
                 .and_then(|t| t.to_rcdoc_with_context(&ctx).ok())
                 .map(|doc| doc.pretty(self.max_line_width).to_string())
                 .unwrap_or_else(|| "Promise<any>".to_string()),
-            has_auth: true, // Assume auth is needed
+            has_auth: security.is_some(),
+            security,
+            servers: self
+                .operation_servers(path, http_method, operation)
+                .map(|servers| servers.name),
+            idempotent: Self::idempotent(operation),
             has_error_handling: true,
         };
 
@@ -302,16 +858,188 @@ impl ApiClassGenerator {
     }
 
     /// Convert a single ParameterInfo into TemplateParameterData using raw `Display` formatting
-    fn parameter_info_to_template_raw(&self, p: &ParameterInfo) -> TemplateParameterData {
+    fn parameter_info_to_template_raw(
+        &self,
+        p: &ParameterInfo,
+        value_expr: String,
+    ) -> TemplateParameterData {
         TemplateParameterData {
             name: p.name.clone(),
             type_expr: Some(format!("{}", p.type_expr)),
             optional: !p.required,
+            value_expr,
+        }
+    }
+
+    /// Security requirement alternatives of an operation as a TypeScript
+    /// expression, `None` when it needs no credentials
+    ///
+    /// Alternatives using a scheme the runtime cannot apply are left out.
+    fn security_expr(&self, operation: &Operation) -> Option<String> {
+        let requirements = match &operation.security {
+            Some(security) => security.iter().map(Self::requirement_schemes).collect(),
+            None => self.security.clone(),
+        };
+        let alternatives: Vec<String> = requirements
+            .iter()
+            .filter(|schemes| {
+                schemes
+                    .keys()
+                    .all(|name| self.security_schemes.contains(name))
+            })
+            .map(|schemes| {
+                let schemes: Vec<String> = schemes
+                    .iter()
+                    .map(|(name, scopes)| {
+                        let scopes: Vec<String> =
+                            scopes.iter().map(|scope| format!("'{}'", scope)).collect();
+                        format!("{{ scheme: '{}', scopes: [{}] }}", name, scopes.join(", "))
+                    })
+                    .collect();
+                format!("[{}]", schemes.join(", "))
+            })
+            .collect();
+        if alternatives.iter().all(|alternative| alternative == "[]") {
+            return None;
+        }
+        Some(format!("[{}]", alternatives.join(", ")))
+    }
+
+    /// Scopes of a security requirement, keyed by scheme name
+    fn requirement_schemes(requirement: &SecurityRequirement) -> BTreeMap<String, Vec<String>> {
+        // Requirements only expose their schemes through serialization
+        serde_json::to_value(requirement)
+            .ok()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default()
+    }
+
+    /// Path segment of a path parameter
+    fn path_value_expr(p: &ParameterInfo) -> String {
+        if p.needs_serialization() {
+            p.serialize_expr(true)
+        } else {
+            p.value_expr()
+        }
+    }
+
+    /// Whether a query parameter is serialized differently from what the
+    /// runtime's `querystring` does by default, which matches exploded form arrays
+    fn needs_query_style(p: &ParameterInfo) -> bool {
+        p.needs_serialization()
+            && !(p.shape == ValueShape::Array && p.style == SerializationStyle::Form && p.explode)
+    }
+
+    /// Query value of a query parameter, tagged with its style when
+    /// `querystring` has to serialize it with `serializeParameter`
+    fn query_value_expr(p: &ParameterInfo) -> String {
+        if Self::needs_query_style(p) {
+            format!(
+                "new QueryParameter({}, '{}', {})",
+                p.value_expr(),
+                p.style.as_str(),
+                p.explode
+            )
+        } else {
+            p.value_expr()
+        }
+    }
+
+    /// Header value of a header parameter, which is never percent-encoded
+    fn header_value_expr(p: &ParameterInfo) -> String {
+        if p.needs_serialization() {
+            p.serialize_expr(false)
+        } else {
+            p.value_expr()
+        }
+    }
+
+    /// Convert a form body into template data
+    fn form_body_data(form_body: &FormBody) -> FormBodyData {
+        let multipart = form_body.encoding == FormEncoding::Multipart;
+        FormBodyData {
+            multipart,
+            fields: form_body
+                .fields
+                .iter()
+                .map(|field| Self::form_field_data(field, multipart))
+                .collect(),
         }
     }
 
+    /// Decide how a form field is appended to `FormData` or `URLSearchParams`
+    ///
+    /// Blobs are appended as they are and everything else as text, except
+    /// multipart objects and parts with a JSON content type, which become JSON
+    /// blobs. URL-encoded arrays and objects follow the field's style and explode.
+    fn form_field_data(field: &FormField, multipart: bool) -> FormFieldData {
+        let name = &field.param.name;
+        let text = |value: &str| {
+            if field.binary {
+                value.to_string()
+            } else {
+                format!("String({})", value)
+            }
+        };
+        let json_part = match &field.content_type {
+            Some(content_type) if content_type.contains("json") => Some(content_type.as_str()),
+            None if field.shape == ValueShape::Object => Some("application/json"),
+            _ => None,
+        };
+
+        let (kind, key_expr, value_expr) = match field.shape {
+            _ if multipart && !field.binary && json_part.is_some() => (
+                "single",
+                String::new(),
+                format!(
+                    "new Blob([JSON.stringify({})], {{ type: '{}' }})",
+                    name,
+                    json_part.unwrap_or_default()
+                ),
+            ),
+            ValueShape::Array if multipart || field.explode => {
+                ("items", String::new(), text("item"))
+            }
+            ValueShape::Array => (
+                "single",
+                String::new(),
+                format!("{}.join('{}')", name, field.style.delimiter()),
+            ),
+            ValueShape::Object if field.style == SerializationStyle::DeepObject => {
+                ("entries", format!("`{}[${{key}}]`", name), text("item"))
+            }
+            ValueShape::Object if field.explode => ("entries", "key".to_string(), text("item")),
+            ValueShape::Object => (
+                "single",
+                String::new(),
+                format!("Object.entries({}).flat().join(',')", name),
+            ),
+            ValueShape::Scalar => ("single", String::new(), text(&field.param.value_expr())),
+        };
+
+        FormFieldData {
+            name: name.clone(),
+            optional: !field.param.required,
+            kind: kind.to_string(),
+            key_expr,
+            value_expr,
+        }
+    }
+
+    /// Build the body of the URL template literal, interpolating path parameters
+    fn url_path(path: &str, path_params: &[ParameterInfo]) -> String {
+        path_params
+            .iter()
+            .fold(path.replace('{', "${"), |url, param| {
+                url.replace(
+                    &format!("${{{}}}", param.name),
+                    &format!("${{{}}}", Self::path_value_expr(param)),
+                )
+            })
+    }
+
     /// Generate method name from operation
-    fn generate_method_name(
+    pub(crate) fn generate_method_name(
         &self,
         path: &str,
         operation: &Operation,
@@ -344,6 +1072,37 @@ impl ApiClassGenerator {
         &self,
         path: &str,
         operation: &Operation,
+    ) -> Result<Vec<TsParameter>, GeneratorError> {
+        let mut parameters = self.operation_parameters(path, operation)?;
+
+        // Standalone functions take the client first
+        if self.client_style == ClientStyle::Functions {
+            parameters.insert(
+                0,
+                TsParameter::with_type(
+                    "client".to_string(),
+                    TsExpression::Reference("Client".to_string()),
+                ),
+            );
+        }
+
+        // Add initOverrides parameter at the end
+        let mut union: BTreeSet<TsExpression> = BTreeSet::new();
+        union.insert(TsExpression::Reference("RequestOptions".to_string()));
+        union.insert(TsExpression::Reference("InitOverrideFunction".to_string()));
+        parameters.push(TsParameter::optional(
+            "initOverrides".to_string(),
+            Some(TsExpression::Union(union)),
+        ));
+
+        Ok(parameters)
+    }
+
+    /// Parameters of an operation, in the order its methods take them
+    pub(crate) fn operation_parameters(
+        &self,
+        path: &str,
+        operation: &Operation,
     ) -> Result<Vec<TsParameter>, GeneratorError> {
         let mut parameters = Vec::new();
 
@@ -372,8 +1131,12 @@ impl ApiClassGenerator {
             });
         }
 
-        // Add header parameters
-        for param_info in extracted.header_params {
+        // Add header and cookie parameters
+        for param_info in extracted
+            .header_params
+            .into_iter()
+            .chain(extracted.cookie_params)
+        {
             parameters.push(TsParameter {
                 name: param_info.name,
                 type_expr: Some(param_info.type_expr),
@@ -382,6 +1145,16 @@ impl ApiClassGenerator {
             });
         }
 
+        // Add form body fields
+        for field in extracted.form_body.into_iter().flat_map(|form| form.fields) {
+            parameters.push(TsParameter {
+                name: field.param.name,
+                type_expr: Some(field.param.type_expr),
+                optional: !field.param.required,
+                default_value: field.param.default_value,
+            });
+        }
+
         // Add request body parameter
         if let Some(body_param) = extracted.body_param {
             parameters.push(TsParameter {
@@ -392,139 +1165,533 @@ impl ApiClassGenerator {
             });
         }
 
-        // Add initOverrides parameter at the end
-        use std::collections::BTreeSet;
-        let mut union: BTreeSet<TsExpression> = BTreeSet::new();
-        union.insert(TsExpression::Reference("RequestInit".to_string()));
-        union.insert(TsExpression::Reference("InitOverrideFunction".to_string()));
-        parameters.push(TsParameter::optional(
-            "initOverrides".to_string(),
-            Some(TsExpression::Union(union)),
-        ));
+        // Optional parameters cannot precede required ones
+        parameters.sort_by_key(|parameter| parameter.optional);
 
         Ok(parameters)
     }
 
-    /// Determine Raw return type (ApiResponse wrappers) based on operation responses
-    fn generate_raw_return_type(
+    /// Whether the convenience method of an operation iterates the values of a
+    /// streamed response rather than resolving to its value
+    pub(crate) fn streams_values(&self, http_method: &Method, operation: &Operation) -> bool {
+        matches!(
+            self.success_response(http_method, operation),
+            SuccessResponse::EventStream(_) | SuccessResponse::JsonLines(..)
+        )
+    }
+
+    /// Determine how the successful response of an operation is read
+    ///
+    /// The first `2xx` response decides: JSON content wins over event streams
+    /// and newline-delimited JSON, then `text/*`, then any other (binary)
+    /// content. Responses without content, and
+    /// `204` ones, are void. Without a `2xx` response, `DELETE` is void and
+    /// everything else untyped JSON. Operations documenting several `2xx`
+    /// responses return a status-keyed result instead, see `operation_result`.
+    fn success_response<'a>(
         &self,
         http_method: &Method,
-        operation: &Operation,
-    ) -> Result<Option<TsExpression>, GeneratorError> {
-        // Look for successful response (200, 201, etc.)
-        for (status_code, response_ref) in operation.responses.responses.iter() {
-            if status_code.starts_with('2') {
-                match response_ref {
-                    RefOr::T(response) => {
-                        if let Some(json_content) = response.content.get("application/json")
-                            && let Some(schema_ref) = &json_content.schema
-                        {
-                            let return_type =
-                                self.schema_mapper.map_ref_or_schema_to_type(schema_ref);
-                            return Ok(Some(TsExpression::Reference(format!(
-                                "Promise<JSONApiResponse<{}>>",
-                                return_type
-                            ))));
-                        }
-                        // No JSON content: treat as void
-                        return Ok(Some(TsExpression::Reference(
-                            "Promise<VoidApiResponse>".to_string(),
-                        )));
-                    }
-                    RefOr::Ref(_) => {
-                        // TODO: Handle response references
-                    }
+        operation: &'a Operation,
+    ) -> SuccessResponse<'a> {
+        // TODO: Handle response references
+        let response = operation
+            .responses
+            .responses
+            .iter()
+            .filter(|(status_code, _)| status_code.starts_with('2'))
+            .find_map(|(status_code, response_ref)| match response_ref {
+                RefOr::T(response) => Some((status_code, response)),
+                RefOr::Ref(_) => None,
+            });
+
+        let Some((status_code, response)) = response else {
+            return if *http_method == Method::DELETE {
+                SuccessResponse::Void
+            } else {
+                SuccessResponse::Json(None)
+            };
+        };
+        if status_code == "204" || response.content.is_empty() {
+            return SuccessResponse::Void;
+        }
+
+        if let Some(json_content) = response
+            .content
+            .iter()
+            .find(|(media_type, _)| Self::is_json_media_type(media_type))
+            .map(|(_, content)| content)
+        {
+            return SuccessResponse::Json(json_content.schema.as_ref());
+        }
+        for (media_type, content) in &response.content {
+            match Self::media_type_essence(media_type) {
+                "text/event-stream" => return SuccessResponse::EventStream(content),
+                essence @ ("application/x-ndjson" | "application/jsonl") => {
+                    return SuccessResponse::JsonLines(essence, content);
                 }
+                _ => {}
+            }
+        }
+        if response
+            .content
+            .keys()
+            .any(|media_type| media_type.starts_with("text/"))
+        {
+            return SuccessResponse::Text;
+        }
+        if self.stream_binary_responses {
+            SuccessResponse::Stream
+        } else {
+            SuccessResponse::Blob
+        }
+    }
+
+    /// Whether a media type carries JSON, e.g. `application/json` or `application/problem+json`
+    fn is_json_media_type(media_type: &str) -> bool {
+        let essence = Self::media_type_essence(media_type);
+        essence == "application/json" || essence.ends_with("+json")
+    }
+
+    /// Media type without its parameters, e.g. `text/event-stream` for
+    /// `text/event-stream; charset=utf-8`
+    fn media_type_essence(media_type: &str) -> &str {
+        media_type.split(';').next().unwrap_or_default().trim()
+    }
+
+    /// Schema of each event of a streamed response: its `itemSchema`, kept by
+    /// the parser as the `x-itemSchema` extension, or else its schema
+    fn event_schema(content: &Content) -> Option<RefOr<Schema>> {
+        content
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("x-itemSchema"))
+            .and_then(|item_schema| serde_json::from_value(item_schema.clone()).ok())
+            .or_else(|| content.schema.clone())
+    }
+
+    /// Schema of the JSON value of a successful response, or of each of its events
+    fn success_schema<'a>(response: SuccessResponse<'a>) -> Option<Cow<'a, RefOr<Schema>>> {
+        match response {
+            SuccessResponse::Json(schema_ref) => schema_ref.map(Cow::Borrowed),
+            SuccessResponse::EventStream(content) | SuccessResponse::JsonLines(_, content) => {
+                Self::event_schema(content).map(Cow::Owned)
+            }
+            _ => None,
+        }
+    }
+
+    /// TypeScript type of the value a successful response resolves to
+    fn success_value_type(&self, response: SuccessResponse) -> String {
+        match response {
+            SuccessResponse::Json(Some(schema_ref)) => self
+                .schema_mapper
+                .map_ref_or_schema_to_type(schema_ref)
+                .to_string(),
+            SuccessResponse::Json(None) => "any".to_string(),
+            SuccessResponse::EventStream(content) | SuccessResponse::JsonLines(_, content) => {
+                Self::event_schema(content)
+                    .map(|schema_ref| {
+                        self.schema_mapper
+                            .map_ref_or_schema_to_type(&schema_ref)
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| "any".to_string())
+            }
+            SuccessResponse::Text => "string".to_string(),
+            SuccessResponse::Blob => "Blob".to_string(),
+            SuccessResponse::Stream => "ReadableStream<Uint8Array>".to_string(),
+            SuccessResponse::Void => "void".to_string(),
+        }
+    }
+
+    /// Value of the `Accept` header of an operation whose response is streamed
+    fn accept(&self, http_method: &Method, operation: &Operation) -> Option<String> {
+        match self.success_response(http_method, operation) {
+            SuccessResponse::EventStream(_) => Some("text/event-stream".to_string()),
+            SuccessResponse::JsonLines(media_type, _) => Some(media_type.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Runtime class wrapping the response of the Raw method
+    fn response_class(
+        &self,
+        path: &str,
+        http_method: &Method,
+        operation: &Operation,
+    ) -> &'static str {
+        if self
+            .operation_result(path, http_method, operation)
+            .is_some()
+        {
+            "StatusApiResponse"
+        } else {
+            self.success_response(http_method, operation).wrapper()
+        }
+    }
+
+    /// Documented responses of an operation whose status is a success one, or not
+    fn status_responses(&self, operation: &Operation, success: bool) -> Vec<TsStatusResponse> {
+        operation
+            .responses
+            .responses
+            .iter()
+            .filter_map(|(status_code, response_ref)| {
+                let status = Self::response_status(status_code)
+                    .filter(|status| Self::is_success_status(*status) == success)?;
+                // TODO: Handle response references
+                let RefOr::T(response) = response_ref else {
+                    return None;
+                };
+                let (body_type, parse_expr, _) = self.response_body(response, success);
+                Some(TsStatusResponse {
+                    status,
+                    status_type: String::new(),
+                    body_type,
+                    parse_expr,
+                    description: Some(response.description.clone())
+                        .filter(|description| !description.is_empty()),
+                })
+            })
+            .collect()
+    }
+
+    /// Status-keyed result of an operation, if it documents several 2xx responses
+    fn operation_result(
+        &self,
+        path: &str,
+        http_method: &Method,
+        operation: &Operation,
+    ) -> Option<TsOperationResult> {
+        let responses = self.status_responses(operation, true);
+        if responses.len() < 2 {
+            return None;
+        }
+
+        let method_name = self.generate_method_name(path, operation, http_method);
+        Some(TsOperationResult::new(
+            format!("{}Result", method_name.to_pascal_case()),
+            method_name,
+            responses,
+        ))
+    }
+
+    /// Typed errors of an operation, if it documents any non-2xx response
+    fn operation_error(
+        &self,
+        path: &str,
+        http_method: &Method,
+        operation: &Operation,
+    ) -> Option<TsOperationError> {
+        let responses = self.status_responses(operation, false);
+        if responses.is_empty() {
+            return None;
+        }
+
+        let method_name = self.generate_method_name(path, operation, http_method);
+        Some(TsOperationError::new(
+            format!("{}Error", method_name.to_pascal_case()),
+            method_name,
+            responses,
+        ))
+    }
+
+    /// Whether an operation may be retried, as declared by its `x-idempotent`
+    /// extension; `None` leaves it to the default of the HTTP method
+    fn idempotent(operation: &Operation) -> Option<bool> {
+        operation
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("x-idempotent"))
+            .and_then(serde_json::Value::as_bool)
+    }
+
+    /// Servers of an operation, if it overrides the API's ones
+    fn operation_servers(
+        &self,
+        path: &str,
+        http_method: &Method,
+        operation: &Operation,
+    ) -> Option<TsOperationServers> {
+        let servers: Vec<TsServer> = operation
+            .servers
+            .iter()
+            .flatten()
+            .map(TsServer::from)
+            .collect();
+        if servers.is_empty() {
+            return None;
+        }
+
+        let method_name = self.generate_method_name(path, operation, http_method);
+        Some(TsOperationServers {
+            name: format!("{}Servers", method_name),
+            operation: method_name,
+            servers,
+        })
+    }
+
+    /// Statuses a response key applies to
+    fn response_status(status_code: &str) -> Option<TsResponseStatus> {
+        if status_code == "default" {
+            return Some(TsResponseStatus::Default);
+        }
+        if let Some(class) = status_code
+            .strip_suffix("XX")
+            .or_else(|| status_code.strip_suffix("xx"))
+        {
+            return class.parse().ok().map(TsResponseStatus::Range);
+        }
+        status_code.parse().ok().map(TsResponseStatus::Code)
+    }
+
+    /// Whether a response status is a `2xx` one
+    fn is_success_status(status: TsResponseStatus) -> bool {
+        match status {
+            TsResponseStatus::Code(code) => (200..300).contains(&code),
+            TsResponseStatus::Range(class) => class == 2,
+            TsResponseStatus::Default => false,
+        }
+    }
+
+    /// Type, parsing expression and model of a response body
+    ///
+    /// JSON success bodies are read like those of `JSONApiResponse` and checked
+    /// with the `check` callback of the result parser; JSON error bodies keep
+    /// their raw text when they do not parse.
+    fn response_body(
+        &self,
+        response: &Response,
+        success: bool,
+    ) -> (String, String, Option<String>) {
+        if response.content.is_empty() {
+            return ("undefined".to_string(), "undefined".to_string(), None);
+        }
+        let json_schema = response
+            .content
+            .iter()
+            .find(|(media_type, _)| Self::is_json_media_type(media_type))
+            .map(|(_, content)| content.schema.as_ref());
+        let Some(schema_ref) = json_schema else {
+            if response
+                .content
+                .keys()
+                .any(|media_type| media_type.starts_with("text/"))
+            {
+                return (
+                    "string".to_string(),
+                    "await response.text()".to_string(),
+                    None,
+                );
+            }
+            return (
+                "Blob".to_string(),
+                "await response.blob()".to_string(),
+                None,
+            );
+        };
+
+        let model_name = |schema_ref: &RefOr<Schema>| match schema_ref {
+            RefOr::Ref(reference) => reference
+                .ref_location
+                .strip_prefix("#/components/schemas/")
+                .map(str::to_string),
+            RefOr::T(_) => None,
+        };
+        let (name, is_array) = match schema_ref {
+            Some(schema_ref @ RefOr::Ref(_)) => (model_name(schema_ref), false),
+            Some(RefOr::T(Schema::Array(arr))) => match &arr.items {
+                ArrayItems::RefOrSchema(item_ref) => (model_name(item_ref), true),
+                ArrayItems::False => (None, false),
+            },
+            _ => (None, false),
+        };
+        let Some(name) = name else {
+            let parse_expr = if success {
+                "await readJson(response)"
+            } else {
+                "await readErrorBody(response)"
+            };
+            return ("any".to_string(), parse_expr.to_string(), None);
+        };
+
+        let body_type = if is_array {
+            format!("Array<{}>", name)
+        } else {
+            name.clone()
+        };
+        if !success {
+            let transformer = if is_array {
+                format!("(json) => (json as Array<any>).map({}FromJSON)", name)
+            } else {
+                format!("{}FromJSON", name)
+            };
+            return (
+                format!("{} | string", body_type),
+                format!("await readErrorBody(response, {})", transformer),
+                Some(name),
+            );
+        }
+
+        let mut checks = if is_array {
+            format!("validateItems({}Validate)", name)
+        } else {
+            format!("{}Validate", name)
+        };
+        if self.validate_responses {
+            let schema = ZodSchemaGenerator::schema_name(&name);
+            if is_array {
+                checks = format!("{}, {}.array()", checks, schema);
+            } else {
+                checks = format!("{}, {}", checks, schema);
             }
         }
+        let checked = format!("check(await readJson(response), {})", checks);
+        let parse_expr = if is_array {
+            format!("({} as Array<any>).map({}FromJSON)", checked, name)
+        } else {
+            format!("{}FromJSON({})", name, checked)
+        };
+        (body_type, parse_expr, Some(name))
+    }
 
-        // Fallbacks: DELETE with no content -> VoidApiResponse; otherwise JSON any
-        if *http_method == Method::DELETE {
-            return Ok(Some(TsExpression::Reference(
-                "Promise<VoidApiResponse>".to_string(),
-            )));
+    /// Determine Raw return type (ApiResponse wrappers) based on operation responses
+    fn generate_raw_return_type(
+        &self,
+        path: &str,
+        http_method: &Method,
+        operation: &Operation,
+    ) -> Result<Option<TsExpression>, GeneratorError> {
+        if let Some(result) = self.operation_result(path, http_method, operation) {
+            return Ok(Some(TsExpression::Reference(format!(
+                "Promise<StatusApiResponse<{}>>",
+                result.name
+            ))));
         }
-        Ok(Some(TsExpression::Reference(
-            "Promise<JSONApiResponse<any>>".to_string(),
-        )))
+        let response = self.success_response(http_method, operation);
+        let wrapper = match response {
+            SuccessResponse::Json(_)
+            | SuccessResponse::EventStream(_)
+            | SuccessResponse::JsonLines(..) => {
+                format!(
+                    "{}<{}>",
+                    response.wrapper(),
+                    self.success_value_type(response)
+                )
+            }
+            _ => response.wrapper().to_string(),
+        };
+        Ok(Some(TsExpression::Reference(format!(
+            "Promise<{}>",
+            wrapper
+        ))))
     }
 
     /// Determine convenience return type (unwrapped)
     fn generate_convenience_return_type(
         &self,
+        path: &str,
         http_method: &Method,
         operation: &Operation,
     ) -> Result<Option<TsExpression>, GeneratorError> {
-        // Look for JSON success schema
-        for (status_code, response_ref) in operation.responses.responses.iter() {
-            if status_code.starts_with('2') {
-                match response_ref {
-                    RefOr::T(response) => {
-                        if let Some(json_content) = response.content.get("application/json")
-                            && let Some(schema_ref) = &json_content.schema
-                        {
-                            let t = self.schema_mapper.map_ref_or_schema_to_type(schema_ref);
-                            return Ok(Some(TsExpression::Reference(format!("Promise<{}>", t))));
-                        }
-                        return Ok(Some(TsExpression::Reference("Promise<void>".to_string())));
-                    }
-                    RefOr::Ref(_) => {}
-                }
-            }
-        }
-        if *http_method == Method::DELETE {
-            return Ok(Some(TsExpression::Reference("Promise<void>".to_string())));
+        if let Some(result) = self.operation_result(path, http_method, operation) {
+            return Ok(Some(TsExpression::Reference(format!(
+                "Promise<{}>",
+                result.name
+            ))));
         }
-        Ok(Some(TsExpression::Reference("Promise<any>".to_string())))
+        let response = self.success_response(http_method, operation);
+        let wrapper = match response {
+            SuccessResponse::EventStream(_) | SuccessResponse::JsonLines(..) => "AsyncIterable",
+            _ => "Promise",
+        };
+        Ok(Some(TsExpression::Reference(format!(
+            "{}<{}>",
+            wrapper,
+            self.success_value_type(response)
+        ))))
+    }
+
+    /// Wire form of a request body, checked against its model's validator
+    fn body_value_expr(&self, body: &ParameterInfo) -> String {
+        let Some(model) = &body.json_model else {
+            return body.value_expr();
+        };
+        let validator = match body.type_expr {
+            TsExpression::Array(_) => format!("validateItems({}Validate)", model),
+            _ => format!("{}Validate", model),
+        };
+        format!(
+            "{}.validateBody({}, {})",
+            self.receiver(),
+            validator,
+            body.value_expr()
+        )
+    }
+
+    /// Model of a JSON success response, or of each of its events, and whether
+    /// the value is an array of it
+    fn response_model(
+        &self,
+        http_method: &Method,
+        operation: &Operation,
+    ) -> Option<(String, bool)> {
+        let schema_ref = Self::success_schema(self.success_response(http_method, operation))?;
+        let (reference, is_array) = match &*schema_ref {
+            RefOr::Ref(reference) => (reference, false),
+            RefOr::T(Schema::Array(arr)) => match &arr.items {
+                ArrayItems::RefOrSchema(item_ref) => match &**item_ref {
+                    RefOr::Ref(reference) => (reference, true),
+                    RefOr::T(_) => return None,
+                },
+                ArrayItems::False => return None,
+            },
+            RefOr::T(_) => return None,
+        };
+        let name = reference
+            .ref_location
+            .strip_prefix("#/components/schemas/")?;
+        Some((name.to_string(), is_array))
     }
 
-    /// Compute JSON transformer expression and model name if applicable
+    /// Transformer of a JSON success response into its model, with the model name
+    ///
+    /// The JSON value is checked against the model's validator, and against its
+    /// Zod schema when responses are validated with them, before conversion.
     fn compute_transformer_and_model(
         &self,
-        _http_method: &Method,
+        http_method: &Method,
         operation: &Operation,
     ) -> Option<(String, String)> {
-        for (status_code, response_ref) in operation.responses.responses.iter() {
-            if !status_code.starts_with('2') {
-                continue;
-            }
-            if let RefOr::T(response) = response_ref
-                && let Some(json_content) = response.content.get("application/json")
-                && let Some(schema_ref) = &json_content.schema
-            {
-                match schema_ref {
-                    RefOr::Ref(reference) => {
-                        if let Some(name) =
-                            reference.ref_location.strip_prefix("#/components/schemas/")
-                        {
-                            let expr = format!("(jsonValue) => {}FromJSON(jsonValue)", name);
-                            return Some((expr, name.to_string()));
-                        }
-                    }
-                    RefOr::T(schema) => {
-                        if let Schema::Array(arr) = schema {
-                            match &arr.items {
-                                ArrayItems::RefOrSchema(item_ref) => {
-                                    if let RefOr::Ref(reference) = &**item_ref
-                                        && let Some(name) = reference
-                                            .ref_location
-                                            .strip_prefix("#/components/schemas/")
-                                    {
-                                        let expr = format!(
-                                            "(jsonValue) => (jsonValue as Array<any>).map({}FromJSON)",
-                                            name
-                                        );
-                                        return Some((expr, name.to_string()));
-                                    }
-                                }
-                                ArrayItems::False => {}
-                            }
-                        }
-                    }
-                }
-            }
+        let (name, is_array) = self.response_model(http_method, operation)?;
+        let mut checked = "jsonValue".to_string();
+        if self.validate_responses {
+            let schema = ZodSchemaGenerator::schema_name(&name);
+            let schema = if is_array {
+                format!("{}.array()", schema)
+            } else {
+                schema
+            };
+            checked = format!(
+                "{}.validateResponse({}, {})",
+                self.receiver(),
+                schema,
+                checked
+            );
         }
-        None
+        let receiver = self.receiver();
+        let expr = if is_array {
+            format!(
+                "(jsonValue) => ({}.validateBody(validateItems({}Validate), {}) as Array<any>).map({}FromJSON)",
+                receiver, name, checked, name
+            )
+        } else {
+            format!(
+                "(jsonValue) => {}FromJSON({}.validateBody({}Validate, {}))",
+                name, receiver, name, checked
+            )
+        };
+        Some((expr, name))
     }
 
     /// Generate implementation body for an API method using templates
@@ -544,37 +1711,63 @@ impl ApiClassGenerator {
         let template_path_params: Vec<TemplateParameterData> = extracted_params
             .path_params
             .iter()
-            .map(|p| self.parameter_info_to_template_raw(p))
+            .map(|p| self.parameter_info_to_template_raw(p, Self::path_value_expr(p)))
             .collect();
 
         let template_query_params: Vec<TemplateParameterData> = extracted_params
             .query_params
             .iter()
-            .map(|p| self.parameter_info_to_template_raw(p))
+            .map(|p| self.parameter_info_to_template_raw(p, Self::query_value_expr(p)))
             .collect();
 
         let template_header_params: Vec<TemplateParameterData> = extracted_params
             .header_params
             .iter()
-            .map(|p| self.parameter_info_to_template_raw(p))
+            .map(|p| self.parameter_info_to_template_raw(p, Self::header_value_expr(p)))
+            .collect();
+
+        let template_cookie_params: Vec<TemplateParameterData> = extracted_params
+            .cookie_params
+            .iter()
+            .map(|p| self.parameter_info_to_template_raw(p, p.serialize_expr(true)))
             .collect();
 
         let template_body_param = extracted_params
             .body_param
             .as_ref()
-            .map(|p| self.parameter_info_to_template_raw(p));
+            .map(|p| self.parameter_info_to_template_raw(p, self.body_value_expr(p)));
+        let security = self.security_expr(operation);
 
         // Create API method data for template
         let api_method_data = ApiMethodData {
             method_name: method_name.to_string(),
             http_method: http_method.as_str().to_string(),
             path: path.to_string(),
+            url_path: Self::url_path(path, &extracted_params.path_params),
             path_params: template_path_params,
             query_params: template_query_params,
             header_params: template_header_params,
+            cookie_params: template_cookie_params,
             body_param: template_body_param,
+            form_body: extracted_params
+                .form_body
+                .as_ref()
+                .map(Self::form_body_data),
+            content_type: extracted_params.content_type.clone(),
+            accept: self.accept(http_method, operation),
+            response_class: self
+                .response_class(path, http_method, operation)
+                .to_string(),
+            error_class: self
+                .operation_error(path, http_method, operation)
+                .map(|error| error.name),
             return_type: "Promise<ApiResponse>".to_string(),
-            has_auth: true,
+            has_auth: security.is_some(),
+            security,
+            servers: self
+                .operation_servers(path, http_method, operation)
+                .map(|servers| servers.name),
+            idempotent: Self::idempotent(operation),
             has_error_handling: true,
         };
 
@@ -6,8 +6,10 @@ use std::slice;
 use heck::{ToKebabCase as _, ToLowerCamelCase as _, ToPascalCase as _, ToSnakeCase as _};
 use utoipa::openapi::OpenApi;
 
-use crate::ast::TsNode;
+use crate::ast::ty::ts_type_definition::json_conversion;
+use crate::ast::{TsImport, TsImportSpecifier, TsNode, TsTypeDefinition};
 use crate::config::{FileConfig, NamingConvention, PackageConfig};
+use crate::emission::ts_dependency_analyzer::TsDependencyAnalyzer;
 use crate::emission::ts_file_category::TsFileCategory;
 use crate::emission::ts_language_emitter::{OpenApiMetadata, TsLanguageEmitter};
 use crate::generator::package_files_generator::PackageFilesGenerator;
@@ -129,12 +131,12 @@ impl TypeScriptFileGenerator {
     ) -> Result<Vec<GeneratedFile>, FileGeneratorError> {
         let mut files = Vec::new();
 
-        // Separate API classes from other schemas
+        // Separate API classes and operation functions from other schemas
         let mut api_classes = HashMap::new();
         let mut other_schemas = HashMap::new();
 
         for (name, node) in schemas {
-            if name.ends_with("Api") {
+            if name.ends_with("Api") || matches!(node, TsNode::Functions(_)) {
                 api_classes.insert(name.clone(), node.clone());
             } else {
                 other_schemas.insert(name.clone(), node.clone());
@@ -144,9 +146,11 @@ impl TypeScriptFileGenerator {
         // Generate models files (no directory prefix - handled by core)
         for (name, node) in &other_schemas {
             let filename = self.generate_filename(name);
+            let mut nodes = self.generate_model_imports(name, node, &other_schemas);
+            nodes.push(node.clone());
             let content = self
                 .emitter
-                .emit_with_context(slice::from_ref(node), &self.create_emission_context())
+                .emit_with_context(&nodes, &self.create_emission_context())
                 .map_err(|e| FileGeneratorError::EmitError {
                     filename: filename.clone(),
                     source: format!("{}", e),
@@ -195,6 +199,112 @@ impl TypeScriptFileGenerator {
         Ok(files)
     }
 
+    /// Generate imports of the other models a model refers to by name
+    fn generate_model_imports(
+        &self,
+        name: &str,
+        node: &TsNode,
+        schemas: &HashMap<String, TsNode>,
+    ) -> Vec<TsNode> {
+        let dependencies = TsDependencyAnalyzer::new()
+            .analyze_dependencies(slice::from_ref(node))
+            .model_dependencies;
+
+        // Type names are PascalCase while schema keys keep their component names
+        let mut imports: Vec<(String, &String)> = schemas
+            .keys()
+            .filter(|schema_name| schema_name.as_str() != name)
+            .map(|schema_name| (schema_name.to_pascal_case(), schema_name))
+            .filter(|(type_name, _)| dependencies.contains(type_name))
+            .collect();
+        imports.sort();
+
+        // Discriminated union helpers delegate to the helpers of their members,
+        // and model validators to the validators of the models they hold
+        let (union_members, validated_models): (Vec<&str>, Vec<&str>) = match node {
+            TsNode::TypeDefinition(TsTypeDefinition::TypeAlias(type_alias)) => (
+                type_alias
+                    .discriminator
+                    .as_ref()
+                    .map(|discriminator| discriminator.member_types())
+                    .unwrap_or_default(),
+                Vec::new(),
+            ),
+            TsNode::TypeDefinition(TsTypeDefinition::Interface(interface)) => (
+                Vec::new(),
+                interface
+                    .properties
+                    .iter()
+                    .filter_map(|property| property.validation.model.as_deref())
+                    .collect(),
+            ),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        let mut nodes: Vec<TsNode> = imports
+            .into_iter()
+            .map(|(type_name, schema_name)| {
+                let filename = self.generate_filename(schema_name);
+                let module_path = format!("./{}", filename.trim_end_matches(".ts"));
+                let mut names = vec![type_name.clone()];
+                if union_members.contains(&type_name.as_str()) {
+                    names.extend([
+                        format!("{type_name}FromJSONTyped"),
+                        format!("{type_name}ToJSON"),
+                        format!("instanceOf{type_name}"),
+                    ]);
+                }
+                if union_members.contains(&type_name.as_str())
+                    || validated_models.contains(&type_name.as_str())
+                {
+                    names.push(format!("{type_name}Validate"));
+                }
+                TsNode::Import(TsImport::new(module_path).with_named_imports(names))
+            })
+            .collect();
+
+        // Validators report violations through the runtime, which also reads
+        // and writes `date` values
+        let runtime_names = match node {
+            TsNode::TypeDefinition(TsTypeDefinition::Interface(interface)) => {
+                let mut names = Vec::new();
+                if interface.properties.iter().any(|p| {
+                    json_conversion(p)
+                        .is_some_and(|conversion| conversion.trim_end_matches("[]") == "date")
+                }) {
+                    names.extend(
+                        ["formatDate", "parseDate"]
+                            .map(|name| TsImportSpecifier::new(name.to_string())),
+                    );
+                }
+                if !interface.properties.iter().all(|p| p.validation.is_empty()) {
+                    names.push(TsImportSpecifier::new("validateProperty".to_string()));
+                }
+                names
+            }
+            TsNode::TypeDefinition(TsTypeDefinition::TypeAlias(type_alias))
+                if type_alias.discriminator.is_some() =>
+            {
+                Vec::new()
+            }
+            _ => return nodes,
+        };
+        nodes.insert(
+            0,
+            TsNode::Import(
+                TsImport::new("../runtime/runtime").with_specifiers(
+                    runtime_names
+                        .into_iter()
+                        .chain([TsImportSpecifier::new_type(
+                            "ValidationViolation".to_string(),
+                        )])
+                        .collect(),
+                ),
+            ),
+        );
+        nodes
+    }
+
     /// Generate apis/index.ts file
     fn generate_apis_index_file(
         &self,
@@ -44,6 +44,22 @@ impl PackageFilesGenerator {
             }
         });
 
+        for (subpath, module) in &self.config.exports {
+            package_json["exports"][subpath] = serde_json::json!(module);
+        }
+
+        if !self.config.side_effects {
+            package_json["sideEffects"] = serde_json::json!(false);
+        }
+
+        if !self.config.dependencies.is_empty() {
+            package_json["dependencies"] = serde_json::json!(self.config.dependencies);
+        }
+
+        if !self.config.peer_dependencies.is_empty() {
+            package_json["peerDependencies"] = serde_json::json!(self.config.peer_dependencies);
+        }
+
         // Add build scripts if configured
         if self.config.include_build_scripts {
             package_json["scripts"] = serde_json::json!({
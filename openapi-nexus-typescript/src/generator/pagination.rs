@@ -0,0 +1,179 @@
+//! `x-pagination` extension of list operations
+
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::path::Operation;
+
+use crate::core::GeneratorError;
+
+/// How the pages of an operation are walked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PaginationStrategy {
+    /// Each page holds the cursor of the next one, sent back as a query parameter
+    Cursor,
+    /// Pages are requested by the offset of their first item
+    Offset,
+    /// Each response links the next page in its `Link` header
+    Link,
+}
+
+/// Pagination of a list operation, as declared by its `x-pagination` extension
+///
+/// ```yaml
+/// x-pagination:
+///   strategy: cursor
+///   items: data
+///   cursor: meta.nextCursor
+///   cursorParam: after
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Pagination {
+    pub strategy: PaginationStrategy,
+    /// Dot-separated path of the items in a page, the page itself when unset
+    pub items: Option<String>,
+    /// Dot-separated path of the next cursor in a page, for the cursor strategy
+    pub cursor: Option<String>,
+    /// Query parameter sending the cursor
+    #[serde(default = "default_cursor_param")]
+    pub cursor_param: String,
+    /// Query parameter sending the offset of the first item
+    #[serde(default = "default_offset_param")]
+    pub offset_param: String,
+    /// Query parameter sending the page size, ending offset pagination on a
+    /// short page when the operation has it
+    #[serde(default = "default_limit_param")]
+    pub limit_param: String,
+}
+
+fn default_cursor_param() -> String {
+    "cursor".to_string()
+}
+
+fn default_offset_param() -> String {
+    "offset".to_string()
+}
+
+fn default_limit_param() -> String {
+    "limit".to_string()
+}
+
+impl Pagination {
+    /// Pagination declared by an operation, `None` when it has no `x-pagination`
+    pub fn from_operation(operation: &Operation) -> Result<Option<Self>, GeneratorError> {
+        let Some(extension) = operation
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("x-pagination"))
+        else {
+            return Ok(None);
+        };
+        let pagination: Self =
+            serde_json::from_value(extension.clone()).map_err(|e| GeneratorError::Generic {
+                message: format!("Invalid x-pagination extension: {}", e),
+            })?;
+        if pagination.strategy == PaginationStrategy::Cursor && pagination.cursor.is_none() {
+            return Err(GeneratorError::Generic {
+                message: "x-pagination with the cursor strategy needs a cursor field".to_string(),
+            });
+        }
+        Ok(Some(pagination))
+    }
+
+    /// Query parameter advanced from one page to the next, `None` when the
+    /// next page is linked instead
+    pub fn page_param(&self) -> Option<&str> {
+        match self.strategy {
+            PaginationStrategy::Cursor => Some(&self.cursor_param),
+            PaginationStrategy::Offset => Some(&self.offset_param),
+            PaginationStrategy::Link => None,
+        }
+    }
+
+    /// TypeScript expression reading the items of `page`, empty when absent
+    pub fn items_expr(&self, page: &str) -> String {
+        match &self.items {
+            Some(items) => format!("{} ?? []", Self::access_expr(page, items)),
+            None => page.to_string(),
+        }
+    }
+
+    /// TypeScript expression reading the next cursor of `page`
+    pub fn cursor_expr(&self, page: &str) -> Option<String> {
+        self.cursor
+            .as_deref()
+            .map(|cursor| Self::access_expr(page, cursor))
+    }
+
+    /// TypeScript expression reading a dot-separated path of `value`, where
+    /// any field but the first may be absent
+    fn access_expr(value: &str, path: &str) -> String {
+        let mut expr = value.to_string();
+        for (index, field) in path.split('.').enumerate() {
+            let optional = if index == 0 { "" } else { "?." };
+            expr.push_str(&format!("{}['{}']", optional, field));
+        }
+        expr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utoipa::openapi::extensions::ExtensionsBuilder;
+    use utoipa::openapi::path::OperationBuilder;
+
+    fn pagination(extension: serde_json::Value) -> Result<Option<Pagination>, GeneratorError> {
+        let operation = OperationBuilder::new()
+            .extensions(Some(
+                ExtensionsBuilder::new()
+                    .add("x-pagination", extension)
+                    .build(),
+            ))
+            .build();
+        Pagination::from_operation(&operation)
+    }
+
+    #[test]
+    fn test_without_extension() {
+        let operation = OperationBuilder::new().build();
+        assert_eq!(Pagination::from_operation(&operation).unwrap(), None);
+    }
+
+    #[test]
+    fn test_cursor_pagination() {
+        let pagination = pagination(serde_json::json!({
+            "strategy": "cursor",
+            "items": "data",
+            "cursor": "meta.nextCursor",
+            "cursorParam": "after",
+        }))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(pagination.page_param(), Some("after"));
+        assert_eq!(pagination.items_expr("page"), "page['data'] ?? []");
+        assert_eq!(
+            pagination.cursor_expr("page").as_deref(),
+            Some("page['meta']?.['nextCursor']")
+        );
+    }
+
+    #[test]
+    fn test_offset_pagination_defaults() {
+        let pagination = pagination(serde_json::json!({ "strategy": "offset" }))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(pagination.page_param(), Some("offset"));
+        assert_eq!(pagination.limit_param, "limit");
+        assert_eq!(pagination.items_expr("page"), "page");
+    }
+
+    #[test]
+    fn test_invalid_extension() {
+        assert!(pagination(serde_json::json!({ "strategy": "pages" })).is_err());
+        assert!(pagination(serde_json::json!({ "strategy": "cursor" })).is_err());
+        assert!(pagination(serde_json::json!({ "strategy": "link", "next": "x" })).is_err());
+    }
+}
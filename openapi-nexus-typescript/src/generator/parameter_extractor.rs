@@ -1,9 +1,15 @@
 //! Parameter extraction utilities for OpenAPI operations
 
+use std::collections::BTreeMap;
+
 use heck::ToPascalCase as _;
-use utoipa::openapi::path::Operation;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::encoding::Encoding;
+use utoipa::openapi::path::{Operation, ParameterIn, ParameterStyle};
+use utoipa::openapi::schema::{ArrayItems, KnownFormat, Schema, SchemaFormat, SchemaType, Type};
 
 use crate::ast::TsExpression;
+use crate::config::TypeMappingConfig;
 use crate::core::GeneratorError;
 use crate::utils::schema_mapper::SchemaMapper;
 
@@ -16,8 +22,116 @@ pub struct ExtractedParameters {
     pub query_params: Vec<ParameterInfo>,
     /// Header parameters
     pub header_params: Vec<ParameterInfo>,
-    /// Request body parameter
+    /// Cookie parameters
+    pub cookie_params: Vec<ParameterInfo>,
+    /// Request body parameter, for JSON bodies and form bodies without inline schema
     pub body_param: Option<ParameterInfo>,
+    /// Form request body built from individual parameters
+    pub form_body: Option<FormBody>,
+    /// `Content-Type` of the request, unset for form bodies where `fetch` derives it
+    pub content_type: Option<String>,
+}
+
+/// Media type of a form request body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormEncoding {
+    /// `multipart/form-data`, sent as `FormData`
+    Multipart,
+    /// `application/x-www-form-urlencoded`, sent as `URLSearchParams`
+    UrlEncoded,
+}
+
+impl FormEncoding {
+    /// Media type of the encoding
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            FormEncoding::Multipart => "multipart/form-data",
+            FormEncoding::UrlEncoded => "application/x-www-form-urlencoded",
+        }
+    }
+}
+
+/// Request body sent as form data, one method parameter per property
+#[derive(Debug, Clone)]
+pub struct FormBody {
+    pub encoding: FormEncoding,
+    pub fields: Vec<FormField>,
+}
+
+/// Shape of a parameter or form field value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValueShape {
+    #[default]
+    Scalar,
+    Array,
+    Object,
+}
+
+/// How array and object values are serialized, following OpenAPI `style`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SerializationStyle {
+    #[default]
+    Form,
+    Simple,
+    Label,
+    Matrix,
+    SpaceDelimited,
+    PipeDelimited,
+    DeepObject,
+}
+
+impl SerializationStyle {
+    /// Name of the style, as used by the runtime's `serializeParameter`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SerializationStyle::Form => "form",
+            SerializationStyle::Simple => "simple",
+            SerializationStyle::Label => "label",
+            SerializationStyle::Matrix => "matrix",
+            SerializationStyle::SpaceDelimited => "spaceDelimited",
+            SerializationStyle::PipeDelimited => "pipeDelimited",
+            SerializationStyle::DeepObject => "deepObject",
+        }
+    }
+
+    /// Separator joining array items when they are not exploded
+    pub fn delimiter(&self) -> &'static str {
+        match self {
+            SerializationStyle::SpaceDelimited => " ",
+            SerializationStyle::PipeDelimited => "|",
+            _ => ",",
+        }
+    }
+}
+
+impl From<&ParameterStyle> for SerializationStyle {
+    fn from(style: &ParameterStyle) -> Self {
+        match style {
+            ParameterStyle::Form => SerializationStyle::Form,
+            ParameterStyle::Simple => SerializationStyle::Simple,
+            ParameterStyle::Label => SerializationStyle::Label,
+            ParameterStyle::Matrix => SerializationStyle::Matrix,
+            ParameterStyle::SpaceDelimited => SerializationStyle::SpaceDelimited,
+            ParameterStyle::PipeDelimited => SerializationStyle::PipeDelimited,
+            ParameterStyle::DeepObject => SerializationStyle::DeepObject,
+        }
+    }
+}
+
+/// Property of a form request body
+#[derive(Debug, Clone)]
+pub struct FormField {
+    /// Method parameter carrying the value
+    pub param: ParameterInfo,
+    pub shape: ValueShape,
+    /// Whether the value, or its items for arrays, is binary and sent as `Blob`
+    pub binary: bool,
+    /// Content type of the part, from the `encoding` object
+    pub content_type: Option<String>,
+    /// Serialization style, from the `encoding` object
+    pub style: SerializationStyle,
+    /// Whether arrays and objects are split into separate fields
+    pub explode: bool,
 }
 
 /// Information about a parameter
@@ -33,12 +147,94 @@ pub struct ParameterInfo {
     pub description: Option<String>,
     /// Default value if any
     pub default_value: Option<String>,
+    /// OpenAPI format of the value, or of its items for arrays
+    pub format: Option<String>,
+    /// Model whose `ToJSON` helper serializes the value, or its items for arrays
+    pub json_model: Option<String>,
+    pub shape: ValueShape,
+    /// Serialization style, defaulting per location as OpenAPI does
+    pub style: SerializationStyle,
+    /// Whether arrays and objects are split into separate values
+    pub explode: bool,
+}
+
+impl ParameterInfo {
+    /// TypeScript expression turning the parameter value into its wire form
+    ///
+    /// Model values go through their `ToJSON` helper, `Date` values become
+    /// ISO 8601 strings, `date` ones their local calendar date through the
+    /// runtime's `formatDate`; other values are passed through unchanged.
+    pub fn value_expr(&self) -> String {
+        if let Some(model) = &self.json_model {
+            return match &self.type_expr {
+                TsExpression::Array(_) if self.required => {
+                    format!("{}.map({}ToJSON)", self.name, model)
+                }
+                TsExpression::Array(_) => format!("{}?.map({}ToJSON)", self.name, model),
+                _ => format!("{}ToJSON({})", model, self.name),
+            };
+        }
+
+        let date = TsExpression::Reference("Date".to_string());
+        let serialize = |item: &str| match self.format.as_deref() {
+            Some("date") => format!("formatDate({})", item),
+            _ => format!("{}.toISOString()", item),
+        };
+
+        match &self.type_expr {
+            t if *t == date => serialize(&self.name),
+            TsExpression::Array(item) if **item == date => {
+                format!("{}.map((item) => {})", self.name, serialize("item"))
+            }
+            _ => self.name.clone(),
+        }
+    }
+
+    /// Whether the value is a `date`, or an array of them, written with the
+    /// runtime's `formatDate`
+    pub fn formats_date(&self) -> bool {
+        let date = TsExpression::Reference("Date".to_string());
+        self.json_model.is_none()
+            && self.format.as_deref() == Some("date")
+            && match &self.type_expr {
+                TsExpression::Array(item) => **item == date,
+                value_type => *value_type == date,
+            }
+    }
+
+    /// Whether the value needs the runtime's `serializeParameter`
+    ///
+    /// Arrays and objects always do. Primitives only do for the path styles
+    /// that prefix them, `label` and `matrix`.
+    pub fn needs_serialization(&self) -> bool {
+        self.shape != ValueShape::Scalar
+            || matches!(
+                self.style,
+                SerializationStyle::Label | SerializationStyle::Matrix
+            )
+    }
+
+    /// TypeScript expression serializing the value with its style and explode,
+    /// percent-encoding names and values when `encode` is set
+    pub fn serialize_expr(&self, encode: bool) -> String {
+        format!(
+            "serializeParameter('{}', {}, '{}', {}{})",
+            self.name,
+            self.value_expr(),
+            self.style.as_str(),
+            self.explode,
+            if encode { "" } else { ", false" }
+        )
+    }
 }
 
 /// Parameter extractor for OpenAPI operations
 #[derive(Debug, Clone)]
 pub struct ParameterExtractor {
     schema_mapper: SchemaMapper,
+    type_mapping: TypeMappingConfig,
+    /// Model used for request bodies, keyed by component schema name
+    request_models: BTreeMap<String, String>,
 }
 
 impl Default for ParameterExtractor {
@@ -50,11 +246,27 @@ impl Default for ParameterExtractor {
 impl ParameterExtractor {
     /// Create a new parameter extractor
     pub fn new() -> Self {
+        Self::with_type_mapping(TypeMappingConfig::default())
+    }
+
+    /// Create a new parameter extractor with the given type mapping
+    pub fn with_type_mapping(type_mapping: TypeMappingConfig) -> Self {
         Self {
             schema_mapper: SchemaMapper::new(),
+            type_mapping,
+            request_models: BTreeMap::new(),
         }
     }
 
+    /// Serialize request bodies referencing these component schemas with the
+    /// `ToJSON` helper of the mapped model
+    ///
+    /// Schemas with `readOnly` properties map to their `Input` variant.
+    pub fn with_request_models(mut self, request_models: BTreeMap<String, String>) -> Self {
+        self.request_models = request_models;
+        self
+    }
+
     /// Extract all parameters from an OpenAPI operation
     pub fn extract_parameters(
         &self,
@@ -64,7 +276,10 @@ impl ParameterExtractor {
         let mut path_params = Vec::new();
         let mut query_params = Vec::new();
         let mut header_params = Vec::new();
+        let mut cookie_params = Vec::new();
         let mut body_param = None;
+        let mut form_body = None;
+        let mut content_type = None;
 
         // Extract path parameters from the path string
         let path_param_names = self.extract_path_parameter_names(path);
@@ -72,6 +287,15 @@ impl ParameterExtractor {
         // Extract parameters from the operation
         if let Some(parameters) = &operation.parameters {
             for param in parameters {
+                // Form style for query and cookie parameters, simple style otherwise
+                let style = param
+                    .style
+                    .as_ref()
+                    .map(SerializationStyle::from)
+                    .unwrap_or(match param.parameter_in {
+                        ParameterIn::Query | ParameterIn::Cookie => SerializationStyle::Form,
+                        ParameterIn::Path | ParameterIn::Header => SerializationStyle::Simple,
+                    });
                 let param_info = ParameterInfo {
                     name: param.name.clone(),
                     type_expr: if let Some(schema) = &param.schema {
@@ -82,10 +306,19 @@ impl ParameterExtractor {
                     required: matches!(param.required, utoipa::openapi::Required::True),
                     description: param.description.clone(),
                     default_value: None, // TODO: Extract default value from schema
+                    format: param.schema.as_ref().and_then(Self::value_format),
+                    json_model: None,
+                    shape: param
+                        .schema
+                        .as_ref()
+                        .map(Self::value_shape)
+                        .unwrap_or_default(),
+                    style,
+                    explode: param.explode.unwrap_or(style == SerializationStyle::Form),
                 };
 
                 match param.parameter_in {
-                    utoipa::openapi::path::ParameterIn::Path => {
+                    ParameterIn::Path => {
                         // Validate that this parameter actually exists in the path
                         if path_param_names.contains(&param.name) {
                             path_params.push(param_info);
@@ -94,41 +327,177 @@ impl ParameterExtractor {
                             query_params.push(param_info);
                         }
                     }
-                    utoipa::openapi::path::ParameterIn::Query => {
+                    ParameterIn::Query => {
                         query_params.push(param_info);
                     }
-                    utoipa::openapi::path::ParameterIn::Header => {
+                    ParameterIn::Header => {
                         header_params.push(param_info);
                     }
-                    _ => {
-                        // Skip other parameter locations for now
+                    ParameterIn::Cookie => {
+                        cookie_params.push(param_info);
                     }
                 }
             }
         }
 
-        // Extract request body parameter
+        // Extract request body parameter, preferring JSON over form bodies
+        let form_content = operation.request_body.as_ref().and_then(|request_body| {
+            [FormEncoding::Multipart, FormEncoding::UrlEncoded]
+                .into_iter()
+                .find_map(|encoding| {
+                    let content = request_body.content.get(encoding.media_type())?;
+                    Some((encoding, content))
+                })
+        });
         if let Some(request_body) = &operation.request_body
             && let Some(json_content) = request_body.content.get("application/json")
             && let Some(schema_ref) = &json_content.schema
         {
+            let json_model = self.request_model(schema_ref);
+            let type_expr = match (&json_model, schema_ref) {
+                (Some(model), RefOr::T(Schema::Array(_))) => {
+                    TsExpression::Array(Box::new(TsExpression::Reference(model.clone())))
+                }
+                (Some(model), _) => TsExpression::Reference(model.clone()),
+                (None, _) => self.map_schema_ref_to_type(schema_ref),
+            };
             body_param = Some(ParameterInfo {
                 name: "body".to_string(),
-                type_expr: self.map_schema_ref_to_type(schema_ref),
+                type_expr,
                 required: matches!(request_body.required, Some(utoipa::openapi::Required::True)),
                 description: request_body.description.clone(),
                 default_value: None,
+                format: None,
+                json_model,
+                shape: ValueShape::default(),
+                style: SerializationStyle::default(),
+                explode: false,
             });
+            content_type = Some("application/json".to_string());
+        } else if let Some(request_body) = &operation.request_body
+            && let Some((encoding, content)) = form_content
+        {
+            let required = matches!(request_body.required, Some(utoipa::openapi::Required::True));
+            match &content.schema {
+                Some(RefOr::T(Schema::Object(obj_schema))) => {
+                    let fields = obj_schema
+                        .properties
+                        .iter()
+                        .map(|(name, schema_ref)| {
+                            self.form_field(
+                                name,
+                                schema_ref,
+                                required && obj_schema.required.contains(name),
+                                encoding,
+                                content.encoding.get(name),
+                            )
+                        })
+                        .collect();
+                    form_body = Some(FormBody { encoding, fields });
+                }
+                // Without an inline schema the caller builds the form data
+                _ => {
+                    let type_name = match encoding {
+                        FormEncoding::Multipart => "FormData",
+                        FormEncoding::UrlEncoded => "URLSearchParams",
+                    };
+                    body_param = Some(ParameterInfo {
+                        name: "body".to_string(),
+                        type_expr: TsExpression::Reference(type_name.to_string()),
+                        required,
+                        description: request_body.description.clone(),
+                        default_value: None,
+                        format: None,
+                        json_model: None,
+                        shape: ValueShape::default(),
+                        style: SerializationStyle::default(),
+                        explode: false,
+                    });
+                }
+            }
         }
 
         Ok(ExtractedParameters {
             path_params,
             query_params,
             header_params,
+            cookie_params,
             body_param,
+            form_body,
+            content_type,
         })
     }
 
+    /// Build a form field from a property of an inline form body schema
+    fn form_field(
+        &self,
+        name: &str,
+        schema_ref: &RefOr<Schema>,
+        required: bool,
+        encoding: FormEncoding,
+        property_encoding: Option<&Encoding>,
+    ) -> FormField {
+        let shape = Self::value_shape(schema_ref);
+        let item = match (shape, schema_ref) {
+            (ValueShape::Array, RefOr::T(Schema::Array(arr_schema))) => match &arr_schema.items {
+                ArrayItems::RefOrSchema(items) => Some(items.as_ref()),
+                ArrayItems::False => None,
+            },
+            (ValueShape::Scalar, _) => Some(schema_ref),
+            _ => None,
+        };
+        let binary = item.is_some_and(|item| {
+            matches!(
+                item,
+                RefOr::T(Schema::Object(obj_schema))
+                    if obj_schema.format == Some(SchemaFormat::KnownFormat(KnownFormat::Binary))
+            )
+        });
+
+        let type_expr = match (binary, shape) {
+            (true, ValueShape::Array) => {
+                TsExpression::Array(Box::new(TsExpression::Reference("Blob".to_string())))
+            }
+            (true, _) => TsExpression::Reference("Blob".to_string()),
+            (false, _) => self.map_parameter_schema_to_type(schema_ref),
+        };
+
+        // Form style with explode is the default for both encodings
+        let style = property_encoding
+            .and_then(|encoding| encoding.style.as_ref())
+            .map(SerializationStyle::from)
+            .unwrap_or_default();
+        let explode = property_encoding
+            .and_then(|encoding| encoding.explode)
+            .unwrap_or(style == SerializationStyle::Form);
+        let content_type = match encoding {
+            FormEncoding::Multipart => {
+                property_encoding.and_then(|encoding| encoding.content_type.clone())
+            }
+            FormEncoding::UrlEncoded => None,
+        };
+
+        FormField {
+            param: ParameterInfo {
+                name: name.to_string(),
+                type_expr,
+                required,
+                description: None,
+                default_value: None,
+                format: Self::value_format(schema_ref),
+                json_model: None,
+                shape,
+                style,
+                explode,
+            },
+            shape,
+            binary,
+            content_type,
+            style,
+            explode,
+        }
+    }
+
     /// Extract path parameter names from a path string
     fn extract_path_parameter_names(&self, path: &str) -> Vec<String> {
         let mut param_names = Vec::new();
@@ -157,9 +526,73 @@ impl ParameterExtractor {
         &self,
         schema_ref: &utoipa::openapi::RefOr<utoipa::openapi::Schema>,
     ) -> TsExpression {
+        let is_date = matches!(
+            Self::value_format(schema_ref).as_deref(),
+            Some("date" | "date-time")
+        );
+        if self.type_mapping.use_date_type && is_date {
+            let date = TsExpression::Reference("Date".to_string());
+            return match schema_ref {
+                RefOr::T(Schema::Array(_)) => TsExpression::Array(Box::new(date)),
+                _ => date,
+            };
+        }
+
         self.schema_mapper.map_ref_or_schema_to_type(schema_ref)
     }
 
+    /// Shape of the values a schema describes; references are taken to be objects
+    fn value_shape(schema_ref: &RefOr<Schema>) -> ValueShape {
+        match schema_ref {
+            RefOr::T(Schema::Array(_)) => ValueShape::Array,
+            RefOr::T(Schema::Object(obj_schema))
+                if obj_schema.schema_type == SchemaType::Type(Type::Object)
+                    || !obj_schema.properties.is_empty() =>
+            {
+                ValueShape::Object
+            }
+            RefOr::T(Schema::Object(_)) => ValueShape::Scalar,
+            _ => ValueShape::Object,
+        }
+    }
+
+    /// OpenAPI format of an inline parameter schema, or of its items for arrays
+    fn value_format(schema_ref: &RefOr<Schema>) -> Option<String> {
+        let format = match schema_ref {
+            RefOr::T(Schema::Object(obj_schema)) => obj_schema.format.as_ref()?,
+            RefOr::T(Schema::Array(arr_schema)) => match &arr_schema.items {
+                ArrayItems::RefOrSchema(items) => match items.as_ref() {
+                    RefOr::T(Schema::Object(obj_schema)) => obj_schema.format.as_ref()?,
+                    _ => return None,
+                },
+                ArrayItems::False => return None,
+            },
+            _ => return None,
+        };
+        serde_json::to_value(format)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+    }
+
+    /// Request model of a body referencing a component schema, directly or as array items
+    fn request_model(&self, schema_ref: &RefOr<Schema>) -> Option<String> {
+        let reference = match schema_ref {
+            RefOr::Ref(reference) => reference,
+            RefOr::T(Schema::Array(arr_schema)) => match &arr_schema.items {
+                ArrayItems::RefOrSchema(items) => match items.as_ref() {
+                    RefOr::Ref(reference) => reference,
+                    RefOr::T(_) => return None,
+                },
+                ArrayItems::False => return None,
+            },
+            RefOr::T(_) => return None,
+        };
+        let name = reference
+            .ref_location
+            .strip_prefix("#/components/schemas/")?;
+        self.request_models.get(name).cloned()
+    }
+
     /// Map schema reference to TypeScript type
     fn map_schema_ref_to_type(
         &self,
@@ -195,3 +628,89 @@ impl ParameterExtractor {
         base_name.to_pascal_case()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utoipa::openapi::path::{OperationBuilder, ParameterBuilder};
+    use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder};
+
+    fn string_array() -> ArrayBuilder {
+        ArrayBuilder::new().items(ObjectBuilder::new().schema_type(Type::String))
+    }
+
+    fn extract(parameters: Vec<ParameterBuilder>) -> ExtractedParameters {
+        let operation = OperationBuilder::new()
+            .parameters(Some(parameters.into_iter().map(ParameterBuilder::build)))
+            .build();
+        ParameterExtractor::new()
+            .extract_parameters(&operation, "/colors/{color}")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_default_styles_per_location() {
+        let extracted = extract(vec![
+            ParameterBuilder::new()
+                .name("color")
+                .parameter_in(ParameterIn::Path)
+                .schema(Some(string_array())),
+            ParameterBuilder::new()
+                .name("tags")
+                .parameter_in(ParameterIn::Query)
+                .schema(Some(string_array())),
+            ParameterBuilder::new()
+                .name("page")
+                .parameter_in(ParameterIn::Query)
+                .schema(Some(ObjectBuilder::new().schema_type(Type::Integer))),
+        ]);
+
+        let color = &extracted.path_params[0];
+        assert_eq!(color.style, SerializationStyle::Simple);
+        assert!(!color.explode);
+        assert!(color.needs_serialization());
+
+        let tags = &extracted.query_params[0];
+        assert_eq!(tags.shape, ValueShape::Array);
+        assert_eq!(tags.style, SerializationStyle::Form);
+        assert!(tags.explode);
+
+        let page = &extracted.query_params[1];
+        assert_eq!(page.shape, ValueShape::Scalar);
+        assert!(!page.needs_serialization());
+    }
+
+    #[test]
+    fn test_explicit_style_and_explode() {
+        let extracted = extract(vec![
+            ParameterBuilder::new()
+                .name("color")
+                .parameter_in(ParameterIn::Path)
+                .style(Some(ParameterStyle::Matrix))
+                .schema(Some(ObjectBuilder::new().schema_type(Type::String))),
+            ParameterBuilder::new()
+                .name("levels")
+                .parameter_in(ParameterIn::Query)
+                .style(Some(ParameterStyle::PipeDelimited))
+                .explode(Some(false))
+                .schema(Some(string_array())),
+            ParameterBuilder::new()
+                .name("trace")
+                .parameter_in(ParameterIn::Header)
+                .schema(Some(string_array())),
+        ]);
+
+        assert_eq!(
+            extracted.path_params[0].serialize_expr(true),
+            "serializeParameter('color', color, 'matrix', false)"
+        );
+        assert_eq!(
+            extracted.query_params[0].serialize_expr(true),
+            "serializeParameter('levels', levels, 'pipeDelimited', false)"
+        );
+        assert_eq!(
+            extracted.header_params[0].serialize_expr(false),
+            "serializeParameter('trace', trace, 'simple', false, false)"
+        );
+    }
+}
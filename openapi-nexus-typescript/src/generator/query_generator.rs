@@ -0,0 +1,325 @@
+//! TanStack Query hooks generator for TypeScript
+//!
+//! Every operation gets a query-key factory and either `xQueryOptions` and
+//! `useXQuery`, or `useXMutation`, in a single `queries` module layered on top
+//! of the API client. GET operations are queries and the others mutations,
+//! unless their `x-tanstack-query` extension says otherwise.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use heck::{ToPascalCase as _, ToShoutySnakeCase as _};
+use http::Method;
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::path::Operation;
+
+use crate::ast::{
+    TsExpression, TsInterfaceDefinition, TsInterfaceSignature, TsNode, TsPrimitive, TsProperty,
+    TsTypeDefinition, TsValidation,
+};
+use crate::config::ClientStyle;
+use crate::core::GeneratorError;
+use crate::emission::TsFileCategory;
+use crate::emission::ts_dependency_analyzer::TsDependencyAnalyzer;
+use crate::generator::api_class_generator::ApiClassGenerator;
+use crate::generator::file_generator::GeneratedFile;
+use crate::templating::TemplatingEmitter;
+
+/// Whether an operation is read with a query or run with a mutation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryKind {
+    Query,
+    Mutation,
+}
+
+impl QueryKind {
+    /// Kind of an operation, as declared by its `x-tanstack-query` extension,
+    /// or else a query for GET operations and a mutation for the others
+    pub fn from_operation(
+        http_method: &Method,
+        operation: &Operation,
+    ) -> Result<Self, GeneratorError> {
+        match operation
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("x-tanstack-query"))
+        {
+            Some(extension) => {
+                serde_json::from_value(extension.clone()).map_err(|e| GeneratorError::Generic {
+                    message: format!("Invalid x-tanstack-query extension: {}", e),
+                })
+            }
+            None if http_method == Method::GET => Ok(QueryKind::Query),
+            None => Ok(QueryKind::Mutation),
+        }
+    }
+}
+
+/// TanStack Query hooks generator
+#[derive(Debug, Clone)]
+pub struct QueryHooksGenerator {
+    templating: TemplatingEmitter,
+    client_style: ClientStyle,
+}
+
+/// Hooks of an operation, as rendered by the template
+#[derive(Debug, Clone, Serialize)]
+struct QueryOperationData {
+    /// Name of the operation's method or function
+    name: String,
+    /// PascalCase name, prefixing the hooks and the parameters interface
+    pascal_name: String,
+    /// First element of the query keys, the operation ID when declared
+    key: String,
+    kind: QueryKind,
+    /// Type of the API client the hooks take
+    api_type: String,
+    /// Name of the parameter holding the API client
+    api_param: String,
+    /// Method or function making the request
+    callee: String,
+    /// Arguments of the call before `initOverrides`, the parameters read from
+    /// their object
+    call_args: Vec<String>,
+    /// Type of the value the operation resolves to
+    value_type: String,
+    /// Properties of the parameters object, in the order of the call
+    params: Vec<QueryParamData>,
+    /// Whether every parameter is optional, defaulting the object to `{}`
+    params_optional: bool,
+    summary: Option<String>,
+}
+
+/// Property of the parameters object of an operation
+#[derive(Debug, Clone, Serialize)]
+struct QueryParamData {
+    name: String,
+    type_expr: String,
+    optional: bool,
+}
+
+/// Invalidation helper of the queries of a tag, as rendered by the template
+#[derive(Debug, Clone, Serialize)]
+struct QueryTagData {
+    name: String,
+    pascal_name: String,
+    /// Name of the constant listing the query keys of the tag
+    keys_name: String,
+    keys: Vec<String>,
+}
+
+impl QueryHooksGenerator {
+    /// Create a new TanStack Query hooks generator
+    pub fn new(max_line_width: usize, client_style: ClientStyle) -> Self {
+        Self {
+            templating: TemplatingEmitter::new(max_line_width),
+            client_style,
+        }
+    }
+
+    /// Generate the `queries/index.ts` module holding the hooks of all operations
+    ///
+    /// Operations are grouped by tag; one listed under several tags gets its
+    /// hooks once, on the API class of its first tag, and is invalidated with
+    /// the queries of each. Operations streaming their values get none. Only
+    /// the given models are imported.
+    pub fn generate_queries_file(
+        &self,
+        api_class_generator: &ApiClassGenerator,
+        tag_operations: &BTreeMap<String, Vec<(String, String, Operation)>>,
+        models: &HashSet<String>,
+    ) -> Result<GeneratedFile, GeneratorError> {
+        let mut operations = Vec::new();
+        let mut tags = Vec::new();
+        let mut generated = BTreeSet::new();
+        let mut api_types = BTreeSet::new();
+        let mut model_types = BTreeSet::new();
+        for (tag, tag_operations) in tag_operations {
+            let mut keys = Vec::new();
+            for (path, method_name, operation) in tag_operations {
+                let http_method =
+                    method_name
+                        .parse::<Method>()
+                        .map_err(|e| GeneratorError::Generic {
+                            message: format!("Invalid HTTP method '{}': {}", method_name, e),
+                        })?;
+                if api_class_generator.streams_values(&http_method, operation) {
+                    continue;
+                }
+                let name = api_class_generator.generate_method_name(path, operation, &http_method);
+                let kind = QueryKind::from_operation(&http_method, operation)?;
+                let key = operation
+                    .operation_id
+                    .clone()
+                    .unwrap_or_else(|| name.clone());
+                if kind == QueryKind::Query {
+                    keys.push(key.clone());
+                }
+                if !generated.insert((path.clone(), method_name.clone())) {
+                    continue;
+                }
+
+                let parameters = api_class_generator.operation_parameters(path, operation)?;
+                let mut call_args: Vec<String> = parameters
+                    .iter()
+                    .map(|parameter| format!("params.{}", parameter.name))
+                    .collect();
+                let (api_type, api_param, callee, value_type) = match self.client_style {
+                    ClientStyle::Classes => {
+                        let first_tag = operation
+                            .tags
+                            .as_ref()
+                            .and_then(|tags| tags.first())
+                            .unwrap_or(tag);
+                        let api_type = format!("{}Api", first_tag.to_pascal_case());
+                        let value_type = format!("Awaited<ReturnType<{}['{}']>>", api_type, name);
+                        api_types.insert(api_type.clone());
+                        (api_type, "api", format!("api.{}", name), value_type)
+                    }
+                    ClientStyle::Functions => {
+                        call_args.insert(0, "client".to_string());
+                        api_types.insert(name.clone());
+                        (
+                            "Client".to_string(),
+                            "client",
+                            name.clone(),
+                            format!("Awaited<ReturnType<typeof {}>>", name),
+                        )
+                    }
+                };
+
+                let pascal_name = name.to_pascal_case();
+                let properties: Vec<TsProperty> = parameters
+                    .iter()
+                    .map(|parameter| TsProperty {
+                        name: parameter.name.clone(),
+                        type_expr: parameter
+                            .type_expr
+                            .clone()
+                            .unwrap_or(TsExpression::Primitive(TsPrimitive::Any)),
+                        optional: parameter.optional,
+                        documentation: None,
+                        format: None,
+                        validation: TsValidation::default(),
+                    })
+                    .collect();
+                let params = properties
+                    .iter()
+                    .map(|property| QueryParamData {
+                        name: property.name.clone(),
+                        type_expr: property.type_expr.to_string(),
+                        optional: property.optional,
+                    })
+                    .collect();
+                let interface = TsInterfaceDefinition::new(TsInterfaceSignature::new(format!(
+                    "{}Params",
+                    pascal_name
+                )))
+                .with_properties(properties);
+                model_types.extend(
+                    TsDependencyAnalyzer::new()
+                        .analyze_dependencies(&[TsNode::TypeDefinition(
+                            TsTypeDefinition::Interface(interface),
+                        )])
+                        .model_dependencies
+                        .into_iter()
+                        .filter(|type_name| models.contains(type_name)),
+                );
+
+                operations.push(QueryOperationData {
+                    pascal_name,
+                    key,
+                    kind,
+                    api_type,
+                    api_param: api_param.to_string(),
+                    callee,
+                    call_args,
+                    value_type,
+                    params_optional: parameters.iter().all(|parameter| parameter.optional),
+                    params,
+                    summary: operation
+                        .summary
+                        .clone()
+                        .or_else(|| operation.description.clone()),
+                    name,
+                });
+            }
+            if !keys.is_empty() {
+                tags.push(QueryTagData {
+                    name: tag.clone(),
+                    pascal_name: tag.to_pascal_case(),
+                    keys_name: format!("{}_QUERY_KEYS", tag.to_shouty_snake_case()),
+                    keys,
+                });
+            }
+        }
+
+        let data = serde_json::json!({
+            "operations": operations,
+            "tags": tags,
+            "client_style": match self.client_style {
+                ClientStyle::Classes => "classes",
+                ClientStyle::Functions => "functions",
+            },
+            "api_imports": api_types,
+            "model_imports": model_types,
+        });
+        let content = self
+            .templating
+            .emit_queries(&data)
+            .map_err(|e| GeneratorError::Generic {
+                message: format!("Failed to generate TanStack Query hooks: {}", e),
+            })?;
+
+        Ok(GeneratedFile {
+            filename: "queries/index.ts".to_string(),
+            content,
+            file_category: TsFileCategory::Index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utoipa::openapi::extensions::ExtensionsBuilder;
+    use utoipa::openapi::path::OperationBuilder;
+
+    fn operation(extension: Option<serde_json::Value>) -> Operation {
+        OperationBuilder::new()
+            .extensions(extension.map(|extension| {
+                ExtensionsBuilder::new()
+                    .add("x-tanstack-query", extension)
+                    .build()
+            }))
+            .build()
+    }
+
+    #[test]
+    fn test_kind_from_method() {
+        assert_eq!(
+            QueryKind::from_operation(&Method::GET, &operation(None)).unwrap(),
+            QueryKind::Query
+        );
+        assert_eq!(
+            QueryKind::from_operation(&Method::POST, &operation(None)).unwrap(),
+            QueryKind::Mutation
+        );
+    }
+
+    #[test]
+    fn test_kind_from_extension() {
+        let search = operation(Some(serde_json::json!("query")));
+        assert_eq!(
+            QueryKind::from_operation(&Method::POST, &search).unwrap(),
+            QueryKind::Query
+        );
+        let refresh = operation(Some(serde_json::json!("mutation")));
+        assert_eq!(
+            QueryKind::from_operation(&Method::GET, &refresh).unwrap(),
+            QueryKind::Mutation
+        );
+        let invalid = operation(Some(serde_json::json!(true)));
+        assert!(QueryKind::from_operation(&Method::GET, &invalid).is_err());
+    }
+}
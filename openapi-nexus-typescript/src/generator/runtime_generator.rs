@@ -1,15 +1,20 @@
 //! Runtime module generator for TypeScript using template-based approach
 
+use crate::config::TypeMappingConfig;
 use crate::core::GeneratorError;
 use crate::emission::TsFileCategory;
 use crate::generator::file_generator::GeneratedFile;
 use crate::templating::TemplatingEmitter;
+use crate::templating::data::RuntimeData;
 use utoipa::openapi::OpenApi;
 
 /// Runtime module generator using template-based approach
 #[derive(Debug, Clone)]
 pub struct RuntimeGenerator {
     templating: TemplatingEmitter,
+    type_mapping: TypeMappingConfig,
+    /// Let generated methods check responses against their schemas
+    validate_responses: bool,
 }
 
 impl RuntimeGenerator {
@@ -17,9 +22,23 @@ impl RuntimeGenerator {
     pub fn new(max_line_width: usize) -> Self {
         Self {
             templating: TemplatingEmitter::new(max_line_width),
+            type_mapping: TypeMappingConfig::default(),
+            validate_responses: false,
         }
     }
 
+    /// Generate a runtime supporting the given type mapping
+    pub fn with_type_mapping(mut self, type_mapping: TypeMappingConfig) -> Self {
+        self.type_mapping = type_mapping;
+        self
+    }
+
+    /// Generate a runtime able to validate responses against schemas
+    pub fn with_response_validation(mut self, validate_responses: bool) -> Self {
+        self.validate_responses = validate_responses;
+        self
+    }
+
     /// Generate runtime files using template-based approach
     pub fn generate_runtime_files(
         &self,
@@ -28,12 +47,15 @@ impl RuntimeGenerator {
         let mut files = Vec::new();
 
         // Generate the main runtime file using template
-        let runtime_content =
-            self.templating
-                .emit_runtime_file(openapi)
-                .map_err(|e| GeneratorError::Generic {
-                    message: format!("Failed to generate runtime file: {}", e),
-                })?;
+        let mut runtime_data = RuntimeData::from_openapi(openapi);
+        runtime_data.lossless_int64 = self.type_mapping.int64.is_lossless();
+        runtime_data.validate_responses = self.validate_responses;
+        let runtime_content = self
+            .templating
+            .emit_runtime_with_data(&runtime_data)
+            .map_err(|e| GeneratorError::Generic {
+                message: format!("Failed to generate runtime file: {}", e),
+            })?;
 
         files.push(GeneratedFile {
             filename: "runtime.ts".to_string(),
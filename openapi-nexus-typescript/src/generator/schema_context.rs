@@ -5,8 +5,20 @@
 
 use std::collections::{BTreeMap, HashSet};
 
+use heck::ToPascalCase as _;
+use openapi_nexus_ir::DiscriminatedUnion;
 use utoipa::openapi::{RefOr, Schema};
 
+/// Side of the API a generated type is used on
+///
+/// Response types omit `writeOnly` properties, request types omit `readOnly` ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchemaDirection {
+    #[default]
+    Response,
+    Request,
+}
+
 /// Context for schema resolution with reference tracking
 ///
 /// This context provides access to all available schemas and tracks visited schemas
@@ -18,6 +30,10 @@ pub struct SchemaContext<'a> {
     pub visited: &'a mut HashSet<String>,
     /// Current resolution depth (for debugging)
     pub depth: usize,
+    /// Discriminated unions among the component schemas
+    pub discriminated_unions: Vec<DiscriminatedUnion>,
+    /// Side of the API the types being generated are used on
+    pub direction: SchemaDirection,
 }
 
 impl<'a> SchemaContext<'a> {
@@ -30,7 +46,55 @@ impl<'a> SchemaContext<'a> {
             schemas,
             visited,
             depth: 0,
+            discriminated_unions: Vec::new(),
+            direction: SchemaDirection::default(),
+        }
+    }
+
+    /// Attach the discriminated unions found among the component schemas
+    pub fn with_discriminated_unions(mut self, unions: Vec<DiscriminatedUnion>) -> Self {
+        self.discriminated_unions = unions;
+        self
+    }
+
+    /// Discriminated union defined by the schema with the given TypeScript (PascalCase) name
+    pub fn discriminated_union(&self, type_name: &str) -> Option<&DiscriminatedUnion> {
+        self.discriminated_unions
+            .iter()
+            .find(|union| union.schema_name.to_pascal_case() == type_name)
+    }
+
+    /// Discriminator properties of a union member, with the values selecting it
+    ///
+    /// Members are matched by their TypeScript (PascalCase) name.
+    pub fn discriminator_values(&self, member_name: &str) -> Vec<(&str, Vec<&str>)> {
+        let mut result: Vec<(&str, Vec<&str>)> = Vec::new();
+        for union in &self.discriminated_unions {
+            let values: Vec<&str> = union
+                .mapping
+                .iter()
+                .filter(|(_, member)| member.to_pascal_case() == member_name)
+                .map(|(value, _)| value.as_str())
+                .collect();
+            if values.is_empty() {
+                continue;
+            }
+            match result
+                .iter_mut()
+                .find(|(property, _)| *property == union.property_name)
+            {
+                Some((_, existing)) => {
+                    // A member of several unions accepts the values of all of them
+                    for value in values {
+                        if !existing.contains(&value) {
+                            existing.push(value);
+                        }
+                    }
+                }
+                None => result.push((union.property_name.as_str(), values)),
+            }
         }
+        result
     }
 
     /// Check if a schema has been visited (circular dependency detection)
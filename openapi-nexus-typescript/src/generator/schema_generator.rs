@@ -6,27 +6,37 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use heck::ToPascalCase as _;
+use openapi_nexus_ir::DiscriminatorIssue;
 use utoipa::openapi::schema::{
-    AdditionalProperties, KnownFormat, Object, SchemaFormat, SchemaType, Type,
+    AdditionalProperties, ArrayItems, KnownFormat, Object, SchemaFormat, SchemaType, Type,
 };
 use utoipa::openapi::{RefOr, Schema};
 
 use crate::ast::{
-    TsDocComment, TsEnumDefinition, TsEnumVariant, TsExpression, TsInterfaceDefinition,
-    TsInterfaceSignature, TsNode, TsPrimitive, TsProperty, TsTypeAliasDefinition, TsTypeDefinition,
+    TsDiscriminator, TsDocComment, TsEnumDefinition, TsEnumVariant, TsExpression,
+    TsInterfaceDefinition, TsInterfaceSignature, TsNode, TsPrimitive, TsProperty,
+    TsTypeAliasDefinition, TsTypeDefinition, TsValidation,
 };
+use crate::config::{Int64Strategy, TypeMappingConfig};
 use crate::core::GeneratorError;
-use crate::generator::schema_context::SchemaContext;
+use crate::generator::schema_context::{SchemaContext, SchemaDirection};
 
 /// Schema generator for converting OpenAPI schemas to TypeScript AST nodes
 ///
 /// This generator consolidates both schema-to-node conversion and type mapping functionality,
 /// providing comprehensive OpenAPI 3.1.2 support including nullable types, format handling,
 /// discriminators, additionalProperties, and multi-type support.
-#[derive(Debug, Clone)]
-pub struct SchemaGenerator;
+#[derive(Debug, Clone, Default)]
+pub struct SchemaGenerator {
+    type_mapping: TypeMappingConfig,
+}
 
 impl SchemaGenerator {
+    /// Create a new schema generator
+    pub fn new(type_mapping: TypeMappingConfig) -> Self {
+        Self { type_mapping }
+    }
+
     /// Convert a schema reference to a TypeScript AST node
     ///
     /// This is the main public API method used by TsLangGenerator.
@@ -52,6 +62,45 @@ impl SchemaGenerator {
         }
     }
 
+    /// Build the request-side variant of a component schema, named `{name}Input`
+    ///
+    /// Returns `None` when the request and response shapes are identical, i.e. no
+    /// `readOnly` or `writeOnly` property is reachable from the schema.
+    pub fn schema_to_input_ts_node(
+        &self,
+        name: &str,
+        schema_ref: &RefOr<Schema>,
+        response_node: &TsNode,
+        context: &mut SchemaContext,
+    ) -> Result<Option<TsNode>, GeneratorError> {
+        let TsNode::TypeDefinition(TsTypeDefinition::Interface(response)) = response_node else {
+            return Ok(None);
+        };
+
+        context.direction = SchemaDirection::Request;
+        let result = self.schema_to_ts_node(name, schema_ref, context);
+        context.direction = SchemaDirection::Response;
+
+        let TsNode::TypeDefinition(TsTypeDefinition::Interface(mut request)) = result? else {
+            return Ok(None);
+        };
+        let same_shape = serde_json::to_value(&request.properties).ok()
+            == serde_json::to_value(&response.properties).ok();
+        if same_shape {
+            return Ok(None);
+        }
+
+        request.signature.name = Self::input_type_name(name);
+        Ok(Some(TsNode::TypeDefinition(TsTypeDefinition::Interface(
+            request,
+        ))))
+    }
+
+    /// Name of the request-side variant of a component schema
+    pub fn input_type_name(name: &str) -> String {
+        format!("{}Input", name.to_pascal_case())
+    }
+
     // ============================================================================
     // SCHEMA-TO-NODE CONVERSION (Private Methods)
     // ============================================================================
@@ -65,9 +114,11 @@ impl SchemaGenerator {
     ) -> Result<TsNode, GeneratorError> {
         match schema {
             Schema::Object(obj_schema) => {
-                // Check if this is an enum schema
+                // Check if this is an enum schema; booleans and mixed values
+                // cannot be TypeScript enum members and become a literal union
                 if let Some(enum_values) = &obj_schema.enum_values
                     && !enum_values.is_empty()
+                    && Self::has_enum_members(enum_values)
                 {
                     return Ok(TsNode::TypeDefinition(TsTypeDefinition::Enum(
                         self.schema_to_enum(name, schema)?,
@@ -96,6 +147,7 @@ impl SchemaGenerator {
                         type_expr,
                         generics: vec![],
                         documentation: obj_schema.description.clone().map(TsDocComment::new),
+                        discriminator: None,
                     },
                 )))
             }
@@ -108,10 +160,18 @@ impl SchemaGenerator {
                         type_expr,
                         generics: vec![],
                         documentation: None,
+                        discriminator: None,
                     },
                 )))
             }
             Schema::OneOf(_) | Schema::AllOf(_) | Schema::AnyOf(_) => {
+                // Discriminated unions alias their named members so they can be narrowed
+                if let Some(type_alias) = self.discriminated_union_alias(name, context) {
+                    return Ok(TsNode::TypeDefinition(TsTypeDefinition::TypeAlias(
+                        type_alias,
+                    )));
+                }
+
                 // Composition schemas become type aliases with union/intersection types
                 let type_expr = self.map_schema_to_type(schema, context);
                 Ok(TsNode::TypeDefinition(TsTypeDefinition::TypeAlias(
@@ -120,6 +180,7 @@ impl SchemaGenerator {
                         type_expr,
                         generics: vec![],
                         documentation: None,
+                        discriminator: None,
                     },
                 )))
             }
@@ -131,6 +192,7 @@ impl SchemaGenerator {
                         type_expr: TsExpression::Primitive(TsPrimitive::Any),
                         generics: vec![],
                         documentation: None,
+                        discriminator: None,
                     },
                 )))
             }
@@ -150,6 +212,9 @@ impl SchemaGenerator {
 
                 // Extract properties from the object schema
                 for (prop_name, prop_schema) in &obj_schema.properties {
+                    if Self::is_omitted(prop_schema, context.direction) {
+                        continue;
+                    }
                     let type_expr = self.map_ref_or_schema_to_type(prop_schema, context);
                     let is_required = obj_schema.required.contains(prop_name);
                     let description = self.extract_description_from_schema(prop_schema);
@@ -159,10 +224,41 @@ impl SchemaGenerator {
                         type_expr,
                         optional: !is_required,
                         documentation: description.map(TsDocComment::new),
+                        format: self.value_format(prop_schema, context),
+                        validation: self.validation(prop_schema, context),
                     };
                     properties.push(property);
                 }
 
+                // Union members declare the discriminator values selecting them as literals
+                let discriminators: Vec<(String, TsExpression)> = context
+                    .discriminator_values(name)
+                    .into_iter()
+                    .map(|(property, values)| {
+                        let literals: Vec<serde_json::Value> = values
+                            .into_iter()
+                            .map(|value| serde_json::Value::String(value.to_string()))
+                            .collect();
+                        (property.to_string(), self.map_enum_to_type(&literals))
+                    })
+                    .collect();
+                for (property_name, type_expr) in discriminators {
+                    match properties.iter_mut().find(|p| p.name == property_name) {
+                        Some(property) => {
+                            property.type_expr = type_expr;
+                            property.optional = false;
+                        }
+                        None => properties.push(TsProperty {
+                            name: property_name,
+                            type_expr,
+                            optional: false,
+                            documentation: None,
+                            format: None,
+                            validation: TsValidation::default(),
+                        }),
+                    }
+                }
+
                 // Handle additionalProperties as index signature
                 if let Some(additional_props) = &obj_schema.additional_properties {
                     match additional_props.as_ref() {
@@ -184,9 +280,11 @@ impl SchemaGenerator {
                             //   [key: string]: string | number;  // Union of all property types
                             // }
                             if !obj_schema.properties.is_empty() {
+                                let direction = context.direction;
                                 let mut unique_types: BTreeSet<TsExpression> = obj_schema
                                     .properties
                                     .values()
+                                    .filter(|prop_schema| !Self::is_omitted(prop_schema, direction))
                                     .map(|prop_schema| {
                                         self.map_ref_or_schema_to_type(prop_schema, context)
                                     })
@@ -205,6 +303,8 @@ impl SchemaGenerator {
                                 documentation: Some(TsDocComment::new(
                                     "Additional properties".to_string(),
                                 )),
+                                format: None,
+                                validation: TsValidation::default(),
                             };
                             properties.push(index_property);
                         }
@@ -216,6 +316,8 @@ impl SchemaGenerator {
                                 documentation: Some(TsDocComment::new(
                                     "Additional properties".to_string(),
                                 )),
+                                format: None,
+                                validation: TsValidation::default(),
                             };
                             properties.push(index_property);
                         }
@@ -242,6 +344,70 @@ impl SchemaGenerator {
         }
     }
 
+    /// Build the type alias of a discriminated union component
+    ///
+    /// Returns `None` when the schema is not a discriminated union, or when some
+    /// member cannot be selected by a discriminator value; such unions are
+    /// emitted as plain unions instead.
+    fn discriminated_union_alias(
+        &self,
+        name: &str,
+        context: &SchemaContext,
+    ) -> Option<TsTypeAliasDefinition> {
+        let union = context.discriminated_union(name)?;
+        if union.issues.iter().any(|issue| {
+            matches!(
+                issue,
+                DiscriminatorIssue::UnmappedMember { .. }
+                    | DiscriminatorIssue::UnknownTarget { .. }
+            )
+        }) {
+            tracing::warn!(
+                "Discriminator of '{}' does not cover every member, emitting a plain union",
+                union.schema_name
+            );
+            return None;
+        }
+
+        let discriminator = TsDiscriminator::new(
+            union.property_name.clone(),
+            union
+                .mapping
+                .iter()
+                .map(|(value, member)| (value.clone(), member.to_pascal_case()))
+                .collect(),
+        );
+        let mut members: BTreeSet<TsExpression> = discriminator
+            .member_types()
+            .into_iter()
+            .map(|member| TsExpression::Reference(member.to_string()))
+            .collect();
+        let type_expr = if members.len() == 1 {
+            members.pop_first()?
+        } else {
+            TsExpression::Union(members)
+        };
+
+        Some(
+            TsTypeAliasDefinition::new(name.to_string(), type_expr)
+                .with_discriminator(discriminator),
+        )
+    }
+
+    /// Whether a property is left out of types used in the given direction
+    ///
+    /// `readOnly` properties are populated by the server and `writeOnly` ones are
+    /// never returned by it.
+    fn is_omitted(prop_schema: &RefOr<Schema>, direction: SchemaDirection) -> bool {
+        let RefOr::T(Schema::Object(prop)) = prop_schema else {
+            return false;
+        };
+        match direction {
+            SchemaDirection::Request => prop.read_only == Some(true),
+            SchemaDirection::Response => prop.write_only == Some(true),
+        }
+    }
+
     /// Convert a schema to a TypeScript enum
     fn schema_to_enum(
         &self,
@@ -252,20 +418,36 @@ impl SchemaGenerator {
             Schema::Object(obj_schema) => {
                 let mut variants: Vec<TsEnumVariant> = Vec::new();
 
+                // `x-enum-varnames` names the variants when it covers every value
+                let var_names: Option<Vec<&str>> = obj_schema
+                    .extensions
+                    .as_ref()
+                    .and_then(|extensions| extensions.get("x-enum-varnames"))
+                    .and_then(serde_json::Value::as_array)
+                    .and_then(|names| names.iter().map(serde_json::Value::as_str).collect());
+
                 if let Some(enum_values) = &obj_schema.enum_values {
-                    for enum_value in enum_values {
-                        // Convert serde_json::Value to string
-                        let value_str = match enum_value {
-                            serde_json::Value::String(s) => s.clone(),
-                            serde_json::Value::Number(n) => n.to_string(),
-                            serde_json::Value::Bool(b) => b.to_string(),
-                            _ => enum_value.to_string().trim_matches('"').to_string(),
+                    let var_names = var_names.filter(|names| names.len() == enum_values.len());
+
+                    for (index, enum_value) in enum_values.iter().enumerate() {
+                        // Enums cannot hold null; nullability stays on the referencing property
+                        if enum_value.is_null() {
+                            continue;
+                        }
+
+                        // String members are emitted as quoted literals, numbers as-is
+                        let (raw_name, value_str) = match enum_value {
+                            serde_json::Value::String(s) => (s.clone(), enum_value.to_string()),
+                            serde_json::Value::Number(n) => (n.to_string(), n.to_string()),
+                            _ => (enum_value.to_string(), enum_value.to_string()),
                         };
 
-                        let variant_name = if value_str.chars().all(|c| c.is_ascii_digit()) {
-                            format!("_{}", value_str)
-                        } else {
-                            value_str.to_pascal_case()
+                        let variant_name = match &var_names {
+                            Some(names) => names[index].to_string(),
+                            None if raw_name.chars().all(|c| c.is_ascii_digit()) => {
+                                format!("_{}", raw_name)
+                            }
+                            None => raw_name.to_pascal_case(),
                         };
                         let variant = TsEnumVariant {
                             name: variant_name,
@@ -438,6 +620,19 @@ impl SchemaGenerator {
         }
     }
 
+    /// Whether enum values fit a TypeScript `enum`: all strings or all numbers
+    fn has_enum_members(enum_values: &[serde_json::Value]) -> bool {
+        let mut values = enum_values.iter().filter(|value| !value.is_null());
+        let Some(first) = values.next() else {
+            return false;
+        };
+        match first {
+            serde_json::Value::String(_) => values.all(serde_json::Value::is_string),
+            serde_json::Value::Number(_) => values.all(serde_json::Value::is_number),
+            _ => false,
+        }
+    }
+
     /// Map enum values to TypeScript type
     fn map_enum_to_type(&self, enum_values: &[serde_json::Value]) -> TsExpression {
         let mut types = Vec::new();
@@ -482,6 +677,9 @@ impl SchemaGenerator {
 
         // Map each property to its TypeScript type
         for (prop_name, prop_schema) in &obj_schema.properties {
+            if Self::is_omitted(prop_schema, context.direction) {
+                continue;
+            }
             let type_expr = self.map_ref_or_schema_to_type(prop_schema, context);
             properties.insert(prop_name.clone(), type_expr);
         }
@@ -501,8 +699,8 @@ impl SchemaGenerator {
             .map(|schema_ref| self.map_ref_or_schema_to_type(schema_ref, context))
             .collect();
 
-        // TODO: Implement proper discriminator handling for discriminated unions
-        // For now, just return a union type with deduplication
+        // Named discriminated unions are aliased in `discriminated_union_alias`;
+        // inline ones have no name to hang helpers on and stay plain unions
         if types.len() == 1 {
             types.into_iter().next().unwrap()
         } else {
@@ -553,6 +751,9 @@ impl SchemaGenerator {
         match schema_ref {
             RefOr::T(schema) => match schema {
                 Schema::Object(obj_schema) => obj_schema.description.clone(),
+                Schema::AllOf(all_of) => all_of.description.clone(),
+                Schema::OneOf(one_of) => one_of.description.clone(),
+                Schema::AnyOf(any_of) => any_of.description.clone(),
                 _ => None,
             },
             RefOr::Ref(reference) => Some(reference.description.clone()),
@@ -560,37 +761,134 @@ impl SchemaGenerator {
     }
 
     /// Handle known format annotations for better type inference
-    /// TODO: Implement proper format handling for better type inference
     fn handle_known_format(
         &self,
         base_type: TsExpression,
         format: &Option<SchemaFormat>,
     ) -> TsExpression {
-        // For now, format doesn't change the base type, but we could add comments or
-        // more specific types in the future (e.g., branded types for email, uuid, etc.)
         match format {
-            Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)) => {
-                // Could generate branded type for date-time in the future
-                base_type
+            Some(SchemaFormat::KnownFormat(KnownFormat::DateTime | KnownFormat::Date))
+                if self.type_mapping.use_date_type =>
+            {
+                // Converted by the generated JSON helpers and API methods
+                TsExpression::Reference("Date".to_string())
             }
-            Some(SchemaFormat::KnownFormat(KnownFormat::Email)) => {
-                // Could generate branded type for email in the future
+            Some(SchemaFormat::KnownFormat(KnownFormat::Int64)) => match self.type_mapping.int64 {
+                // Converted by the generated JSON helpers, parsed losslessly by the runtime
+                Int64Strategy::BigInt => TsExpression::Primitive(TsPrimitive::BigInt),
+                Int64Strategy::String => TsExpression::Primitive(TsPrimitive::String),
+                Int64Strategy::Number => base_type,
+            },
+            Some(SchemaFormat::KnownFormat(KnownFormat::Int32)) => {
+                // Integer formats still map to number in TypeScript
                 base_type
             }
-            Some(SchemaFormat::KnownFormat(KnownFormat::Uri)) => {
-                // Could generate branded type for URI in the future
-                base_type
+            _ => base_type,
+        }
+    }
+
+    /// OpenAPI format of a property value, or of its items for arrays
+    ///
+    /// Component references are followed so that aliased formats are not lost.
+    fn value_format(&self, schema_ref: &RefOr<Schema>, context: &SchemaContext) -> Option<String> {
+        let schema = match schema_ref {
+            RefOr::T(schema) => schema,
+            RefOr::Ref(reference) => {
+                let schema_name = self.extract_schema_name(&reference.ref_location).ok()?;
+                match context.schemas.get(&schema_name)? {
+                    RefOr::T(schema) => schema,
+                    RefOr::Ref(_) => return None,
+                }
             }
-            Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)) => {
-                // Could generate branded type for UUID in the future
-                base_type
+        };
+
+        match schema {
+            Schema::Object(obj_schema) => obj_schema.format.as_ref().map(|format| {
+                serde_json::to_value(format)
+                    .ok()
+                    .and_then(|value| value.as_str().map(str::to_string))
+                    .unwrap_or_default()
+            }),
+            Schema::Array(arr_schema) => match &arr_schema.items {
+                ArrayItems::RefOrSchema(items) => match items.as_ref() {
+                    // Only one level deep; nested arrays are not converted
+                    RefOr::T(Schema::Array(_)) => None,
+                    items => self.value_format(items, context),
+                },
+                ArrayItems::False => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Constraints of a property value validated at runtime
+    ///
+    /// Component references are followed for primitive and array components;
+    /// nested models are linked once all components are known.
+    fn validation(&self, schema_ref: &RefOr<Schema>, context: &SchemaContext) -> TsValidation {
+        let schema = match schema_ref {
+            RefOr::T(schema) => schema,
+            RefOr::Ref(reference) => {
+                let Some(RefOr::T(schema)) = self
+                    .extract_schema_name(&reference.ref_location)
+                    .ok()
+                    .and_then(|schema_name| context.schemas.get(&schema_name))
+                else {
+                    return TsValidation::default();
+                };
+                schema
             }
-            Some(SchemaFormat::KnownFormat(KnownFormat::Int64))
-            | Some(SchemaFormat::KnownFormat(KnownFormat::Int32)) => {
-                // Integer formats still map to number in TypeScript
-                base_type
+        };
+        let number = |number: &Option<utoipa::Number>| {
+            number
+                .as_ref()
+                .and_then(|number| serde_json::to_value(number).ok())
+                .and_then(|value| match value {
+                    serde_json::Value::Number(number) => Some(number),
+                    _ => None,
+                })
+        };
+
+        match schema {
+            Schema::Object(obj_schema) if obj_schema.properties.is_empty() => {
+                let mut validation = TsValidation {
+                    max_length: obj_schema.max_length,
+                    min_length: obj_schema.min_length,
+                    pattern: obj_schema.pattern.clone(),
+                    maximum: number(&obj_schema.maximum),
+                    minimum: number(&obj_schema.minimum),
+                    multiple_of: number(&obj_schema.multiple_of),
+                    ..TsValidation::default()
+                };
+                // OpenAPI 3.1 gives exclusive bounds as numbers of their own, which
+                // replace the inclusive ones unless those are stricter
+                if let Some(maximum) = number(&obj_schema.exclusive_maximum)
+                    && validation
+                        .maximum
+                        .as_ref()
+                        .is_none_or(|inclusive| maximum.as_f64() <= inclusive.as_f64())
+                {
+                    validation.maximum = Some(maximum);
+                    validation.exclusive_maximum = true;
+                }
+                if let Some(minimum) = number(&obj_schema.exclusive_minimum)
+                    && validation
+                        .minimum
+                        .as_ref()
+                        .is_none_or(|inclusive| minimum.as_f64() >= inclusive.as_f64())
+                {
+                    validation.minimum = Some(minimum);
+                    validation.exclusive_minimum = true;
+                }
+                validation
             }
-            _ => base_type,
+            Schema::Array(arr_schema) => TsValidation {
+                max_items: arr_schema.max_items,
+                min_items: arr_schema.min_items,
+                unique_items: arr_schema.unique_items,
+                ..TsValidation::default()
+            },
+            _ => TsValidation::default(),
         }
     }
 
@@ -620,6 +918,7 @@ impl SchemaGenerator {
                         "Circular reference to {}",
                         schema_name
                     ))),
+                    discriminator: None,
                 },
             )));
         }
@@ -651,6 +950,7 @@ impl SchemaGenerator {
                         "Unresolved reference to {}",
                         schema_name
                     ))),
+                    discriminator: None,
                 },
             )))
         }
@@ -684,6 +984,24 @@ impl SchemaGenerator {
 
         // Look up the actual schema
         if let Some(target_schema) = context.schemas.get(&schema_name) {
+            // Enum components are emitted as named enums and referenced by name
+            if let RefOr::T(Schema::Object(obj_schema)) = target_schema
+                && obj_schema
+                    .enum_values
+                    .as_ref()
+                    .is_some_and(|values| !values.is_empty())
+            {
+                return Ok(TsExpression::Reference(schema_name.to_pascal_case()));
+            }
+
+            // Discriminated unions are referenced by name so they keep their helpers
+            if context
+                .discriminated_union(&schema_name.to_pascal_case())
+                .is_some()
+            {
+                return Ok(TsExpression::Reference(schema_name.to_pascal_case()));
+            }
+
             // Mark as visited to prevent cycles
             let schema_name_clone = schema_name.clone();
             context.mark_visited(schema_name_clone);
@@ -705,8 +1023,126 @@ impl SchemaGenerator {
     }
 }
 
-impl Default for SchemaGenerator {
-    fn default() -> Self {
-        SchemaGenerator
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder};
+
+    fn validation(schema: impl Into<Schema>) -> TsValidation {
+        let schemas = BTreeMap::new();
+        let mut visited = HashSet::new();
+        let context = SchemaContext::new(&schemas, &mut visited);
+        SchemaGenerator::default().validation(&RefOr::T(schema.into()), &context)
+    }
+
+    fn number(value: serde_json::Value) -> Option<serde_json::Number> {
+        match value {
+            serde_json::Value::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_inclusive_bounds() {
+        let validation = validation(
+            ObjectBuilder::new()
+                .schema_type(Type::Number)
+                .minimum(Some(1))
+                .maximum(Some(2.5))
+                .multiple_of(Some(0.5)),
+        );
+        assert_eq!(validation.minimum, number(serde_json::json!(1)));
+        assert!(!validation.exclusive_minimum);
+        assert_eq!(validation.maximum, number(serde_json::json!(2.5)));
+        assert!(!validation.exclusive_maximum);
+        assert_eq!(validation.multiple_of, number(serde_json::json!(0.5)));
+    }
+
+    #[test]
+    fn test_numeric_exclusive_bounds() {
+        let validation = validation(
+            ObjectBuilder::new()
+                .schema_type(Type::Integer)
+                .exclusive_minimum(Some(0))
+                .exclusive_maximum(Some(100)),
+        );
+        assert_eq!(validation.minimum, number(serde_json::json!(0)));
+        assert!(validation.exclusive_minimum);
+        assert_eq!(validation.maximum, number(serde_json::json!(100)));
+        assert!(validation.exclusive_maximum);
+    }
+
+    #[test]
+    fn test_stricter_of_inclusive_and_exclusive_bounds() {
+        let exclusive_stricter = validation(
+            ObjectBuilder::new()
+                .schema_type(Type::Integer)
+                .minimum(Some(0))
+                .exclusive_minimum(Some(0))
+                .maximum(Some(100))
+                .exclusive_maximum(Some(50)),
+        );
+        assert_eq!(exclusive_stricter.minimum, number(serde_json::json!(0)));
+        assert!(exclusive_stricter.exclusive_minimum);
+        assert_eq!(exclusive_stricter.maximum, number(serde_json::json!(50)));
+        assert!(exclusive_stricter.exclusive_maximum);
+
+        let inclusive_stricter = validation(
+            ObjectBuilder::new()
+                .schema_type(Type::Number)
+                .minimum(Some(10))
+                .exclusive_minimum(Some(5))
+                .maximum(Some(20))
+                .exclusive_maximum(Some(20.5)),
+        );
+        assert_eq!(inclusive_stricter.minimum, number(serde_json::json!(10)));
+        assert!(!inclusive_stricter.exclusive_minimum);
+        assert_eq!(inclusive_stricter.maximum, number(serde_json::json!(20)));
+        assert!(!inclusive_stricter.exclusive_maximum);
+    }
+
+    #[test]
+    fn test_string_and_array_constraints() {
+        let string = validation(
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .min_length(Some(1))
+                .max_length(Some(8))
+                .pattern(Some("^[a-z]+$")),
+        );
+        assert_eq!(string.min_length, Some(1));
+        assert_eq!(string.max_length, Some(8));
+        assert_eq!(string.pattern.as_deref(), Some("^[a-z]+$"));
+
+        let array = validation(
+            ArrayBuilder::new()
+                .items(ObjectBuilder::new().schema_type(Type::String))
+                .min_items(Some(1))
+                .max_items(Some(3))
+                .unique_items(true),
+        );
+        assert_eq!(array.min_items, Some(1));
+        assert_eq!(array.max_items, Some(3));
+        assert!(array.unique_items);
+    }
+
+    #[test]
+    fn test_enum_members() {
+        use serde_json::json;
+
+        assert!(SchemaGenerator::has_enum_members(&[json!("a"), json!("b")]));
+        assert!(SchemaGenerator::has_enum_members(&[
+            json!(1),
+            json!(2),
+            json!(null)
+        ]));
+        assert!(!SchemaGenerator::has_enum_members(&[
+            json!(true),
+            json!(false)
+        ]));
+        assert!(!SchemaGenerator::has_enum_members(&[json!("a"), json!(1)]));
+        assert!(!SchemaGenerator::has_enum_members(&[json!(null)]));
     }
 }
@@ -5,6 +5,7 @@ use std::fmt;
 use minijinja::Environment;
 use rust_embed::RustEmbed;
 
+use crate::generator::pagination::PaginationStrategy;
 use crate::generator::templates::Templates;
 
 /// Embedded template files
@@ -202,6 +203,49 @@ pub struct ParameterData {
     pub name: String,
     pub type_expr: Option<String>,
     pub optional: bool,
+    /// Expression turning the value into its wire form
+    pub value_expr: String,
+}
+
+/// Data structure for a request body sent as form data
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct FormBodyData {
+    /// `FormData` for multipart bodies, `URLSearchParams` otherwise
+    pub multipart: bool,
+    pub fields: Vec<FormFieldData>,
+}
+
+/// Data structure for one field of a form body
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct FormFieldData {
+    pub name: String,
+    pub optional: bool,
+    /// How the value is appended: `single`, `items` or `entries`
+    pub kind: String,
+    /// Field name expression for `entries`, in terms of `key`
+    pub key_expr: String,
+    /// Appended value; `items` and `entries` refer to the element as `item`
+    pub value_expr: String,
+}
+
+/// Data structure for the methods walking the pages of a paginated operation
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaginationData {
+    pub strategy: PaginationStrategy,
+    /// Raw method fetching a single page
+    pub raw_method: String,
+    /// Method walking the pages, which the item iterator goes through
+    pub pages_method: String,
+    /// Items of `currentPage`
+    pub items_expr: String,
+    /// Next cursor of `currentPage`, for the cursor strategy
+    pub cursor_expr: Option<String>,
+    /// Parameter advanced from one page to the next
+    pub page_param: Option<String>,
+    /// Offset of the page after `currentPage`, for the offset strategy
+    pub next_offset: Option<String>,
+    /// Page size parameter, when the operation has one
+    pub limit_param: Option<String>,
 }
 
 /// Data structure for README.md generation
@@ -222,10 +266,31 @@ pub struct ApiMethodData {
     pub method_name: String,
     pub http_method: String,
     pub path: String,
+    /// Path as a template literal body, with path parameters interpolated
+    pub url_path: String,
     pub path_params: Vec<ParameterData>,
     pub query_params: Vec<ParameterData>,
     pub header_params: Vec<ParameterData>,
+    /// Cookie parameters, whose values are serialized `name=value` pairs
+    pub cookie_params: Vec<ParameterData>,
     pub body_param: Option<ParameterData>,
+    /// Form body built from individual parameters, replacing `body_param`
+    pub form_body: Option<FormBodyData>,
+    /// Value of the `Content-Type` header, unset when the runtime derives it
+    pub content_type: Option<String>,
+    /// Value of the `Accept` header, set for streamed responses
+    pub accept: Option<String>,
+    /// Runtime class wrapping the response, e.g. `JSONApiResponse`
+    pub response_class: String,
+    /// Typed error class built from non-2xx responses
+    pub error_class: Option<String>,
+    /// Security requirement alternatives, as a TypeScript array expression
+    pub security: Option<String>,
+    /// Servers overriding the API's ones, as the name of their constant
+    pub servers: Option<String>,
+    /// Whether the operation may be retried, when `x-idempotent` overrides
+    /// the default of its HTTP method
+    pub idempotent: Option<bool>,
     pub return_type: String,
     pub has_auth: bool,
     pub has_error_handling: bool,
@@ -290,10 +355,20 @@ mod tests {
                 method_name: "test".to_string(),
                 http_method: "GET".to_string(),
                 path: "/test".to_string(),
+                url_path: "/test".to_string(),
                 path_params: vec![],
                 query_params: vec![],
                 header_params: vec![],
+                cookie_params: vec![],
                 body_param: None,
+                form_body: None,
+                content_type: None,
+                accept: None,
+                response_class: "JSONApiResponse".to_string(),
+                error_class: None,
+                security: None,
+                servers: None,
+                idempotent: None,
                 return_type: "Promise<any>".to_string(),
                 has_auth: false,
                 has_error_handling: false,
@@ -306,10 +381,20 @@ mod tests {
                 method_name: "test".to_string(),
                 http_method: "POST".to_string(),
                 path: "/test".to_string(),
+                url_path: "/test".to_string(),
                 path_params: vec![],
                 query_params: vec![],
                 header_params: vec![],
+                cookie_params: vec![],
                 body_param: None,
+                form_body: None,
+                content_type: None,
+                accept: None,
+                response_class: "JSONApiResponse".to_string(),
+                error_class: None,
+                security: None,
+                servers: None,
+                idempotent: None,
                 return_type: "Promise<any>".to_string(),
                 has_auth: false,
                 has_error_handling: false,
@@ -322,10 +407,20 @@ mod tests {
                 method_name: "test".to_string(),
                 http_method: "DELETE".to_string(),
                 path: "/test".to_string(),
+                url_path: "/test".to_string(),
                 path_params: vec![],
                 query_params: vec![],
                 header_params: vec![],
+                cookie_params: vec![],
                 body_param: None,
+                form_body: None,
+                content_type: None,
+                accept: None,
+                response_class: "JSONApiResponse".to_string(),
+                error_class: None,
+                security: None,
+                servers: None,
+                idempotent: None,
                 return_type: "Promise<any>".to_string(),
                 has_auth: false,
                 has_error_handling: false,
@@ -0,0 +1,1069 @@
+//! Zod schema generator for TypeScript
+//!
+//! Every component schema becomes an `XSchema` constant describing its JSON
+//! form, along with an `X` type inferred from it, in a single `schemas` module.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use heck::ToPascalCase as _;
+use serde::Serialize;
+use utoipa::openapi::schema::{AdditionalProperties, ArrayItems, Object, SchemaType, Type};
+use utoipa::openapi::{OpenApi, RefOr, Schema};
+
+use crate::config::Int64Strategy;
+use crate::core::GeneratorError;
+use crate::emission::TsFileCategory;
+use crate::generator::file_generator::GeneratedFile;
+use crate::templating::TemplatingEmitter;
+use openapi_nexus_ir::{DiscriminatedUnion, SchemaAnalyzer};
+
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Zod schema generator
+#[derive(Debug, Clone)]
+pub struct ZodSchemaGenerator {
+    templating: TemplatingEmitter,
+    int64: Int64Strategy,
+}
+
+/// Zod schema of a component, as rendered by the template
+#[derive(Debug, Clone, Serialize)]
+struct ZodSchemaData {
+    /// Name of the inferred type
+    name: String,
+    /// Name of the schema constant
+    schema_name: String,
+    expr: String,
+    /// Type written out for schemas referring to themselves, which Zod cannot infer
+    type_expr: Option<String>,
+}
+
+/// Component schemas being converted
+struct ZodContext<'a> {
+    schemas: &'a BTreeMap<String, RefOr<Schema>>,
+    unions: Vec<DiscriminatedUnion>,
+    recursive: BTreeSet<String>,
+}
+
+impl ZodSchemaGenerator {
+    /// Create a new Zod schema generator
+    pub fn new(max_line_width: usize, int64: Int64Strategy) -> Self {
+        Self {
+            templating: TemplatingEmitter::new(max_line_width),
+            int64,
+        }
+    }
+
+    /// Name of the Zod schema constant of a component
+    pub fn schema_name(component: &str) -> String {
+        format!("{}Schema", component.to_pascal_case())
+    }
+
+    /// Generate the `schemas/index.ts` module holding the Zod schemas of all components
+    pub fn generate_schemas_file(
+        &self,
+        openapi: &OpenApi,
+    ) -> Result<GeneratedFile, GeneratorError> {
+        let empty = BTreeMap::new();
+        let schemas = openapi
+            .components
+            .as_ref()
+            .map_or(&empty, |components| &components.schemas);
+
+        let dependencies: BTreeMap<&str, BTreeSet<String>> = schemas
+            .iter()
+            .map(|(name, schema)| {
+                let mut references = BTreeSet::new();
+                Self::collect_references(schema, &mut references);
+                (name.as_str(), references)
+            })
+            .collect();
+        let context = ZodContext {
+            schemas,
+            unions: SchemaAnalyzer::new(openapi).analyze_discriminated_unions(),
+            recursive: schemas
+                .keys()
+                .filter(|name| Self::reaches(&dependencies, name, name))
+                .cloned()
+                .collect(),
+        };
+
+        let data: Vec<ZodSchemaData> = Self::dependency_order(&dependencies)
+            .into_iter()
+            .filter_map(|name| {
+                let schema = schemas.get(name)?;
+                let recursive = context.recursive.contains(name);
+                Some(ZodSchemaData {
+                    name: name.to_pascal_case(),
+                    schema_name: Self::schema_name(name),
+                    expr: self.zod_expr(schema, &context, 0, Some(name)),
+                    type_expr: recursive.then(|| self.type_expr(schema, 0)),
+                })
+            })
+            .collect();
+
+        let content = self
+            .templating
+            .emit_zod_schemas(&serde_json::json!({ "schemas": data }))
+            .map_err(|e| GeneratorError::Generic {
+                message: format!("Failed to generate Zod schemas: {}", e),
+            })?;
+
+        Ok(GeneratedFile {
+            filename: "schemas/index.ts".to_string(),
+            content,
+            file_category: TsFileCategory::Index,
+        })
+    }
+
+    // ============================================================================
+    // ZOD EXPRESSIONS
+    // ============================================================================
+
+    /// Zod expression validating a schema, `component` naming the schema being defined
+    fn zod_expr(
+        &self,
+        schema_ref: &RefOr<Schema>,
+        context: &ZodContext,
+        indent: usize,
+        component: Option<&str>,
+    ) -> String {
+        let schema = match schema_ref {
+            RefOr::Ref(reference) => {
+                return Self::reference_name(&reference.ref_location)
+                    .map(Self::schema_name)
+                    .unwrap_or_else(|| "z.unknown()".to_string());
+            }
+            RefOr::T(schema) => schema,
+        };
+
+        match schema {
+            Schema::Object(object) => self.object_expr(object, context, indent),
+            Schema::Array(array) => {
+                let items = match &array.items {
+                    ArrayItems::RefOrSchema(items) => self.zod_expr(items, context, indent, None),
+                    ArrayItems::False => "z.never()".to_string(),
+                };
+                let mut expr = format!("z.array({})", items);
+                if let Some(min_items) = array.min_items {
+                    expr.push_str(&format!(".min({})", min_items));
+                }
+                if let Some(max_items) = array.max_items {
+                    expr.push_str(&format!(".max({})", max_items));
+                }
+                Self::nullable(expr, &array.schema_type)
+            }
+            Schema::OneOf(one_of) => {
+                let union = component
+                    .and_then(|name| context.unions.iter().find(|u| u.schema_name == name))
+                    .filter(|union| union.is_valid());
+                if let Some(union) = union
+                    && let Some(expr) = self.discriminated_union_expr(union, context)
+                {
+                    return expr;
+                }
+                self.union_expr(&one_of.items, context, indent)
+            }
+            Schema::AnyOf(any_of) => self.union_expr(&any_of.items, context, indent),
+            Schema::AllOf(all_of) => {
+                let mut members = all_of
+                    .items
+                    .iter()
+                    .map(|item| self.zod_expr(item, context, indent, None));
+                let first = members.next().unwrap_or_else(|| "z.unknown()".to_string());
+                members.fold(first, |expr, member| format!("{}.and({})", expr, member))
+            }
+            _ => "z.unknown()".to_string(),
+        }
+    }
+
+    /// Zod expression of an object schema, which may also be a primitive or an enum
+    fn object_expr(&self, object: &Object, context: &ZodContext, indent: usize) -> String {
+        if let Some(values) = &object.enum_values {
+            return Self::enum_expr(values);
+        }
+
+        let types: Vec<&Type> = match &object.schema_type {
+            SchemaType::Type(ty) => vec![ty],
+            SchemaType::Array(types) => types.iter().filter(|ty| **ty != Type::Null).collect(),
+            SchemaType::AnyValue if !object.properties.is_empty() => vec![&Type::Object],
+            SchemaType::AnyValue => return "z.unknown()".to_string(),
+        };
+        let mut exprs: Vec<String> = types
+            .into_iter()
+            .map(|ty| self.typed_expr(ty, object, context, indent))
+            .collect();
+        let expr = match exprs.len() {
+            0 => "z.null()".to_string(),
+            1 => exprs.remove(0),
+            _ => format!("z.union([{}])", exprs.join(", ")),
+        };
+        Self::nullable(expr, &object.schema_type)
+    }
+
+    /// Zod expression of an object schema restricted to one of its types
+    fn typed_expr(
+        &self,
+        ty: &Type,
+        object: &Object,
+        context: &ZodContext,
+        indent: usize,
+    ) -> String {
+        let format = Self::format(object);
+        match ty {
+            Type::String => {
+                let mut expr = match format.as_deref() {
+                    Some("binary") => return "z.instanceof(Blob)".to_string(),
+                    Some("date-time") => "z.string().datetime({ offset: true })".to_string(),
+                    Some("date") => "z.string().date()".to_string(),
+                    Some("email") => "z.string().email()".to_string(),
+                    Some("uuid") => "z.string().uuid()".to_string(),
+                    Some("uri" | "url") => "z.string().url()".to_string(),
+                    Some("byte") => "z.string().base64()".to_string(),
+                    _ => "z.string()".to_string(),
+                };
+                if let Some(min_length) = object.min_length {
+                    expr.push_str(&format!(".min({})", min_length));
+                }
+                if let Some(max_length) = object.max_length {
+                    expr.push_str(&format!(".max({})", max_length));
+                }
+                if let Some(pattern) = &object.pattern {
+                    expr.push_str(&format!(
+                        ".regex(new RegExp({}))",
+                        Self::string_literal(pattern)
+                    ));
+                }
+                expr
+            }
+            Type::Integer | Type::Number => {
+                let mut expr = "z.number()".to_string();
+                if *ty == Type::Integer {
+                    expr.push_str(".int()");
+                }
+                for (method, bound) in [
+                    ("gte", &object.minimum),
+                    ("lte", &object.maximum),
+                    ("gt", &object.exclusive_minimum),
+                    ("lt", &object.exclusive_maximum),
+                    ("multipleOf", &object.multiple_of),
+                ] {
+                    if let Some(bound) = bound {
+                        expr.push_str(&format!(".{}({})", method, Self::number_literal(bound)));
+                    }
+                }
+                // The runtime keeps integers a number cannot represent as strings
+                if format.as_deref() == Some("int64") && self.int64.is_lossless() {
+                    expr = format!("z.union([{}, z.string().regex(/^-?\\d+$/)])", expr);
+                }
+                expr
+            }
+            Type::Boolean => "z.boolean()".to_string(),
+            Type::Null => "z.null()".to_string(),
+            Type::Array => "z.array(z.unknown())".to_string(),
+            Type::Object => self.properties_expr(object, context, indent),
+        }
+    }
+
+    /// Zod expression of the properties of an object schema
+    fn properties_expr(&self, object: &Object, context: &ZodContext, indent: usize) -> String {
+        let additional = match object.additional_properties.as_deref() {
+            Some(AdditionalProperties::RefOr(schema)) => {
+                Some(self.zod_expr(schema, context, indent, None))
+            }
+            Some(AdditionalProperties::FreeForm(true)) => Some("z.unknown()".to_string()),
+            Some(AdditionalProperties::FreeForm(false)) | None => None,
+        };
+        if object.properties.is_empty() {
+            return format!(
+                "z.record(z.string(), {})",
+                additional.as_deref().unwrap_or("z.unknown()")
+            );
+        }
+
+        let padding = "  ".repeat(indent + 1);
+        let mut expr = "z.object({\n".to_string();
+        for (name, property) in &object.properties {
+            let mut value = self.zod_expr(property, context, indent + 1, None);
+            if !Self::is_present(object, name, property) {
+                value.push_str(".optional()");
+            }
+            expr.push_str(&format!(
+                "{}{}: {},\n",
+                padding,
+                Self::property_key(name),
+                value
+            ));
+        }
+        expr.push_str(&format!("{}}})", "  ".repeat(indent)));
+        if let Some(additional) = additional {
+            expr.push_str(&format!(".catchall({})", additional));
+        }
+        expr
+    }
+
+    /// Zod expression of a discriminated union, `None` when its members cannot be extended
+    ///
+    /// Members get a literal discriminator property, as Zod requires one to pick
+    /// the member. Members that are not plain objects are left to a plain union.
+    fn discriminated_union_expr(
+        &self,
+        union: &DiscriminatedUnion,
+        context: &ZodContext,
+    ) -> Option<String> {
+        let mut members: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (value, member) in &union.mapping {
+            members.entry(member).or_default().push(value);
+        }
+
+        let options = members
+            .into_iter()
+            .map(|(member, values)| {
+                let is_object = matches!(
+                    context.schemas.get(member),
+                    Some(RefOr::T(Schema::Object(object)))
+                        if !object.properties.is_empty() && object.enum_values.is_none()
+                );
+                if !is_object || context.recursive.contains(member) {
+                    return None;
+                }
+                let literal = match values.as_slice() {
+                    [value] => format!("z.literal({})", Self::string_literal(value)),
+                    values => format!(
+                        "z.enum([{}])",
+                        values
+                            .iter()
+                            .map(|value| Self::string_literal(value))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                Some(format!(
+                    "{}.extend({{ {}: {} }})",
+                    Self::schema_name(member),
+                    Self::property_key(&union.property_name),
+                    literal
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(format!(
+            "z.discriminatedUnion({}, [{}])",
+            Self::string_literal(&union.property_name),
+            options.join(", ")
+        ))
+    }
+
+    /// Zod expression of a plain union
+    fn union_expr(&self, items: &[RefOr<Schema>], context: &ZodContext, indent: usize) -> String {
+        let mut members: Vec<String> = items
+            .iter()
+            .map(|item| self.zod_expr(item, context, indent, None))
+            .collect();
+        match members.len() {
+            0 => "z.never()".to_string(),
+            1 => members.remove(0),
+            _ => format!("z.union([{}])", members.join(", ")),
+        }
+    }
+
+    /// Zod expression of an enum
+    fn enum_expr(values: &[serde_json::Value]) -> String {
+        let nullable = values.iter().any(serde_json::Value::is_null);
+        let values: Vec<&serde_json::Value> = values.iter().filter(|v| !v.is_null()).collect();
+        let expr = if !values.is_empty() && values.iter().all(|value| value.is_string()) {
+            format!(
+                "z.enum([{}])",
+                values
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        } else {
+            let mut literals: Vec<String> = values
+                .iter()
+                .map(|value| format!("z.literal({})", value))
+                .collect();
+            match literals.len() {
+                0 => "z.never()".to_string(),
+                1 => literals.remove(0),
+                _ => format!("z.union([{}])", literals.join(", ")),
+            }
+        };
+        if nullable {
+            format!("{}.nullable()", expr)
+        } else {
+            expr
+        }
+    }
+
+    /// Make an expression nullable when the schema type includes `null`
+    fn nullable(expr: String, schema_type: &SchemaType) -> String {
+        match schema_type {
+            SchemaType::Array(types) if types.len() > 1 && types.contains(&Type::Null) => {
+                format!("{}.nullable()", expr)
+            }
+            _ => expr,
+        }
+    }
+
+    // ============================================================================
+    // TYPES OF RECURSIVE SCHEMAS
+    // ============================================================================
+
+    /// TypeScript type of the JSON form of a schema, matching what Zod would infer
+    fn type_expr(&self, schema_ref: &RefOr<Schema>, indent: usize) -> String {
+        let schema = match schema_ref {
+            RefOr::Ref(reference) => {
+                return Self::reference_name(&reference.ref_location)
+                    .map(|name| name.to_pascal_case())
+                    .unwrap_or_else(|| "unknown".to_string());
+            }
+            RefOr::T(schema) => schema,
+        };
+
+        match schema {
+            Schema::Object(object) => self.object_type(object, indent),
+            Schema::Array(array) => {
+                let items = match &array.items {
+                    ArrayItems::RefOrSchema(items) => self.type_expr(items, indent),
+                    ArrayItems::False => "never".to_string(),
+                };
+                Self::nullable_type(format!("Array<{}>", items), &array.schema_type)
+            }
+            Schema::OneOf(one_of) => self.members_type(&one_of.items, indent, " | "),
+            Schema::AnyOf(any_of) => self.members_type(&any_of.items, indent, " | "),
+            Schema::AllOf(all_of) => self.members_type(&all_of.items, indent, " & "),
+            _ => "unknown".to_string(),
+        }
+    }
+
+    /// TypeScript type of an object schema, which may also be a primitive or an enum
+    fn object_type(&self, object: &Object, indent: usize) -> String {
+        if let Some(values) = &object.enum_values {
+            return values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(" | ");
+        }
+
+        let types: Vec<&Type> = match &object.schema_type {
+            SchemaType::Type(ty) => vec![ty],
+            SchemaType::Array(types) => types.iter().filter(|ty| **ty != Type::Null).collect(),
+            SchemaType::AnyValue if !object.properties.is_empty() => vec![&Type::Object],
+            SchemaType::AnyValue => return "unknown".to_string(),
+        };
+        let format = Self::format(object);
+        let types: Vec<String> = types
+            .into_iter()
+            .map(|ty| match ty {
+                Type::String if format.as_deref() == Some("binary") => "Blob".to_string(),
+                Type::String => "string".to_string(),
+                Type::Integer if format.as_deref() == Some("int64") && self.int64.is_lossless() => {
+                    "number | string".to_string()
+                }
+                Type::Integer | Type::Number => "number".to_string(),
+                Type::Boolean => "boolean".to_string(),
+                Type::Null => "null".to_string(),
+                Type::Array => "Array<unknown>".to_string(),
+                Type::Object => self.properties_type(object, indent),
+            })
+            .collect();
+        let ty = if types.is_empty() {
+            "null".to_string()
+        } else {
+            types.join(" | ")
+        };
+        Self::nullable_type(ty, &object.schema_type)
+    }
+
+    /// TypeScript type of the properties of an object schema
+    fn properties_type(&self, object: &Object, indent: usize) -> String {
+        let additional = match object.additional_properties.as_deref() {
+            Some(AdditionalProperties::RefOr(schema)) => Some(self.type_expr(schema, indent)),
+            Some(AdditionalProperties::FreeForm(true)) => Some("unknown".to_string()),
+            Some(AdditionalProperties::FreeForm(false)) | None => None,
+        };
+        if object.properties.is_empty() {
+            return format!(
+                "{{ [key: string]: {} }}",
+                additional.as_deref().unwrap_or("unknown")
+            );
+        }
+
+        let padding = "  ".repeat(indent + 1);
+        let mut ty = "{\n".to_string();
+        for (name, property) in &object.properties {
+            let optional = if Self::is_present(object, name, property) {
+                ""
+            } else {
+                "?"
+            };
+            ty.push_str(&format!(
+                "{}{}{}: {};\n",
+                padding,
+                Self::property_key(name),
+                optional,
+                self.type_expr(property, indent + 1)
+            ));
+        }
+        if let Some(additional) = additional {
+            ty.push_str(&format!("{}[key: string]: {};\n", padding, additional));
+        }
+        ty.push_str(&format!("{}}}", "  ".repeat(indent)));
+        ty
+    }
+
+    /// TypeScript type combining the members of a composition
+    fn members_type(&self, items: &[RefOr<Schema>], indent: usize, separator: &str) -> String {
+        if items.is_empty() {
+            return "never".to_string();
+        }
+        items
+            .iter()
+            .map(|item| self.type_expr(item, indent))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// Make a type nullable when the schema type includes `null`
+    fn nullable_type(ty: String, schema_type: &SchemaType) -> String {
+        match schema_type {
+            SchemaType::Array(types) if types.len() > 1 && types.contains(&Type::Null) => {
+                format!("{} | null", ty)
+            }
+            _ => ty,
+        }
+    }
+
+    // ============================================================================
+    // HELPERS
+    // ============================================================================
+
+    /// Whether a property is always present in responses
+    ///
+    /// Write-only properties are never returned, even when required.
+    fn is_present(object: &Object, name: &str, property: &RefOr<Schema>) -> bool {
+        let write_only = matches!(
+            property,
+            RefOr::T(Schema::Object(Object {
+                write_only: Some(true),
+                ..
+            }))
+        );
+        object.required.iter().any(|required| required == name) && !write_only
+    }
+
+    /// OpenAPI format of an object schema
+    fn format(object: &Object) -> Option<String> {
+        object.format.as_ref().and_then(|format| {
+            serde_json::to_value(format)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string))
+        })
+    }
+
+    /// Component name a reference points to
+    fn reference_name(ref_location: &str) -> Option<&str> {
+        ref_location.strip_prefix(SCHEMA_REF_PREFIX)
+    }
+
+    /// Object key, quoted unless it is a valid identifier
+    fn property_key(name: &str) -> String {
+        let mut chars = name.chars();
+        let is_identifier = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+        if is_identifier {
+            name.to_string()
+        } else {
+            Self::string_literal(name)
+        }
+    }
+
+    /// JavaScript string literal
+    fn string_literal(value: &str) -> String {
+        serde_json::Value::String(value.to_string()).to_string()
+    }
+
+    /// JavaScript number literal
+    fn number_literal(number: &utoipa::Number) -> String {
+        serde_json::to_string(number).unwrap_or_else(|_| "0".to_string())
+    }
+
+    // ============================================================================
+    // DEPENDENCIES
+    // ============================================================================
+
+    /// Collect the components a schema refers to
+    fn collect_references(schema_ref: &RefOr<Schema>, references: &mut BTreeSet<String>) {
+        let schema = match schema_ref {
+            RefOr::Ref(reference) => {
+                if let Some(name) = Self::reference_name(&reference.ref_location) {
+                    references.insert(name.to_string());
+                }
+                return;
+            }
+            RefOr::T(schema) => schema,
+        };
+
+        match schema {
+            Schema::Object(object) => {
+                for property in object.properties.values() {
+                    Self::collect_references(property, references);
+                }
+                if let Some(AdditionalProperties::RefOr(schema)) =
+                    object.additional_properties.as_deref()
+                {
+                    Self::collect_references(schema, references);
+                }
+            }
+            Schema::Array(array) => {
+                if let ArrayItems::RefOrSchema(items) = &array.items {
+                    Self::collect_references(items, references);
+                }
+            }
+            Schema::OneOf(one_of) => {
+                for item in &one_of.items {
+                    Self::collect_references(item, references);
+                }
+            }
+            Schema::AnyOf(any_of) => {
+                for item in &any_of.items {
+                    Self::collect_references(item, references);
+                }
+            }
+            Schema::AllOf(all_of) => {
+                for item in &all_of.items {
+                    Self::collect_references(item, references);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether `target` can be reached from the references of `from`
+    fn reaches(dependencies: &BTreeMap<&str, BTreeSet<String>>, from: &str, target: &str) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut pending: Vec<&str> = dependencies
+            .get(from)
+            .map(|references| references.iter().map(String::as_str).collect())
+            .unwrap_or_default();
+        while let Some(name) = pending.pop() {
+            if name == target {
+                return true;
+            }
+            if visited.insert(name)
+                && let Some(references) = dependencies.get(name)
+            {
+                pending.extend(references.iter().map(String::as_str));
+            }
+        }
+        false
+    }
+
+    /// Component names ordered so that schemas come after the ones they refer to
+    ///
+    /// Schemas in a cycle cannot all come first; those are wrapped in `z.lazy`.
+    fn dependency_order<'a>(dependencies: &BTreeMap<&'a str, BTreeSet<String>>) -> Vec<&'a str> {
+        fn visit<'a>(
+            name: &'a str,
+            dependencies: &BTreeMap<&'a str, BTreeSet<String>>,
+            visited: &mut BTreeSet<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) {
+            if !visited.insert(name) {
+                return;
+            }
+            for reference in dependencies.get(name).into_iter().flatten() {
+                if let Some((known, _)) = dependencies.get_key_value(reference.as_str()) {
+                    visit(known, dependencies, visited, order);
+                }
+            }
+            order.push(name);
+        }
+
+        let mut visited = BTreeSet::new();
+        let mut order = Vec::new();
+        for name in dependencies.keys() {
+            visit(name, dependencies, &mut visited, &mut order);
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapi_nexus_ir::DiscriminatorIssue;
+    use serde_json::json;
+    use utoipa::openapi::schema::ObjectBuilder;
+
+    fn generator(int64: Int64Strategy) -> ZodSchemaGenerator {
+        ZodSchemaGenerator::new(100, int64)
+    }
+
+    fn schemas(value: serde_json::Value) -> BTreeMap<String, RefOr<Schema>> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn dependencies(schemas: &BTreeMap<String, RefOr<Schema>>) -> BTreeMap<&str, BTreeSet<String>> {
+        schemas
+            .iter()
+            .map(|(name, schema)| {
+                let mut references = BTreeSet::new();
+                ZodSchemaGenerator::collect_references(schema, &mut references);
+                (name.as_str(), references)
+            })
+            .collect()
+    }
+
+    fn zod_context(
+        schemas: &BTreeMap<String, RefOr<Schema>>,
+        unions: Vec<DiscriminatedUnion>,
+    ) -> ZodContext<'_> {
+        let dependencies = dependencies(schemas);
+        ZodContext {
+            schemas,
+            unions,
+            recursive: schemas
+                .keys()
+                .filter(|name| ZodSchemaGenerator::reaches(&dependencies, name, name))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn expr(schema: serde_json::Value) -> String {
+        built_expr(serde_json::from_value(schema).unwrap())
+    }
+
+    fn built_expr(schema: RefOr<Schema>) -> String {
+        let schemas = BTreeMap::new();
+        generator(Int64Strategy::default()).zod_expr(
+            &schema,
+            &zod_context(&schemas, Vec::new()),
+            0,
+            None,
+        )
+    }
+
+    fn pet_union(mapping: &[(&str, &str)]) -> DiscriminatedUnion {
+        DiscriminatedUnion {
+            schema_name: "Pet".to_string(),
+            property_name: "petType".to_string(),
+            mapping: mapping
+                .iter()
+                .map(|(value, member)| (value.to_string(), member.to_string()))
+                .collect(),
+            issues: Vec::new(),
+        }
+    }
+
+    fn pets(dog: serde_json::Value) -> BTreeMap<String, RefOr<Schema>> {
+        schemas(json!({
+            "Pet": {
+                "oneOf": [
+                    { "$ref": "#/components/schemas/Cat" },
+                    { "$ref": "#/components/schemas/Dog" }
+                ],
+                "discriminator": { "propertyName": "petType" }
+            },
+            "Cat": {
+                "type": "object",
+                "required": ["petType"],
+                "properties": { "petType": { "type": "string" } }
+            },
+            "Dog": dog
+        }))
+    }
+
+    fn dog() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "required": ["petType"],
+            "properties": { "petType": { "type": "string" } }
+        })
+    }
+
+    #[test]
+    fn test_zod_expr_of_primitives() {
+        assert_eq!(
+            expr(json!({ "type": "string", "format": "email", "minLength": 3, "maxLength": 9 })),
+            "z.string().email().min(3).max(9)"
+        );
+        assert_eq!(
+            expr(json!({ "type": "string", "pattern": "^\"a\\d\"$" })),
+            r#"z.string().regex(new RegExp("^\"a\\d\"$"))"#
+        );
+        assert_eq!(
+            expr(json!({ "type": "string", "format": "binary" })),
+            "z.instanceof(Blob)"
+        );
+        assert_eq!(
+            expr(
+                json!({ "type": "integer", "minimum": 1, "exclusiveMaximum": 10, "multipleOf": 2 })
+            ),
+            "z.number().int().gte(1).lt(10).multipleOf(2)"
+        );
+        assert_eq!(
+            expr(json!({ "type": ["number", "null"], "exclusiveMinimum": 0.5 })),
+            "z.number().gt(0.5).nullable()"
+        );
+        assert_eq!(
+            expr(json!({ "type": ["string", "integer"] })),
+            "z.union([z.string(), z.number().int()])"
+        );
+        assert_eq!(expr(json!({ "type": "boolean" })), "z.boolean()");
+        assert_eq!(expr(json!({ "type": "null" })), "z.null()");
+        assert_eq!(
+            built_expr(
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::AnyValue)
+                    .into()
+            ),
+            "z.unknown()"
+        );
+    }
+
+    #[test]
+    fn test_zod_expr_of_int64_follows_the_strategy() {
+        let schemas = BTreeMap::new();
+        let context = zod_context(&schemas, Vec::new());
+        let schema: RefOr<Schema> =
+            serde_json::from_value(json!({ "type": "integer", "format": "int64" })).unwrap();
+        assert_eq!(
+            generator(Int64Strategy::Number).zod_expr(&schema, &context, 0, None),
+            "z.number().int()"
+        );
+        assert_eq!(
+            generator(Int64Strategy::BigInt).zod_expr(&schema, &context, 0, None),
+            r"z.union([z.number().int(), z.string().regex(/^-?\d+$/)])"
+        );
+    }
+
+    #[test]
+    fn test_zod_expr_of_enums() {
+        assert_eq!(
+            expr(json!({ "type": "string", "enum": ["a", "b"] })),
+            r#"z.enum(["a", "b"])"#
+        );
+        assert_eq!(
+            expr(json!({ "type": ["string", "null"], "enum": ["a", null] })),
+            r#"z.enum(["a"]).nullable()"#
+        );
+        assert_eq!(
+            expr(json!({ "type": "integer", "enum": [1, 2] })),
+            "z.union([z.literal(1), z.literal(2)])"
+        );
+        assert_eq!(
+            built_expr(ObjectBuilder::new().enum_values(Some([true])).into()),
+            "z.literal(true)"
+        );
+    }
+
+    #[test]
+    fn test_zod_expr_of_objects() {
+        assert_eq!(
+            expr(json!({
+                "type": "object",
+                "required": ["id", "secret"],
+                "properties": {
+                    "id": { "type": "integer" },
+                    "secret": { "type": "string", "writeOnly": true },
+                    "display-name": { "type": "string" },
+                    "owner": { "$ref": "#/components/schemas/User" }
+                },
+                "additionalProperties": { "type": "string" }
+            })),
+            "z.object({\n  \"display-name\": z.string().optional(),\n  id: z.number().int(),\n  \
+             owner: UserSchema.optional(),\n  secret: z.string().optional(),\n})\
+             .catchall(z.string())"
+        );
+        assert_eq!(
+            expr(json!({ "type": "object", "additionalProperties": { "type": "integer" } })),
+            "z.record(z.string(), z.number().int())"
+        );
+        assert_eq!(
+            expr(json!({ "type": "object" })),
+            "z.record(z.string(), z.unknown())"
+        );
+        assert_eq!(
+            expr(json!({ "$ref": "#/components/schemas/user_profile" })),
+            "UserProfileSchema"
+        );
+        assert_eq!(expr(json!({ "$ref": "other.yaml#/Pet" })), "z.unknown()");
+    }
+
+    #[test]
+    fn test_zod_expr_of_arrays_and_compositions() {
+        assert_eq!(
+            expr(json!({
+                "type": ["array", "null"],
+                "items": { "type": "string" },
+                "minItems": 1,
+                "maxItems": 3
+            })),
+            "z.array(z.string()).min(1).max(3).nullable()"
+        );
+        assert_eq!(
+            expr(json!({
+                "anyOf": [{ "type": "string" }, { "$ref": "#/components/schemas/Pet" }]
+            })),
+            "z.union([z.string(), PetSchema])"
+        );
+        assert_eq!(
+            expr(json!({ "oneOf": [{ "type": "string" }] })),
+            "z.string()"
+        );
+        assert_eq!(
+            expr(json!({
+                "allOf": [
+                    { "$ref": "#/components/schemas/Base" },
+                    { "type": "object", "properties": { "extra": { "type": "boolean" } } }
+                ]
+            })),
+            "BaseSchema.and(z.object({\n  extra: z.boolean().optional(),\n}))"
+        );
+    }
+
+    #[test]
+    fn test_discriminated_union_expr() {
+        let schemas = pets(dog());
+        let union = pet_union(&[("cat", "Cat"), ("dog", "Dog")]);
+        let context = zod_context(&schemas, vec![union.clone()]);
+        let generator = generator(Int64Strategy::default());
+
+        let expected = "z.discriminatedUnion(\"petType\", [\
+                        CatSchema.extend({ petType: z.literal(\"cat\") }), \
+                        DogSchema.extend({ petType: z.literal(\"dog\") })])";
+        assert_eq!(
+            generator
+                .discriminated_union_expr(&union, &context)
+                .as_deref(),
+            Some(expected)
+        );
+        assert_eq!(
+            generator.zod_expr(&schemas["Pet"], &context, 0, Some("Pet")),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_discriminated_union_expr_with_several_values_per_member() {
+        let schemas = pets(dog());
+        let union = pet_union(&[("cat", "Cat"), ("dog", "Dog"), ("puppy", "Dog")]);
+        let context = zod_context(&schemas, vec![union.clone()]);
+        assert_eq!(
+            generator(Int64Strategy::default())
+                .discriminated_union_expr(&union, &context)
+                .as_deref(),
+            Some(
+                "z.discriminatedUnion(\"petType\", [\
+                 CatSchema.extend({ petType: z.literal(\"cat\") }), \
+                 DogSchema.extend({ petType: z.enum([\"dog\", \"puppy\"]) })])"
+            )
+        );
+    }
+
+    #[test]
+    fn test_discriminated_union_falls_back_to_plain_union() {
+        let generator = generator(Int64Strategy::default());
+        let union = pet_union(&[("cat", "Cat"), ("dog", "Dog")]);
+        let plain = "z.union([CatSchema, DogSchema])";
+
+        // A member that is not a plain object cannot be extended
+        let schemas = pets(json!({ "allOf": [{ "$ref": "#/components/schemas/Cat" }] }));
+        let context = zod_context(&schemas, vec![union.clone()]);
+        assert_eq!(generator.discriminated_union_expr(&union, &context), None);
+        assert_eq!(
+            generator.zod_expr(&schemas["Pet"], &context, 0, Some("Pet")),
+            plain
+        );
+
+        // Nor can one referring back to the union, which is defined lazily
+        let schemas = pets(json!({
+            "type": "object",
+            "required": ["petType"],
+            "properties": {
+                "petType": { "type": "string" },
+                "friends": { "type": "array", "items": { "$ref": "#/components/schemas/Pet" } }
+            }
+        }));
+        let context = zod_context(&schemas, vec![union.clone()]);
+        assert_eq!(generator.discriminated_union_expr(&union, &context), None);
+        assert_eq!(
+            generator.zod_expr(&schemas["Pet"], &context, 0, Some("Pet")),
+            plain
+        );
+
+        // Invalid unions are never discriminated
+        let schemas = pets(dog());
+        let mut invalid = union.clone();
+        invalid
+            .issues
+            .push(DiscriminatorIssue::UnmappedMember { index: 1 });
+        let context = zod_context(&schemas, vec![invalid]);
+        assert_eq!(
+            generator.zod_expr(&schemas["Pet"], &context, 0, Some("Pet")),
+            plain
+        );
+    }
+
+    #[test]
+    fn test_dependency_order_puts_references_first() {
+        let schemas = schemas(json!({
+            "A": { "$ref": "#/components/schemas/B" },
+            "B": { "type": "array", "items": { "$ref": "#/components/schemas/C" } },
+            "C": {
+                "type": "object",
+                "properties": { "d": { "$ref": "#/components/schemas/D" } },
+                "additionalProperties": { "$ref": "#/components/schemas/Missing" }
+            },
+            "D": { "type": "string" }
+        }));
+        assert_eq!(
+            ZodSchemaGenerator::dependency_order(&dependencies(&schemas)),
+            vec!["D", "C", "B", "A"]
+        );
+    }
+
+    #[test]
+    fn test_dependency_order_with_cycles() {
+        let schemas = schemas(json!({
+            "Node": {
+                "type": "object",
+                "properties": {
+                    "children": { "type": "array", "items": { "$ref": "#/components/schemas/Node" } }
+                }
+            },
+            "Parent": {
+                "type": "object",
+                "properties": { "child": { "$ref": "#/components/schemas/Child" } }
+            },
+            "Child": {
+                "type": "object",
+                "properties": {
+                    "parent": { "$ref": "#/components/schemas/Parent" },
+                    "tag": { "$ref": "#/components/schemas/Tag" }
+                }
+            },
+            "Tag": { "type": "string" }
+        }));
+        let dependencies = dependencies(&schemas);
+
+        let order = ZodSchemaGenerator::dependency_order(&dependencies);
+        // The cycle is entered at `Child`, so `Parent` comes first and refers to it lazily
+        assert_eq!(order, vec!["Parent", "Tag", "Child", "Node"]);
+
+        let recursive: Vec<&str> = schemas
+            .keys()
+            .map(String::as_str)
+            .filter(|name| ZodSchemaGenerator::reaches(&dependencies, name, name))
+            .collect();
+        assert_eq!(recursive, vec!["Child", "Node", "Parent"]);
+    }
+}
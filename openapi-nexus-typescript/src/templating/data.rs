@@ -2,4 +2,4 @@
 
 pub mod runtime_data;
 
-pub use runtime_data::RuntimeData;
+pub use runtime_data::{RuntimeData, SecuritySchemeData};
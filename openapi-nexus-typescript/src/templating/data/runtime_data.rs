@@ -1,7 +1,12 @@
 //! Runtime data structures for template generation
 
+use openapi_nexus_ir::Analyzer;
 use serde::Serialize;
+use tracing::warn;
 use utoipa::openapi::OpenApi;
+use utoipa::openapi::security::{ApiKey, HttpAuthScheme, SecurityScheme};
+
+use crate::ast::TsServer;
 
 /// Data structure for runtime template generation
 #[derive(Clone, Serialize)]
@@ -9,27 +14,100 @@ pub struct RuntimeData {
     pub base_path: String,
     pub package_name: String,
     pub openapi_info: utoipa::openapi::Info,
+    /// Parse and serialize JSON without losing precision on large integers
+    pub lossless_int64: bool,
+    /// Let generated methods check responses against their schemas
+    pub validate_responses: bool,
+    /// Security schemes generated methods can apply
+    pub security_schemes: Vec<SecuritySchemeData>,
+    /// Servers of the API, the first one providing the base path
+    pub servers: Vec<TsServer>,
+}
+
+/// Security scheme as applied by the runtime
+#[derive(Debug, Clone, Serialize)]
+pub struct SecuritySchemeData {
+    pub name: String,
+    /// `apiKey`, `basic` or `bearer`; OAuth2 and OpenID Connect send bearer tokens
+    pub kind: &'static str,
+    /// Where an API key goes: `header`, `query` or `cookie`
+    pub location: Option<&'static str>,
+    /// Name of the API key header, query parameter or cookie
+    pub key_name: Option<String>,
+}
+
+impl SecuritySchemeData {
+    /// Security schemes of a specification that the runtime supports
+    ///
+    /// Schemes the runtime cannot apply, such as mutual TLS or HTTP schemes
+    /// other than basic and bearer, are left out with a warning.
+    pub fn from_openapi(openapi: &OpenApi) -> Vec<Self> {
+        Analyzer::get_all_security_schemes(openapi)
+            .into_iter()
+            .filter_map(|(name, scheme)| {
+                let data = Self::from_scheme(name, scheme);
+                if data.is_none() {
+                    warn!("Security scheme '{}' is not supported and is ignored", name);
+                }
+                data
+            })
+            .collect()
+    }
+
+    /// Runtime form of a security scheme, `None` when it is not supported
+    pub fn from_scheme(name: &str, scheme: &SecurityScheme) -> Option<Self> {
+        let (kind, location, key_name) = match scheme {
+            SecurityScheme::ApiKey(api_key) => {
+                let (location, value) = match api_key {
+                    ApiKey::Header(value) => ("header", value),
+                    ApiKey::Query(value) => ("query", value),
+                    ApiKey::Cookie(value) => ("cookie", value),
+                };
+                ("apiKey", Some(location), Some(value.name.clone()))
+            }
+            SecurityScheme::Http(http) => match http.scheme {
+                HttpAuthScheme::Basic => ("basic", None, None),
+                HttpAuthScheme::Bearer => ("bearer", None, None),
+                _ => return None,
+            },
+            SecurityScheme::OAuth2(_) | SecurityScheme::OpenIdConnect(_) => ("bearer", None, None),
+            _ => return None,
+        };
+        Some(Self {
+            name: name.to_string(),
+            kind,
+            location,
+            key_name,
+        })
+    }
 }
 
 impl RuntimeData {
     /// Create runtime data from OpenAPI specification
     pub fn from_openapi(openapi: &OpenApi) -> Self {
-        let base_path = Self::extract_base_path(openapi);
+        let servers: Vec<TsServer> = openapi
+            .servers
+            .iter()
+            .flatten()
+            .map(TsServer::from)
+            .collect();
 
         Self {
-            base_path,
+            base_path: Self::extract_base_path(&servers),
             package_name: "generated-api".to_string(), // Default, can be overridden by config
             openapi_info: openapi.info.clone(),
+            lossless_int64: false,
+            validate_responses: false,
+            security_schemes: SecuritySchemeData::from_openapi(openapi),
+            servers,
         }
     }
 
-    /// Extract base path from OpenAPI servers
-    fn extract_base_path(openapi: &OpenApi) -> String {
-        if let Some(servers) = &openapi.servers
-            && let Some(first_server) = servers.first()
-        {
-            return first_server.url.clone();
-        }
-        "http://localhost".to_string()
+    /// Extract base path from the first server, with its variables at their defaults
+    fn extract_base_path(servers: &[TsServer]) -> String {
+        servers
+            .first()
+            .map(TsServer::default_url)
+            .unwrap_or_else(|| "http://localhost".to_string())
     }
 }
@@ -21,7 +21,8 @@ pub use format_interface_signature::{
     create_format_interface_signature_filter, format_interface_signature_filter,
 };
 pub use format_method_signature::{
-    create_format_method_signature_filter, create_format_method_signature_iface_filter,
+    create_format_function_signature_filter, create_format_method_signature_filter,
+    create_format_method_signature_iface_filter, format_function_signature_filter,
     format_method_signature_filter, format_method_signature_iface_filter,
 };
 pub use format_ts_class_property::{
@@ -28,6 +28,37 @@ pub fn create_format_method_signature_filter(
     move |method, indent_level| format_method_signature_filter(method, indent_level, max_line_width)
 }
 
+/// Template filter for formatting a method as the signature of a standalone function
+pub fn format_function_signature_filter(
+    method: ViaDeserialize<TsClassMethod>,
+    indent_level: Option<usize>,
+    max_line_width: usize,
+) -> String {
+    let ctx = EmissionContext {
+        indent: indent_level.unwrap_or(0),
+        max_line_width,
+    };
+    let mut m = method.0.clone();
+    m.name = if m.is_generator {
+        format!("function* {}", m.name)
+    } else {
+        format!("function {}", m.name)
+    };
+    m.is_generator = false;
+    m.to_rcdoc_with_context(&ctx)
+        .map(|doc| doc.pretty(max_line_width).to_string())
+        .unwrap_or_else(|_| "/* invalid function */".to_string())
+}
+
+/// Create a format_function_signature filter with the given max_line_width
+pub fn create_format_function_signature_filter(
+    max_line_width: usize,
+) -> impl Fn(ViaDeserialize<TsClassMethod>, Option<usize>) -> String + Send + Sync + 'static {
+    move |method, indent_level| {
+        format_function_signature_filter(method, indent_level, max_line_width)
+    }
+}
+
 /// Template filter for formatting interface method signature (no async keyword)
 pub fn format_method_signature_iface_filter(
     method: ViaDeserialize<TsClassMethod>,
@@ -40,6 +71,7 @@ pub fn format_method_signature_iface_filter(
     };
     let mut m = method.0.clone();
     m.is_async = false;
+    m.is_generator = false;
     m.to_rcdoc_with_context(&ctx)
         .map(|doc| doc.pretty(max_line_width).to_string())
         .unwrap_or_else(|_| "/* invalid method */".to_string())
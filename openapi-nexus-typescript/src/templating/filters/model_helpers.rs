@@ -10,20 +10,129 @@ pub fn instance_guard_filter(prop: &str, indent: usize) -> String {
     )
 }
 
-/// Filter: from_json_line_filter(value: &str, indent: usize, optional: bool) -> String
+/// Filter: from_json_line_filter(value: &str, indent: usize, optional: bool, conversion: Option<String>, nullable: bool) -> String
 /// Builds a FromJSONTyped mapping line for a property.
-pub fn from_json_line_filter(prop: &str, indent: usize, optional: bool) -> String {
+///
+/// `conversion` is `date`, `date-time`, `bigint` or `int64-string`, suffixed
+/// with `[]` for arrays, when the JSON value has to be converted. `date`
+/// values are read as local calendar dates. A `null` value of an optional
+/// property becomes `undefined` unless the property is nullable.
+pub fn from_json_line_filter(
+    prop: &str,
+    indent: usize,
+    optional: bool,
+    conversion: Option<String>,
+    nullable: bool,
+) -> String {
     let indent_str = "  ".repeat(indent);
-    if optional {
-        format!("{}'{}': json['{}'] ?? undefined,", indent_str, prop, prop)
+    let to_undefined = optional && !nullable;
+    let Some(conversion) = conversion else {
+        return if to_undefined {
+            format!("{}'{}': json['{}'] ?? undefined,", indent_str, prop, prop)
+        } else {
+            format!("{}'{}': json['{}'],", indent_str, prop, prop)
+        };
+    };
+
+    let convert = |item: &str| match conversion.trim_end_matches("[]") {
+        "bigint" => format!("BigInt({})", item),
+        "int64-string" => format!("String({})", item),
+        "date" => format!("parseDate({})", item),
+        _ => format!("new Date({})", item),
+    };
+    let converted = if conversion.ends_with("[]") {
+        format!(
+            "(json['{}'] as any[]).map((item) => {})",
+            prop,
+            convert("item")
+        )
     } else {
-        format!("{}'{}': json['{}'],", indent_str, prop, prop)
-    }
+        convert(&format!("json['{}']", prop))
+    };
+    let missing = if to_undefined {
+        "undefined".to_string()
+    } else {
+        format!("json['{}']", prop)
+    };
+    format!(
+        "{}'{}': json['{}'] == null ? {} : {},",
+        indent_str, prop, prop, missing, converted
+    )
 }
 
-/// Filter: to_json_line_filter(value: &str, indent: usize) -> String
+/// Filter: to_json_line_filter(value: &str, indent: usize, conversion: Option<String>) -> String
 /// Builds a ToJSONTyped mapping line for a property.
-pub fn to_json_line_filter(prop: &str, indent: usize) -> String {
+///
+/// `Date` values are serialized as ISO 8601 strings, `date` ones as their
+/// local calendar date.
+/// int64 strings become `bigint`, which the runtime writes as plain integers.
+pub fn to_json_line_filter(prop: &str, indent: usize, conversion: Option<String>) -> String {
     let indent_str = "  ".repeat(indent);
-    format!("{}'{}': value['{}'],", indent_str, prop, prop)
+    let convert = |item: &str| match conversion.as_deref()?.trim_end_matches("[]") {
+        "date" => Some(format!("formatDate({})", item)),
+        "date-time" => Some(format!("{}.toISOString()", item)),
+        "int64-string" => Some(format!("BigInt({})", item)),
+        _ => None,
+    };
+    let is_array = conversion
+        .as_deref()
+        .is_some_and(|conversion| conversion.ends_with("[]"));
+    let converted = if is_array {
+        convert("item").map(|item| format!("value['{}'].map((item) => {})", prop, item))
+    } else {
+        convert(&format!("value['{}']", prop))
+    };
+
+    match converted {
+        Some(converted) => format!(
+            "{}'{}': value['{}'] == null ? value['{}'] : {},",
+            indent_str, prop, prop, prop, converted
+        ),
+        None => format!("{}'{}': value['{}'],", indent_str, prop, prop),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json_keeps_null_of_nullable_properties() {
+        assert_eq!(
+            from_json_line_filter("name", 0, true, None, false),
+            "'name': json['name'] ?? undefined,"
+        );
+        assert_eq!(
+            from_json_line_filter("name", 0, true, None, true),
+            "'name': json['name'],"
+        );
+        assert_eq!(
+            from_json_line_filter("endsAt", 0, true, Some("date-time".to_string()), false),
+            "'endsAt': json['endsAt'] == null ? undefined : new Date(json['endsAt']),"
+        );
+        assert_eq!(
+            from_json_line_filter("endsAt", 0, true, Some("date-time".to_string()), true),
+            "'endsAt': json['endsAt'] == null ? json['endsAt'] : new Date(json['endsAt']),"
+        );
+    }
+
+    #[test]
+    fn test_dates_use_local_calendar_dates() {
+        assert_eq!(
+            from_json_line_filter("day", 0, false, Some("date".to_string()), false),
+            "'day': json['day'] == null ? json['day'] : parseDate(json['day']),"
+        );
+        assert_eq!(
+            from_json_line_filter("days", 0, true, Some("date[]".to_string()), true),
+            "'days': json['days'] == null ? json['days'] : (json['days'] as any[]).map((item) => parseDate(item)),"
+        );
+        assert_eq!(
+            to_json_line_filter("day", 0, Some("date".to_string())),
+            "'day': value['day'] == null ? value['day'] : formatDate(value['day']),"
+        );
+        assert_eq!(
+            to_json_line_filter("days", 0, Some("date[]".to_string())),
+            "'days': value['days'] == null ? value['days'] : value['days'].map((item) => formatDate(item)),"
+        );
+    }
 }
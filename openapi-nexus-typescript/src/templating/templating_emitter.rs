@@ -10,15 +10,17 @@ use utoipa::openapi::OpenApi;
 use super::data::RuntimeData;
 use super::filters::{
     create_format_class_signature_filter, create_format_doc_comment_filter,
-    create_format_generic_list_filter, create_format_import_filter,
-    create_format_interface_signature_filter, create_format_method_signature_filter,
-    create_format_method_signature_iface_filter, create_format_ts_class_property_filter,
-    create_format_ts_property_filter, create_format_type_expr_filter, from_json_line_filter,
-    instance_guard_filter, to_json_line_filter,
+    create_format_function_signature_filter, create_format_generic_list_filter,
+    create_format_import_filter, create_format_interface_signature_filter,
+    create_format_method_signature_filter, create_format_method_signature_iface_filter,
+    create_format_ts_class_property_filter, create_format_ts_property_filter,
+    create_format_type_expr_filter, from_json_line_filter, instance_guard_filter,
+    to_json_line_filter,
 };
 use super::functions::{do_not_edit, file_header};
 use crate::ast::{
     TsClassDefinition, TsExpression, TsInterfaceDefinition, TsInterfaceSignature, TsProperty,
+    TsValidation,
 };
 use crate::emission::error::EmitError;
 use openapi_nexus_core::traits::EmissionContext;
@@ -78,6 +80,8 @@ impl TemplatingEmitter {
                     type_expr: func_type,
                     optional: false,
                     documentation: m.documentation,
+                    format: None,
+                    validation: TsValidation::default(),
                 }
             })
             .collect();
@@ -88,6 +92,7 @@ impl TemplatingEmitter {
             class => class,
             imports => class.imports.clone(),
             api_interface => api_interface,
+            receiver => "this",
         };
 
         // Get the API class template and render directly
@@ -105,6 +110,28 @@ impl TemplatingEmitter {
             })
     }
 
+    /// Emit TypeScript code exporting the methods of a class definition as
+    /// standalone functions, which take the client as their first parameter
+    pub fn emit_functions(&self, class: &TsClassDefinition) -> Result<String, EmitError> {
+        let template_data = context! {
+            class => class,
+            imports => class.imports.clone(),
+            receiver => "client",
+        };
+
+        let template = self.env.get_template("api/api_functions.j2").map_err(|e| {
+            EmitError::TemplateError {
+                message: format!("Failed to get api/api_functions.j2 template: {}", e),
+            }
+        })?;
+
+        template
+            .render(template_data)
+            .map_err(|e| EmitError::TemplateError {
+                message: format!("Failed to render template: {}", e),
+            })
+    }
+
     /// Emit runtime TypeScript code from OpenAPI specification
     pub fn emit_runtime_file(&self, openapi: &OpenApi) -> Result<String, EmitError> {
         let runtime_data = RuntimeData::from_openapi(openapi);
@@ -143,6 +170,48 @@ impl TemplatingEmitter {
         })
     }
 
+    /// Emit discriminated union helper functions (instanceOf/FromJSON/ToJSON)
+    pub fn emit_union_helpers(&self, data: &serde_json::Value) -> Result<String, EmitError> {
+        let template = self
+            .env
+            .get_template("models/union_helpers.j2")
+            .map_err(|e| EmitError::TemplateError {
+                message: format!("Failed to get models/union_helpers.j2 template: {}", e),
+            })?;
+
+        template.render(data).map_err(|e| EmitError::TemplateError {
+            message: format!("Failed to render union helpers template: {}", e),
+        })
+    }
+
+    /// Emit the module holding the Zod schemas of the components
+    pub fn emit_zod_schemas(&self, data: &serde_json::Value) -> Result<String, EmitError> {
+        let template =
+            self.env
+                .get_template("schemas/zod.j2")
+                .map_err(|e| EmitError::TemplateError {
+                    message: format!("Failed to get schemas/zod.j2 template: {}", e),
+                })?;
+
+        template.render(data).map_err(|e| EmitError::TemplateError {
+            message: format!("Failed to render Zod schemas template: {}", e),
+        })
+    }
+
+    /// Emit the TanStack Query hooks module
+    pub fn emit_queries(&self, data: &serde_json::Value) -> Result<String, EmitError> {
+        let template =
+            self.env
+                .get_template("queries/queries.j2")
+                .map_err(|e| EmitError::TemplateError {
+                    message: format!("Failed to get queries/queries.j2 template: {}", e),
+                })?;
+
+        template.render(data).map_err(|e| EmitError::TemplateError {
+            message: format!("Failed to render TanStack Query hooks template: {}", e),
+        })
+    }
+
     /// Emit file header with optional OpenAPI metadata
     pub fn emit_file_header(
         &self,
@@ -188,6 +257,7 @@ impl TemplatingEmitter {
         add_mlw_filters!(env, max_line_width, {
             "format_class_signature" => create_format_class_signature_filter,
             "format_doc_comment" => create_format_doc_comment_filter,
+            "format_function_signature" => create_format_function_signature_filter,
             "format_generic_list" => create_format_generic_list_filter,
             "format_import" => create_format_import_filter,
             "format_interface_signature" => create_format_interface_signature_filter,
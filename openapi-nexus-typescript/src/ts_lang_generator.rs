@@ -1,22 +1,28 @@
 //! Main TypeScript code generator
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 
+use heck::ToPascalCase as _;
 use tracing::warn;
-use utoipa::openapi::OpenApi;
 use utoipa::openapi::path::Operation;
+use utoipa::openapi::{OpenApi, RefOr, Schema};
 
-use crate::config::GeneratorConfig;
+use crate::ast::{TsExpression, TsNode, TsPrimitive, TsTypeDefinition};
+use crate::config::{ClientStyle, GeneratorConfig};
 use crate::core::GeneratorError;
 use crate::generator::api_class_generator::ApiClassGenerator;
 use crate::generator::file_generator::TypeScriptFileGenerator;
+use crate::generator::query_generator::QueryHooksGenerator;
 use crate::generator::runtime_generator::RuntimeGenerator;
 use crate::generator::schema_context::SchemaContext;
 use crate::generator::schema_generator::SchemaGenerator;
+use crate::generator::zod_generator::ZodSchemaGenerator;
+use crate::templating::data::SecuritySchemeData;
 use openapi_nexus_core::generator_registry::LanguageGenerator;
 use openapi_nexus_core::traits::code_generator::LanguageCodeGenerator;
 use openapi_nexus_core::traits::file_writer::{FileCategory, FileInfo, FileWriter};
+use openapi_nexus_ir::{Analyzer, SchemaAnalyzer};
 
 /// Main TypeScript code generator
 #[derive(Debug, Clone)]
@@ -25,21 +31,63 @@ pub struct TsLangGenerator {
     api_class_generator: ApiClassGenerator,
     runtime_generator: RuntimeGenerator,
     file_generator: TypeScriptFileGenerator,
+    /// Generator of the Zod schemas, when they are emitted
+    zod_schema_generator: Option<ZodSchemaGenerator>,
+    /// Generator of the TanStack Query hooks, when they are emitted
+    query_hooks_generator: Option<QueryHooksGenerator>,
+    /// Whether operations are methods of classes or standalone functions
+    client_style: ClientStyle,
 }
 
 impl TsLangGenerator {
     /// Create a new TypeScript generator
     pub fn new(config: GeneratorConfig) -> Self {
         let max_line_width = config.file_config.max_line_width;
+        let zod_schemas = config.emission_config.zod_schemas;
+        let mut package_config = config.package_config.clone();
+        if zod_schemas {
+            package_config
+                .dependencies
+                .insert("zod".to_string(), "^3.23.8".to_string());
+        }
+        // Operation modules only declare functions, so unused ones can be dropped
+        if config.emission_config.client_style == ClientStyle::Functions {
+            package_config.side_effects = false;
+        }
+        let tanstack_query = config.emission_config.tanstack_query;
+        if tanstack_query {
+            // The hooks must share the application's React Query client
+            package_config
+                .peer_dependencies
+                .insert("@tanstack/react-query".to_string(), "^5.0.0".to_string());
+            package_config
+                .peer_dependencies
+                .insert("react".to_string(), "^18.0.0 || ^19.0.0".to_string());
+            package_config.exports.insert(
+                "./queries".to_string(),
+                "./src/queries/index.ts".to_string(),
+            );
+        }
 
         Self {
-            schema_generator: SchemaGenerator,
-            api_class_generator: ApiClassGenerator::new(max_line_width),
-            runtime_generator: RuntimeGenerator::new(max_line_width),
+            schema_generator: SchemaGenerator::new(config.type_mapping_config.clone()),
+            api_class_generator: ApiClassGenerator::new(max_line_width)
+                .with_type_mapping(config.type_mapping_config.clone())
+                .with_response_validation(zod_schemas)
+                .with_client_style(config.emission_config.client_style),
+            runtime_generator: RuntimeGenerator::new(max_line_width)
+                .with_type_mapping(config.type_mapping_config.clone())
+                .with_response_validation(zod_schemas),
             file_generator: TypeScriptFileGenerator::new(
                 config.file_config.clone(),
-                config.package_config.clone(),
+                package_config,
             ),
+            zod_schema_generator: zod_schemas
+                .then(|| ZodSchemaGenerator::new(max_line_width, config.type_mapping_config.int64)),
+            query_hooks_generator: tanstack_query.then(|| {
+                QueryHooksGenerator::new(max_line_width, config.emission_config.client_style)
+            }),
+            client_style: config.emission_config.client_style,
         }
     }
 
@@ -55,11 +103,17 @@ impl TsLangGenerator {
             openapi,
         );
 
+        // Models serializing request bodies, keyed by component schema name
+        let mut request_models = BTreeMap::new();
+
         // Generate interfaces and types from schemas
         if let Some(components) = &openapi.components {
             // Create schema context for reference resolution
             let mut visited = HashSet::new();
-            let mut context = SchemaContext::new(&components.schemas, &mut visited);
+            let mut context = SchemaContext::new(&components.schemas, &mut visited)
+                .with_discriminated_unions(
+                    SchemaAnalyzer::new(openapi).analyze_discriminated_unions(),
+                );
 
             for (name, schema_ref) in &components.schemas {
                 match self
@@ -67,6 +121,17 @@ impl TsLangGenerator {
                     .schema_to_ts_node(name, schema_ref, &mut context)
                 {
                     Ok(node) => {
+                        if Self::has_json_helpers(&node) {
+                            request_models.insert(name.clone(), self.to_pascal_case(name));
+                        }
+                        self.insert_input_variant(
+                            name,
+                            schema_ref,
+                            &node,
+                            &mut context,
+                            &mut schemas,
+                            &mut request_models,
+                        );
                         schemas.insert(name.clone(), node);
                     }
                     Err(e) => {
@@ -75,17 +140,65 @@ impl TsLangGenerator {
                 }
             }
         }
+        Self::link_validated_models(&mut schemas);
+
+        let api_class_generator = self
+            .api_class_generator
+            .clone()
+            .with_request_models(request_models)
+            .with_security(
+                Analyzer::get_all_security_schemes(openapi)
+                    .into_iter()
+                    .filter_map(|(name, scheme)| SecuritySchemeData::from_scheme(name, scheme))
+                    .map(|scheme| scheme.name)
+                    .collect(),
+                openapi.security.clone().unwrap_or_default(),
+            );
 
         // Generate API classes per tag
         let tag_operations = self.collect_operations_by_tag(openapi);
-
-        // Generate API class for each tag
-        for (tag, operations) in tag_operations {
-            let api_class = self
-                .api_class_generator
-                .generate_api_class(&tag, &operations)?;
-            let class_name = format!("{}Api", self.to_pascal_case(&tag));
-            schemas.insert(class_name, api_class);
+        let sorted_tag_operations = self.query_hooks_generator.is_some().then(|| {
+            tag_operations
+                .clone()
+                .into_iter()
+                .collect::<BTreeMap<_, _>>()
+        });
+        let no_schemas = BTreeMap::new();
+        let component_schemas = openapi
+            .components
+            .as_ref()
+            .map_or(&no_schemas, |components| &components.schemas);
+
+        match self.client_style {
+            // Generate API class for each tag
+            ClientStyle::Classes => {
+                for (tag, operations) in tag_operations {
+                    let api_class = api_class_generator.generate_api_class(
+                        &tag,
+                        &operations,
+                        component_schemas,
+                    )?;
+                    let class_name = format!("{}Api", self.to_pascal_case(&tag));
+                    schemas.insert(class_name, api_class);
+                }
+            }
+            // Generate the functions of each operation once, whatever its tags
+            ClientStyle::Functions => {
+                let operations: BTreeMap<(String, String), Operation> = tag_operations
+                    .into_values()
+                    .flatten()
+                    .map(|(path, method, operation)| ((path, method), operation))
+                    .collect();
+                let operations: Vec<(String, String, Operation)> = operations
+                    .into_iter()
+                    .map(|((path, method), operation)| (path, method, operation))
+                    .collect();
+                for (name, functions) in
+                    api_class_generator.generate_api_functions(&operations, component_schemas)?
+                {
+                    schemas.insert(name, functions);
+                }
+            }
         }
 
         // Generate files using file generator with metadata
@@ -105,6 +218,40 @@ impl TsLangGenerator {
             file_infos.push(file_info);
         }
 
+        // Generate the Zod schemas of the components
+        if let Some(zod_schema_generator) = &self.zod_schema_generator {
+            let file = zod_schema_generator.generate_schemas_file(openapi)?;
+            file_infos.push(FileInfo::new(
+                file.filename,
+                file.content,
+                FileCategory::from(file.file_category),
+            ));
+        }
+
+        // Generate the TanStack Query hooks of the operations
+        if let (Some(query_hooks_generator), Some(tag_operations)) =
+            (&self.query_hooks_generator, &sorted_tag_operations)
+        {
+            // Models the hooks may import, named as exported by `models`
+            let models: HashSet<String> = schemas
+                .iter()
+                .filter(|(name, node)| {
+                    !name.ends_with("Api") && !matches!(node, TsNode::Functions(_))
+                })
+                .map(|(name, _)| self.to_pascal_case(name))
+                .collect();
+            let file = query_hooks_generator.generate_queries_file(
+                &api_class_generator,
+                tag_operations,
+                &models,
+            )?;
+            file_infos.push(FileInfo::new(
+                file.filename,
+                file.content,
+                FileCategory::from(file.file_category),
+            ));
+        }
+
         // Generate runtime files
         let runtime_files = self.runtime_generator.generate_runtime_files(openapi)?; // Re-enabled with OpenAPI context
         for file in runtime_files {
@@ -120,6 +267,96 @@ impl TsLangGenerator {
         Ok(file_infos)
     }
 
+    /// Add the request-side variant of a component schema, if it differs
+    fn insert_input_variant(
+        &self,
+        name: &str,
+        schema_ref: &RefOr<Schema>,
+        node: &TsNode,
+        context: &mut SchemaContext,
+        schemas: &mut HashMap<String, TsNode>,
+        request_models: &mut BTreeMap<String, String>,
+    ) {
+        let input_node = match self
+            .schema_generator
+            .schema_to_input_ts_node(name, schema_ref, node, context)
+        {
+            Ok(Some(input_node)) => input_node,
+            Ok(None) => return,
+            Err(e) => {
+                warn!(
+                    "Failed to convert request variant of schema {}: {}",
+                    name, e
+                );
+                return;
+            }
+        };
+
+        let input_name = SchemaGenerator::input_type_name(name);
+        if context.schemas.contains_key(&input_name) {
+            warn!(
+                "Schema {} has readOnly or writeOnly properties, but {} already exists; \
+                 requests use the full model",
+                name, input_name
+            );
+            return;
+        }
+        schemas.insert(input_name.clone(), input_node);
+        request_models.insert(name.to_string(), input_name);
+    }
+
+    /// Link properties holding models to the models whose validators check them
+    fn link_validated_models(schemas: &mut HashMap<String, TsNode>) {
+        let validated: HashSet<String> = schemas
+            .iter()
+            .filter(|(_, node)| Self::has_json_helpers(node))
+            .map(|(name, _)| name.to_pascal_case())
+            .collect();
+        for node in schemas.values_mut() {
+            if let TsNode::TypeDefinition(TsTypeDefinition::Interface(interface)) = node {
+                for property in &mut interface.properties {
+                    property.validation.model = Self::model_reference(&property.type_expr)
+                        .filter(|name| validated.contains(name));
+                }
+            }
+        }
+    }
+
+    /// Model a property holds, or holds items of, ignoring `null` and `undefined`
+    fn model_reference(type_expr: &TsExpression) -> Option<String> {
+        match type_expr {
+            TsExpression::Reference(name) => Some(name.clone()),
+            TsExpression::Array(item) => match item.as_ref() {
+                TsExpression::Reference(name) => Some(name.clone()),
+                _ => None,
+            },
+            TsExpression::Union(types) => {
+                let mut present = types.iter().filter(|t| {
+                    !matches!(
+                        t,
+                        TsExpression::Primitive(TsPrimitive::Null | TsPrimitive::Undefined)
+                    )
+                });
+                match (present.next(), present.next()) {
+                    (Some(type_expr), None) => Self::model_reference(type_expr),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a model file exports `FromJSON`/`ToJSON` helpers for the node
+    fn has_json_helpers(node: &TsNode) -> bool {
+        match node {
+            TsNode::TypeDefinition(TsTypeDefinition::Interface(_)) => true,
+            TsNode::TypeDefinition(TsTypeDefinition::TypeAlias(alias)) => {
+                alias.discriminator.is_some()
+            }
+            _ => false,
+        }
+    }
+
     /// Collect all operations grouped by their tags
     fn collect_operations_by_tag(
         &self,
@@ -142,6 +379,11 @@ impl TsLangGenerator {
 
             for (method, operation_opt) in methods {
                 if let Some(operation) = operation_opt {
+                    // Operations without their own servers use the ones of their path
+                    let mut operation = operation.clone();
+                    if operation.servers.is_none() {
+                        operation.servers = path_item.servers.clone();
+                    }
                     let tags = operation.tags.as_ref().unwrap_or(&default_tags);
                     for tag in tags {
                         tag_operations
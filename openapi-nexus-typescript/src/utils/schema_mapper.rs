@@ -1,5 +1,6 @@
 //! Unified schema mapping utilities for OpenAPI to TypeScript conversion
 
+use utoipa::openapi::schema::ArrayItems;
 use utoipa::openapi::{RefOr, Schema};
 
 use crate::ast::TsExpression;
@@ -41,9 +42,10 @@ impl SchemaMapper {
                     TsExpression::Reference("object".to_string())
                 }
             }
-            Schema::Array(_) => TsExpression::Array(Box::new(TsExpression::Primitive(
-                crate::ast::TsPrimitive::String,
-            ))),
+            Schema::Array(array) => TsExpression::Array(Box::new(match &array.items {
+                ArrayItems::RefOrSchema(items) => self.map_ref_or_schema_to_type(items),
+                ArrayItems::False => TsExpression::Primitive(crate::ast::TsPrimitive::String),
+            })),
             _ => TsExpression::Primitive(crate::ast::TsPrimitive::String),
         }
     }
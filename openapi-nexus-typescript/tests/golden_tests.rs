@@ -15,8 +15,9 @@ use tracing_test::traced_test;
 use utoipa::openapi::OpenApi;
 
 use openapi_nexus_core::traits::file_writer::FileWriter;
+use openapi_nexus_parser::OpenApiParser;
 use openapi_nexus_typescript::TsLangGenerator;
-use openapi_nexus_typescript::config::GeneratorConfig;
+use openapi_nexus_typescript::config::{ClientStyle, GeneratorConfig, Int64Strategy};
 
 /// Read a fixture file from various possible locations
 fn read_fixture(fixture_path: &str) -> String {
@@ -39,12 +40,14 @@ fn get_golden_dir() -> &'static Path {
     Path::new("../tests/golden/typescript")
 }
 
-/// Generate TypeScript files from an OpenAPI specification
+/// Generate TypeScript files from an OpenAPI specification, parsed as the CLI does
 fn generate_typescript_files(
     spec_content: &str,
+    config: GeneratorConfig,
 ) -> Result<HashMap<String, String>, Box<dyn std::error::Error + Send + Sync>> {
-    let openapi: OpenApi = serde_norway::from_str(spec_content)?;
-    let config = GeneratorConfig::default();
+    let openapi = OpenApiParser::new()
+        .parse_content(spec_content, Some("yaml"))?
+        .openapi;
     let generator = TsLangGenerator::new(config);
     let generated_files = match generator.generate_files(&openapi) {
         Ok(files) => {
@@ -120,9 +123,18 @@ fn read_directory_recursive(
 fn test_golden_files(
     spec_name: &str,
     fixture_path: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    test_golden_files_with_config(spec_name, fixture_path, GeneratorConfig::default())
+}
+
+/// Update or compare golden files for a given spec generated with a custom configuration
+fn test_golden_files_with_config(
+    spec_name: &str,
+    fixture_path: &str,
+    config: GeneratorConfig,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let spec_content = read_fixture(fixture_path);
-    let generated = match generate_typescript_files(&spec_content) {
+    let generated = match generate_typescript_files(&spec_content, config) {
         Ok(files) => files,
         Err(e) => {
             println!(
@@ -288,6 +300,132 @@ fn test_server_object_golden() {
     }
 }
 
+#[test]
+#[traced_test]
+fn test_date_types_golden() {
+    let mut config = GeneratorConfig::default();
+    config.type_mapping_config.use_date_type = true;
+    test_golden_files_with_config("date-types", "valid/date-types.yaml", config).unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_enum_types_golden() {
+    test_golden_files("enum-types", "valid/enum-types.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_int64_bigint_golden() {
+    let mut config = GeneratorConfig::default();
+    config.type_mapping_config.int64 = Int64Strategy::BigInt;
+    test_golden_files_with_config("int64-bigint", "valid/int64-types.yaml", config).unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_int64_string_golden() {
+    let mut config = GeneratorConfig::default();
+    config.type_mapping_config.int64 = Int64Strategy::String;
+    test_golden_files_with_config("int64-string", "valid/int64-types.yaml", config).unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_read_write_only_golden() {
+    test_golden_files("read-write-only", "valid/read-write-only.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_form_bodies_golden() {
+    test_golden_files("form-bodies", "valid/form-bodies.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_response_types_golden() {
+    test_golden_files("response-types", "valid/response-types.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_response_types_stream_golden() {
+    let mut config = GeneratorConfig::default();
+    config.type_mapping_config.stream_binary_responses = true;
+    test_golden_files_with_config("response-types-stream", "valid/response-types.yaml", config)
+        .unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_parameter_styles_golden() {
+    test_golden_files("parameter-styles", "valid/parameter-styles.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_security_schemes_golden() {
+    test_golden_files("security-schemes", "valid/security-schemes.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_zod_schemas_golden() {
+    let mut config = GeneratorConfig::default();
+    config.emission_config.zod_schemas = true;
+    test_golden_files_with_config("zod-schemas", "valid/zod-schemas.yaml", config).unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_idempotent_operations_golden() {
+    test_golden_files("idempotent-operations", "valid/idempotent-operations.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_pagination_golden() {
+    test_golden_files("pagination", "valid/pagination.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_event_streams_golden() {
+    test_golden_files("event-streams", "valid/event-streams.yaml").unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_client_functions_golden() {
+    let mut config = GeneratorConfig::default();
+    config.emission_config.client_style = ClientStyle::Functions;
+    test_golden_files_with_config("client-functions", "valid/client-functions.yaml", config)
+        .unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_tanstack_query_golden() {
+    let mut config = GeneratorConfig::default();
+    config.emission_config.tanstack_query = true;
+    test_golden_files_with_config("tanstack-query", "valid/tanstack-query.yaml", config).unwrap();
+}
+
+#[test]
+#[traced_test]
+fn test_tanstack_query_functions_golden() {
+    let mut config = GeneratorConfig::default();
+    config.emission_config.tanstack_query = true;
+    config.emission_config.client_style = ClientStyle::Functions;
+    test_golden_files_with_config(
+        "tanstack-query-functions",
+        "valid/tanstack-query.yaml",
+        config,
+    )
+    .unwrap();
+}
+
 #[test]
 #[traced_test]
 fn test_runtime_generation() {
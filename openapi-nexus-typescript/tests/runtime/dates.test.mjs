@@ -0,0 +1,31 @@
+// formatDate and parseDate, which read and write `date` values in local time
+
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+
+import { formatDate, parseDate } from './runtime.ts';
+
+const TIME_ZONES = ['UTC', 'America/Los_Angeles', 'Pacific/Kiritimati', 'Asia/Kolkata'];
+
+for (const timeZone of TIME_ZONES) {
+    test(`local calendar dates in ${timeZone}`, () => {
+        process.env.TZ = timeZone;
+        try {
+            assert.equal(formatDate(new Date(2024, 0, 2)), '2024-01-02');
+            assert.equal(formatDate(new Date(2024, 0, 2, 23, 59, 59)), '2024-01-02');
+            assert.equal(formatDate(new Date(2024, 11, 31, 0, 0, 1)), '2024-12-31');
+
+            const date = parseDate('2024-03-10');
+            assert.deepEqual([date.getFullYear(), date.getMonth(), date.getDate(), date.getHours()], [2024, 2, 10, 0]);
+            assert.equal(formatDate(date), '2024-03-10');
+            assert.equal(formatDate(parseDate('0099-12-31')), '0099-12-31');
+        } finally {
+            delete process.env.TZ;
+        }
+    });
+}
+
+test('reads other values as Date does', () => {
+    assert.equal(parseDate('2024-01-02T03:04:05Z').toISOString(), '2024-01-02T03:04:05.000Z');
+    assert.ok(Number.isNaN(parseDate('not a date').getTime()));
+});
@@ -0,0 +1,49 @@
+// stringifyJson and parseJson of the lossless int64 runtime
+
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+
+import { parseJson, stringifyJson } from './runtime.ts';
+
+test('writes bigints as plain integers', () => {
+    assert.equal(stringifyJson({ id: 9007199254740993n, ids: [1n, -2n] }), '{"id":9007199254740993,"ids":[1,-2]}');
+    assert.equal(stringifyJson(12345678901234567890n), '12345678901234567890');
+});
+
+test('leaves strings that look like bigint placeholders alone', () => {
+    const value = { note: '__bigint__42', other: '"__bigint__7"', id: 42n };
+    assert.equal(stringifyJson(value), '{"note":"__bigint__42","other":"\\"__bigint__7\\"","id":42}');
+});
+
+test('leaves strings shaped like the placeholders of the call alone', () => {
+    const realRandom = Math.random;
+    const randoms = [0.5, 0.5, 0.25];
+    Math.random = () => randoms.shift() ?? realRandom();
+    try {
+        const nonce = (0.5).toString(36).slice(2);
+        const value = { note: `${nonce}:0`, id: 7n };
+        assert.equal(stringifyJson(value), `{"note":"${nonce}:0","id":7}`);
+    } finally {
+        Math.random = realRandom;
+    }
+});
+
+test('matches JSON.stringify without bigints', () => {
+    const value = { name: 'a', list: [1, 2.5, null, true], nested: { text: 'x:0' } };
+    assert.equal(stringifyJson(value), JSON.stringify(value));
+    assert.equal(stringifyJson(undefined), undefined);
+});
+
+test('keeps unsafe integers of parsed JSON as strings', () => {
+    assert.deepEqual(parseJson('{"id":9007199254740993,"small":42,"text":"9007199254740993","ratio":1.5e3}'), {
+        id: '9007199254740993',
+        small: 42,
+        text: '9007199254740993',
+        ratio: 1500,
+    });
+});
+
+test('round trips through parseJson', () => {
+    const json = stringifyJson({ note: '__bigint__1', id: 9007199254740993n });
+    assert.deepEqual(parseJson(json), { note: '__bigint__1', id: '9007199254740993' });
+});
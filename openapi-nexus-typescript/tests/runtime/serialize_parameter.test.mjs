@@ -0,0 +1,66 @@
+// serializeParameter against the style examples of the OpenAPI specification,
+// for a parameter named `color`
+
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+
+import { serializeParameter } from './runtime.ts';
+
+const primitive = 'blue';
+const array = ['blue', 'black', 'brown'];
+const object = { R: 100, G: 200, B: 150 };
+
+// [style, explode, empty string, primitive, array, object]
+const STYLE_TABLE = [
+    ['matrix', false, ';color', ';color=blue', ';color=blue,black,brown', ';color=R,100,G,200,B,150'],
+    ['matrix', true, ';color', ';color=blue', ';color=blue;color=black;color=brown', ';R=100;G=200;B=150'],
+    ['label', false, '.', '.blue', '.blue,black,brown', '.R,100,G,200,B,150'],
+    ['label', true, '.', '.blue', '.blue.black.brown', '.R=100.G=200.B=150'],
+    ['simple', false, '', 'blue', 'blue,black,brown', 'R,100,G,200,B,150'],
+    ['simple', true, '', 'blue', 'blue,black,brown', 'R=100,G=200,B=150'],
+    ['form', false, 'color=', 'color=blue', 'color=blue,black,brown', 'color=R,100,G,200,B,150'],
+    ['form', true, 'color=', 'color=blue', 'color=blue&color=black&color=brown', 'R=100&G=200&B=150'],
+    ['spaceDelimited', false, 'color=', 'color=blue', 'color=blue%20black%20brown', 'color=R%20100%20G%20200%20B%20150'],
+    ['spaceDelimited', true, 'color=', 'color=blue', 'color=blue&color=black&color=brown', 'R=100&G=200&B=150'],
+    ['pipeDelimited', false, 'color=', 'color=blue', 'color=blue|black|brown', 'color=R|100|G|200|B|150'],
+    ['pipeDelimited', true, 'color=', 'color=blue', 'color=blue&color=black&color=brown', 'R=100&G=200&B=150'],
+    ['deepObject', true, 'color=', 'color=blue', 'color=blue&color=black&color=brown', 'color%5BR%5D=100&color%5BG%5D=200&color%5BB%5D=150'],
+];
+
+for (const [style, explode, empty, single, list, map] of STYLE_TABLE) {
+    test(`${style} explode=${explode}`, () => {
+        assert.equal(serializeParameter('color', '', style, explode), empty);
+        assert.equal(serializeParameter('color', primitive, style, explode), single);
+        assert.equal(serializeParameter('color', array, style, explode), list);
+        assert.equal(serializeParameter('color', new Set(array), style, explode), list);
+        assert.equal(serializeParameter('color', object, style, explode), map);
+    });
+
+    test(`${style} explode=${explode} with empty and undefined values`, () => {
+        assert.equal(serializeParameter('color', [], style, explode), '');
+        assert.equal(serializeParameter('color', {}, style, explode), '');
+        assert.equal(serializeParameter('color', { R: undefined }, style, explode), '');
+        assert.equal(serializeParameter('color', undefined, style, explode), '');
+        assert.equal(serializeParameter('color', null, style, explode), '');
+    });
+}
+
+test('percent-encodes names and values', () => {
+    assert.equal(serializeParameter('the color', 'sky blue', 'form', true), 'the%20color=sky%20blue');
+    assert.equal(serializeParameter('color', ['a&b', 'c,d'], 'form', false), 'color=a%26b,c%2Cd');
+    assert.equal(serializeParameter('color', { 'a/b': 'c d' }, 'simple', true), 'a%2Fb=c%20d');
+});
+
+test('leaves header values unencoded', () => {
+    assert.equal(serializeParameter('X-Color', 'sky blue', 'simple', false, false), 'sky blue');
+    assert.equal(serializeParameter('X-Color', ['a b', 'c/d'], 'simple', false, false), 'a b,c/d');
+    assert.equal(serializeParameter('X-Color', { R: 'a b', G: 'c/d' }, 'simple', false, false), 'R,a b,G,c/d');
+    assert.equal(serializeParameter('X-Color', { R: 'a b', G: 'c/d' }, 'simple', true, false), 'R=a b,G=c/d');
+    assert.equal(serializeParameter('X-Color', [], 'simple', false, false), '');
+});
+
+test('writes dates as ISO 8601 timestamps', () => {
+    const date = new Date(Date.UTC(2024, 0, 2, 3, 4, 5));
+    assert.equal(serializeParameter('since', date, 'form', true), 'since=2024-01-02T03%3A04%3A05.000Z');
+    assert.equal(serializeParameter('since', date, 'simple', false, false), '2024-01-02T03:04:05.000Z');
+});
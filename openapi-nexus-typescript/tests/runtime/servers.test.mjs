@@ -0,0 +1,66 @@
+// Server selection of BaseAPI, for a specification with several servers
+
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+
+import { BASE_PATH, BaseAPI, Configuration, ServerConfiguration } from './runtime.ts';
+
+const operationServers = [
+    new ServerConfiguration('https://files.example.com/{bucket}', 'File storage', { bucket: 'public' }),
+    new ServerConfiguration('https://mirror.example.com/'),
+];
+
+async function send(configuration, context, initOverrides) {
+    const requests = [];
+    const fetchApi = async (url, init) => {
+        requests.push({ url, init });
+        return new Response(null, { status: 204 });
+    };
+    const api = new BaseAPI(new Configuration({ ...configuration, fetchApi }));
+    await api.request({ path: '/files', method: 'GET', headers: {}, ...context }, initOverrides);
+    return requests[0];
+}
+
+async function url(configuration, context, initOverrides) {
+    return (await send(configuration, context, initOverrides)).url;
+}
+
+test('uses the first server of the operation by default', async () => {
+    assert.equal(await url({}, { servers: operationServers }), 'https://files.example.com/public/files');
+});
+
+test('uses the API base path for operations without servers', async () => {
+    assert.equal(BASE_PATH, 'https://api.example.com/v1');
+    assert.equal(await url({}, {}), 'https://api.example.com/v1/files');
+});
+
+test('prefers the configured base path to the servers of the operation', async () => {
+    const configuration = { basePath: 'https://proxy.example.com' };
+    assert.equal(await url(configuration, { servers: operationServers }), 'https://proxy.example.com/files');
+    assert.equal(await url(configuration, {}), 'https://proxy.example.com/files');
+});
+
+test('picks the server and variables given in the request options', async () => {
+    const configuration = { basePath: 'https://proxy.example.com' };
+    const context = { servers: operationServers };
+    assert.equal(await url(configuration, context, { server: 1 }), 'https://mirror.example.com/files');
+    assert.equal(await url(configuration, context, { serverVariables: { bucket: 'private' } }), 'https://files.example.com/private/files');
+    assert.equal(await url({}, context, { server: 0, serverVariables: { bucket: 'a' } }), 'https://files.example.com/a/files');
+});
+
+test('picks among the API servers for operations without servers', async () => {
+    assert.equal(await url({}, {}, { server: 2 }), 'https://dev-api.example.com/v1/files');
+    assert.equal(await url({}, {}, { server: 3 }), 'https://eu.api.example.com/v1/files');
+    assert.equal(await url({}, {}, { server: 3, serverVariables: { region: 'us' } }), 'https://us.api.example.com/v1/files');
+});
+
+test('rejects unknown servers', async () => {
+    await assert.rejects(url({}, { servers: operationServers }, { server: 2 }), RangeError);
+});
+
+test('keeps server options out of the fetch init', async () => {
+    const { init } = await send({}, { servers: operationServers }, { server: 1, serverVariables: {}, cache: 'no-store' });
+    assert.equal(init.cache, 'no-store');
+    assert.equal('server' in init, false);
+    assert.equal('serverVariables' in init, false);
+});
@@ -0,0 +1,85 @@
+// Status-keyed results and typed errors of the client generated for the
+// response-types fixture, with int64 values read losslessly as bigint. The
+// bodies are read after narrowing on the status, so `tsc --noEmit` on this
+// suite also checks that the unions narrow.
+
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+
+import { GetReportError, ReportsApi } from './apis/ReportsApi.ts';
+import { Configuration } from './runtime/runtime.ts';
+
+const UNSAFE_INTEGER = '9007199254740993';
+
+function api(status: number, body: string, contentType = 'application/json', validateModels = false): ReportsApi {
+    return new ReportsApi(new Configuration({
+        basePath: 'http://localhost',
+        fetchApi: async () => new Response(body, { status, headers: { 'Content-Type': contentType } }),
+        validateModels,
+    }));
+}
+
+async function rejection(promise: Promise<unknown>): Promise<GetReportError> {
+    try {
+        await promise;
+    } catch (e) {
+        assert.ok(e instanceof GetReportError);
+        return e;
+    }
+    assert.fail('expected the request to fail');
+}
+
+test('parses each documented success status', async () => {
+    const created = await api(201, '{"id":"r1"}').createReport();
+    if (created.status !== 201) {
+        assert.fail(`unexpected status ${created.status}`);
+    }
+    assert.equal(created.body.id, 'r1');
+
+    const queued = await api(202, `{"jobId":"j1","position":${UNSAFE_INTEGER}}`).createReport();
+    if (queued.status !== 202) {
+        assert.fail(`unexpected status ${queued.status}`);
+    }
+    const position: bigint | undefined = queued.body.position;
+    assert.equal(position, BigInt(UNSAFE_INTEGER));
+});
+
+test('checks success bodies like single JSON responses', async () => {
+    await assert.rejects(api(202, '{}', 'application/json', true).createReport(), { name: 'ValidationError' });
+    const queued = await api(202, '{}').createReport();
+    assert.equal(queued.status, 202);
+});
+
+test('narrows errors on their documented status', async () => {
+    const missing = (await rejection(api(404, 'no such report', 'text/plain').getReport('r1'))).payload;
+    if (missing.status !== 404) {
+        assert.fail(`unexpected status ${missing.status}`);
+    }
+    const text: string = missing.body;
+    assert.equal(text, 'no such report');
+
+    const body = `{"title":"down","traceId":${UNSAFE_INTEGER}}`;
+    const failure = (await rejection(api(503, body, 'application/problem+json').getReport('r1'))).payload;
+    if (failure.status !== 503 || typeof failure.body === 'string') {
+        assert.fail(`unexpected payload ${JSON.stringify(failure)}`);
+    }
+    assert.equal(failure.body.title, 'down');
+    const traceId: bigint | undefined = failure.body.traceId;
+    assert.equal(traceId, BigInt(UNSAFE_INTEGER));
+});
+
+test('keeps the raw text of error bodies that are not JSON', async () => {
+    const gateway = (await rejection(api(502, 'Bad Gateway', 'application/problem+json').getReport('r1'))).payload;
+    assert.equal(gateway.status, 502);
+    assert.equal(gateway.body, 'Bad Gateway');
+
+    const teapot = (await rejection(api(418, 'short and stout', 'text/plain').getReport('r1'))).payload;
+    assert.equal(teapot.status, 418);
+    assert.equal(teapot.body, 'short and stout');
+});
+
+test('reads undocumented error bodies losslessly', async () => {
+    const conflict = (await rejection(api(409, `{"version":${UNSAFE_INTEGER}}`).getReport('r1'))).payload;
+    assert.equal(conflict.status, 409);
+    assert.deepEqual(conflict.body, { version: UNSAFE_INTEGER });
+});
@@ -0,0 +1,32 @@
+// validateProperty of the lossless int64 runtime
+
+import assert from 'node:assert/strict';
+import { test } from 'node:test';
+
+import { validateProperty } from './runtime.ts';
+
+function violations(value, attributes) {
+    const found = [];
+    validateProperty(value, attributes, 'value', found);
+    return found.map(violation => violation.message);
+}
+
+test('checks unique items of bigints', () => {
+    assert.deepEqual(violations([1n, 9007199254740993n], { uniqueItems: true }), []);
+    assert.deepEqual(violations([9007199254740993n, 9007199254740993n], { uniqueItems: true }), ['must have unique items']);
+    assert.deepEqual(violations([{ id: 1n }, { id: 2n }], { uniqueItems: true }), []);
+    assert.deepEqual(violations([{ id: 1n }, { id: 1n }], { uniqueItems: true }), ['must have unique items']);
+});
+
+test('checks unique items of other values', () => {
+    assert.deepEqual(violations(['a', 'b', 1], { uniqueItems: true }), []);
+    assert.deepEqual(violations([{ a: [1] }, { a: [1] }], { uniqueItems: true }), ['must have unique items']);
+    assert.deepEqual(violations(['a', 'a'], {}), []);
+});
+
+test('checks bounds of bigints', () => {
+    assert.deepEqual(violations(0n, { minimum: 0, exclusiveMinimum: true }), ['must be greater than 0']);
+    assert.deepEqual(violations(1n, { minimum: 0, exclusiveMinimum: true }), []);
+    assert.deepEqual(violations(100n, { maximum: 100 }), []);
+    assert.deepEqual(violations(101n, { maximum: 100 }), ['must be at most 100']);
+});
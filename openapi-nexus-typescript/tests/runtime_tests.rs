@@ -0,0 +1,216 @@
+//! Behaviour tests for the generated TypeScript runtime
+//!
+//! The runtime, or the whole client, is generated from a fixture and exercised
+//! by the `node:test` suites in `tests/runtime`. Node has to run TypeScript directly, which needs
+//! `--experimental-transform-types` (Node 22.7 or later), so the tests are
+//! ignored by default and fail when no such Node is on the `PATH`:
+//!   cargo test --test runtime_tests -- --ignored
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use openapi_nexus_core::traits::file_writer::FileWriter;
+use openapi_nexus_parser::OpenApiParser;
+use openapi_nexus_typescript::TsLangGenerator;
+use openapi_nexus_typescript::config::{GeneratorConfig, Int64Strategy};
+
+const NODE_FLAGS: [&str; 2] = ["--experimental-transform-types", "--no-warnings"];
+
+/// Whether the `node` on the `PATH` can run TypeScript
+fn node_runs_typescript() -> bool {
+    Command::new("node")
+        .args(NODE_FLAGS)
+        .args(["--eval", ""])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// A fresh directory holding a copy of the suite
+fn suite_dir(suite: &str) -> PathBuf {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!(
+        "openapi_nexus_runtime_{}_{}",
+        std::process::id(),
+        timestamp
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("package.json"), r#"{ "type": "module" }"#).unwrap();
+    fs::copy(Path::new("tests/runtime").join(suite), dir.join(suite)).unwrap();
+    dir
+}
+
+/// Generator of a fixture, along with its parsed specification
+fn generator(
+    fixture: &str,
+    config: GeneratorConfig,
+) -> (TsLangGenerator, utoipa::openapi::OpenApi) {
+    let spec = fs::read_to_string(Path::new("../tests/fixtures").join(fixture)).unwrap();
+    let openapi = OpenApiParser::new()
+        .parse_content(&spec, Some("yaml"))
+        .unwrap()
+        .openapi;
+    (TsLangGenerator::new(config), openapi)
+}
+
+/// Generate the runtime for a fixture into a fresh directory, next to the suite
+fn prepare_runtime(fixture: &str, config: GeneratorConfig, suite: &str) -> PathBuf {
+    let (generator, openapi) = generator(fixture, config);
+    let files = generator.generate_files(&openapi).unwrap();
+    let runtime = files
+        .iter()
+        .find(|file| file.filename == "runtime.ts")
+        .expect("Runtime file should be generated");
+
+    let dir = suite_dir(suite);
+    fs::write(dir.join("runtime.ts"), &runtime.content).unwrap();
+    dir
+}
+
+/// Generate the whole client for a fixture into a fresh directory, next to the suite
+fn prepare_client(fixture: &str, config: GeneratorConfig, suite: &str) -> PathBuf {
+    let (generator, openapi) = generator(fixture, config);
+    let files = generator.generate_files(&openapi).unwrap();
+
+    let dir = suite_dir(suite);
+    generator.write_files(&dir, &files).unwrap();
+    add_import_extensions(&dir);
+    dir
+}
+
+/// Point the relative imports of the generated files at the files themselves,
+/// as Node does not resolve modules without their extension
+fn add_import_extensions(dir: &Path) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            add_import_extensions(&path);
+            continue;
+        }
+        if path.extension().is_none_or(|extension| extension != "ts") {
+            continue;
+        }
+        let parent = path.parent().unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        let mut rewritten = String::with_capacity(content.len());
+        let mut rest = content.as_str();
+        while let Some(start) = rest.find("from '.") {
+            let (head, tail) = rest.split_at(start + "from '".len());
+            let end = tail.find('\'').unwrap();
+            let specifier = &tail[..end];
+            let suffix = if specifier.ends_with(".ts") {
+                ""
+            } else if parent.join(specifier).is_dir() {
+                "/index.ts"
+            } else {
+                ".ts"
+            };
+            rewritten.push_str(head);
+            rewritten.push_str(specifier);
+            rewritten.push_str(suffix);
+            rest = &tail[end..];
+        }
+        rewritten.push_str(rest);
+        fs::write(&path, rewritten).unwrap();
+    }
+}
+
+/// Run a `node:test` suite in a directory prepared for it
+fn run_suite_in(dir: &Path, suite: &str) {
+    let output = Command::new("node")
+        .args(NODE_FLAGS)
+        .arg(suite)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "{suite} failed in {}\n{stdout}\n{stderr}",
+        dir.display()
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+/// Assert that the `node` on the `PATH` can run the TypeScript of a suite
+fn require_node(suite: &str) {
+    assert!(
+        node_runs_typescript(),
+        "{suite} needs a node on the PATH that runs TypeScript with --experimental-transform-types (Node 22.7 or later)"
+    );
+}
+
+/// Run a `node:test` suite against the runtime generated for a fixture
+fn run_suite(fixture: &str, config: GeneratorConfig, suite: &str) {
+    require_node(suite);
+    run_suite_in(&prepare_runtime(fixture, config, suite), suite);
+}
+
+/// Run a `node:test` suite against the whole client generated for a fixture
+fn run_client_suite(fixture: &str, config: GeneratorConfig, suite: &str) {
+    require_node(suite);
+    run_suite_in(&prepare_client(fixture, config, suite), suite);
+}
+
+#[test]
+#[ignore = "needs Node 22.7 or later to run TypeScript"]
+fn test_serialize_parameter() {
+    run_suite(
+        "valid/parameter-styles.yaml",
+        GeneratorConfig::default(),
+        "serialize_parameter.test.mjs",
+    );
+}
+
+#[test]
+#[ignore = "needs Node 22.7 or later to run TypeScript"]
+fn test_lossless_json() {
+    let mut config = GeneratorConfig::default();
+    config.type_mapping_config.int64 = Int64Strategy::BigInt;
+    run_suite("valid/int64-types.yaml", config, "json.test.mjs");
+}
+
+#[test]
+#[ignore = "needs Node 22.7 or later to run TypeScript"]
+fn test_validate_property() {
+    let mut config = GeneratorConfig::default();
+    config.type_mapping_config.int64 = Int64Strategy::BigInt;
+    run_suite("valid/int64-types.yaml", config, "validation.test.mjs");
+}
+
+#[test]
+#[ignore = "needs Node 22.7 or later to run TypeScript"]
+fn test_dates() {
+    run_suite(
+        "valid/date-types.yaml",
+        GeneratorConfig::default(),
+        "dates.test.mjs",
+    );
+}
+
+#[test]
+#[ignore = "needs Node 22.7 or later to run TypeScript"]
+fn test_servers() {
+    run_suite(
+        "valid/server-object.yaml",
+        GeneratorConfig::default(),
+        "servers.test.mjs",
+    );
+}
+
+#[test]
+#[ignore = "needs Node 22.7 or later to run TypeScript"]
+fn test_status_responses() {
+    let mut config = GeneratorConfig::default();
+    config.type_mapping_config.int64 = Int64Strategy::BigInt;
+    run_client_suite(
+        "valid/response-types.yaml",
+        config,
+        "status_responses.test.ts",
+    );
+}
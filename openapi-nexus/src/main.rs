@@ -1,10 +1,12 @@
 //! OpenAPI Code Generator CLI
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use tracing::{Level, info};
 
 use openapi_nexus_core::OpenApiCodeGenerator;
+use openapi_nexus_transforms::{FilterRules, Instrumentation, SpecFilterPass};
 use openapi_nexus_typescript::TsLangGenerator;
+use openapi_nexus_typescript::config::{ClientStyle, Int64Strategy};
 
 #[derive(Parser)]
 #[command(name = "openapi-nexus")]
@@ -34,6 +36,15 @@ enum Commands {
         /// Verbose output
         #[arg(short, long)]
         verbose: bool,
+
+        #[command(flatten)]
+        filter: Box<FilterArgs>,
+
+        #[command(flatten)]
+        instrumentation: InstrumentationArgs,
+
+        #[command(flatten)]
+        typescript: TypeScriptArgs,
     },
     /// Validate an OpenAPI specification
     Validate {
@@ -47,6 +58,204 @@ enum Commands {
     },
 }
 
+/// Operation filtering options
+///
+/// Operations are kept when they match every kind of `--include-*` option
+/// given, e.g. GET operations tagged `pets` for `--include-tag pets
+/// --include-method get`, and dropped when they match any `--exclude-*` option.
+#[derive(Args)]
+struct FilterArgs {
+    /// Only keep operations with one of these tags
+    ///
+    /// Include options of different kinds all apply: `--include-tag pets
+    /// --include-method get` keeps the GET operations tagged `pets`. Exclude
+    /// options drop the operations matching any of them.
+    #[arg(long, value_name = "TAG")]
+    include_tag: Vec<String>,
+
+    /// Drop operations with one of these tags
+    #[arg(long, value_name = "TAG")]
+    exclude_tag: Vec<String>,
+
+    /// Only keep operations whose path matches one of these globs
+    #[arg(long, value_name = "GLOB")]
+    include_path: Vec<String>,
+
+    /// Drop operations whose path matches one of these globs
+    #[arg(long, value_name = "GLOB")]
+    exclude_path: Vec<String>,
+
+    /// Only keep operations with one of these operationIds
+    #[arg(long, value_name = "ID")]
+    include_operation: Vec<String>,
+
+    /// Drop operations with one of these operationIds
+    #[arg(long, value_name = "ID")]
+    exclude_operation: Vec<String>,
+
+    /// Only keep operations using one of these HTTP methods
+    #[arg(long, value_name = "METHOD")]
+    include_method: Vec<String>,
+
+    /// Drop operations using one of these HTTP methods
+    #[arg(long, value_name = "METHOD")]
+    exclude_method: Vec<String>,
+
+    /// Only keep operations carrying an extension, e.g. `x-public` or `x-audience=partner`
+    #[arg(long, value_name = "KEY[=JSON]", value_parser = parse_extension)]
+    include_extension: Vec<(String, serde_json::Value)>,
+
+    /// Drop operations carrying an extension, e.g. `x-internal`
+    #[arg(long, value_name = "KEY[=JSON]", value_parser = parse_extension)]
+    exclude_extension: Vec<(String, serde_json::Value)>,
+
+    /// Drop deprecated operations
+    #[arg(long)]
+    exclude_deprecated: bool,
+}
+
+impl FilterArgs {
+    /// Build the filtering pass, or `None` when no filter was requested
+    fn into_pass(self) -> Option<SpecFilterPass> {
+        let pass = SpecFilterPass {
+            include: FilterRules {
+                tags: self.include_tag,
+                paths: self.include_path,
+                operation_ids: self.include_operation,
+                methods: self.include_method,
+                deprecated: false,
+                extensions: self.include_extension.into_iter().collect(),
+            },
+            exclude: FilterRules {
+                tags: self.exclude_tag,
+                paths: self.exclude_path,
+                operation_ids: self.exclude_operation,
+                methods: self.exclude_method,
+                deprecated: self.exclude_deprecated,
+                extensions: self.exclude_extension.into_iter().collect(),
+            },
+            ..SpecFilterPass::default()
+        };
+
+        (!pass.include.is_empty() || !pass.exclude.is_empty()).then_some(pass)
+    }
+}
+
+/// Transformation pipeline instrumentation options
+#[derive(Args)]
+struct InstrumentationArgs {
+    /// Print how long each transformation pass took
+    #[arg(long)]
+    pass_report: bool,
+
+    /// Also report what each pass added, removed and changed (implies --pass-report)
+    #[arg(long)]
+    pass_diff: bool,
+
+    /// Write the specification after each transformation pass to a subdirectory
+    /// of this directory named after the language
+    #[arg(long, value_name = "DIR")]
+    snapshot_dir: Option<std::path::PathBuf>,
+}
+
+impl InstrumentationArgs {
+    fn prints_report(&self) -> bool {
+        self.pass_report || self.pass_diff
+    }
+
+    fn to_instrumentation(&self) -> Instrumentation {
+        Instrumentation {
+            diff: self.pass_diff,
+            snapshot_dir: self.snapshot_dir.clone(),
+        }
+    }
+}
+
+/// TypeScript generation options
+#[derive(Args)]
+struct TypeScriptArgs {
+    /// Map `date` and `date-time` strings to `Date` instead of `string`
+    #[arg(long)]
+    use_date_type: bool,
+
+    /// TypeScript type of `int64` integers
+    #[arg(long, value_enum, default_value_t = Int64Arg::Number)]
+    int64: Int64Arg,
+
+    /// Return binary responses as a `ReadableStream` instead of a `Blob`
+    #[arg(long)]
+    stream_binary_responses: bool,
+
+    /// Emit Zod schemas of the components, which API methods can check responses against
+    #[arg(long)]
+    zod_schemas: bool,
+
+    /// Shape of the generated API client
+    #[arg(long, value_enum, default_value_t = ClientStyleArg::Classes)]
+    client_style: ClientStyleArg,
+
+    /// Emit TanStack Query hooks and query-key factories of the operations
+    #[arg(long)]
+    tanstack_query: bool,
+}
+
+/// TypeScript representations of `int64` integers
+#[derive(Clone, Copy, ValueEnum)]
+enum Int64Arg {
+    /// `number`, losing precision above 2^53
+    Number,
+    /// `bigint`
+    Bigint,
+    /// `string` holding the decimal digits
+    String,
+}
+
+/// Shapes of the generated TypeScript API client
+#[derive(Clone, Copy, ValueEnum)]
+enum ClientStyleArg {
+    /// One class per tag, extending `BaseAPI`
+    Classes,
+    /// One tree-shakable module per operation, exporting standalone functions
+    Functions,
+}
+
+impl TypeScriptArgs {
+    fn to_config(&self) -> openapi_nexus_typescript::config::GeneratorConfig {
+        let mut config = openapi_nexus_typescript::config::GeneratorConfig::default();
+        config.type_mapping_config.use_date_type = self.use_date_type;
+        config.type_mapping_config.stream_binary_responses = self.stream_binary_responses;
+        config.emission_config.zod_schemas = self.zod_schemas;
+        config.emission_config.client_style = match self.client_style {
+            ClientStyleArg::Classes => ClientStyle::Classes,
+            ClientStyleArg::Functions => ClientStyle::Functions,
+        };
+        config.emission_config.tanstack_query = self.tanstack_query;
+        config.type_mapping_config.int64 = match self.int64 {
+            Int64Arg::Number => Int64Strategy::Number,
+            Int64Arg::Bigint => Int64Strategy::BigInt,
+            Int64Arg::String => Int64Strategy::String,
+        };
+        config
+    }
+}
+
+/// Parse `KEY[=JSON]`, defaulting the value to `true`
+fn parse_extension(arg: &str) -> Result<(String, serde_json::Value), String> {
+    let (key, value) = match arg.split_once('=') {
+        Some((key, value)) => {
+            let value = serde_json::from_str(value)
+                .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+            (key, value)
+        }
+        None => (arg, serde_json::Value::Bool(true)),
+    };
+
+    if !key.starts_with("x-") {
+        return Err(format!("extension '{}' must start with 'x-'", key));
+    }
+    Ok((key.to_string(), value))
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
@@ -64,6 +273,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             input,
             output,
             languages,
+            filter,
+            instrumentation,
+            typescript,
             ..
         } => {
             info!("Starting code generation");
@@ -71,15 +283,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             info!("Output: {}", output);
             info!("Languages: {:?}", languages);
 
-            let mut generator = OpenApiCodeGenerator::new();
+            let pipeline = match filter.into_pass() {
+                Some(filter_pass) => OpenApiCodeGenerator::filtered_pipeline(filter_pass),
+                None => OpenApiCodeGenerator::default_pipeline(),
+            };
+            let mut generator = OpenApiCodeGenerator::new().with_transform_pipeline(
+                pipeline.with_instrumentation(instrumentation.to_instrumentation()),
+            );
 
-            // Register TypeScript generator with default configuration
-            let ts_config = openapi_nexus_typescript::config::GeneratorConfig::default();
+            // Register TypeScript generator with the requested configuration
+            let ts_config = typescript.to_config();
             let ts_generator = TsLangGenerator::new(ts_config.clone());
             generator.register_language_generator("typescript", ts_generator.clone())?;
             generator.register_language_generator("ts", ts_generator)?;
 
-            generator.generate_from_file(&input, &output, &languages)?;
+            let reports = generator.generate_from_file_with_reports(&input, &output, &languages)?;
+            if instrumentation.prints_report() {
+                for (language, report) in reports {
+                    println!("Transformation passes for {}:\n{}\n", language, report);
+                }
+            }
 
             info!("Code generation completed successfully");
         }
@@ -0,0 +1,77 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Client Functions API
+ *
+ * Operations generated as standalone functions
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, readErrorBody, type StatusCodes, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Pet, PetFromJSON, PetToJSON, PetValidate } from '../models/Pet';
+import { type Problem, ProblemFromJSON } from '../models/Problem';
+
+/** Error responses of createPet, keyed by status */
+export type CreatePetErrorResponse =
+  | { status: 422; body: Problem | string }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 422>; body: unknown };
+
+/** Error thrown by createPet for non-2xx responses, with the parsed body */
+export class CreatePetError extends ResponseError {
+  constructor(response: Response, public readonly payload: CreatePetErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<CreatePetError> {
+    return new CreatePetError(response, await CreatePetError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<CreatePetErrorResponse> {
+    switch (response.status) {
+      case 422:
+        return { status: 422, body: await readErrorBody(response, ProblemFromJSON) };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 422>, body: await readErrorBody(response) };
+  }
+}
+
+/** Add a pet */
+export async function createPetRaw(client: Client, body: Pet, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
+  // Build path with path parameters
+  let urlPath = `/pets`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    'Content-Type': 'application/json',
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Prepare request body
+  const requestBody = client.validateBody(PetValidate, PetToJSON(body));
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'POST',
+      headers: headerParameters,
+      query: queryParameters,
+      body: requestBody,
+  }, initOverrides, CreatePetError.fromResponse);
+
+  return new JSONApiResponse(response, (jsonValue) => PetFromJSON(client.validateBody(PetValidate, jsonValue)));
+}
+
+/** Add a pet */
+export async function createPet(client: Client, body: Pet, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+  const response = await createPetRaw(client, body, initOverrides);
+  return await response.value();
+}
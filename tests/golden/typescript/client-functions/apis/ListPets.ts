@@ -0,0 +1,73 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Client Functions API
+ *
+ * Operations generated as standalone functions
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, validateItems, nextPageLink, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Pet, PetFromJSON, PetValidate } from '../models/Pet';
+
+/** List pets, following the next links */
+export async function listPetsRaw(client: Client, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Pet>>> {
+  // Build path with path parameters
+  let urlPath = `/pets`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+  if (limit !== undefined) {
+    queryParameters['limit'] = limit;
+  }
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'GET',
+      headers: headerParameters,
+      query: queryParameters,
+  }, initOverrides);
+
+  return new JSONApiResponse(response, (jsonValue) => (client.validateBody(validateItems(PetValidate), jsonValue) as Array<any>).map(PetFromJSON));
+}
+
+/** List pets, following the next links */
+export async function listPets(client: Client, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Pet>> {
+  const response = await listPetsRaw(client, limit, initOverrides);
+  return await response.value();
+}
+
+/**
+ * Walk the pages of `listPets`, following the `next` links of their responses
+ */
+export async function* listPetsAllPages(client: Client, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Array<Pet>> {
+  let pagesApi = client;
+  for (;;) {
+    const pageResponse = await listPetsRaw(pagesApi, limit, initOverrides);
+    yield await pageResponse.value();
+    const next = nextPageLink(pageResponse.raw);
+    if (next === undefined) {
+      return;
+    }
+    pagesApi = client.withPreMiddleware(async ({ init }) => ({ url: next, init }));
+  }
+}
+
+/** Iterate the items of every page of `listPets` */
+export async function* listPetsPaginated(client: Client, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Pet> {
+  for await (const currentPage of listPetsAllPages(client, limit, initOverrides)) {
+    yield* currentPage;
+  }
+}
@@ -0,0 +1,47 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Client Functions API
+ *
+ * Operations generated as standalone functions
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, EventStreamApiResponse, ResponseError, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Pet, PetFromJSON, PetValidate } from '../models/Pet';
+
+/** Follow the changes of the pets */
+export async function watchPetsRaw(client: Client, initOverrides?: InitOverrideFunction | RequestOptions): Promise<EventStreamApiResponse<Pet>> {
+  // Build path with path parameters
+  let urlPath = `/pets/events`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    'Accept': 'text/event-stream',
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'GET',
+      headers: headerParameters,
+      query: queryParameters,
+  }, initOverrides);
+
+  return new EventStreamApiResponse(response, (jsonValue) => PetFromJSON(client.validateBody(PetValidate, jsonValue)));
+}
+
+/** Follow the changes of the pets */
+export async function* watchPets(client: Client, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Pet> {
+  yield* client.readEventStream((api) => watchPetsRaw(api, initOverrides), initOverrides);
+}
@@ -0,0 +1,4 @@
+export * from './CreatePet';
+export * from './DeletePet';
+export * from './ListPets';
+export * from './WatchPets';
\ No newline at end of file
@@ -0,0 +1,80 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Client Functions API
+ *
+ * Operations generated as standalone functions
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { validateProperty, type ValidationViolation } from '../runtime/runtime';
+
+export interface Pet {
+id?: string, name: string
+}
+
+export function instanceOfPet(value: object): value is Pet {
+    if (!('name' in value) || (value as any)['name'] === undefined) return false;
+    return true;
+}
+
+export function PetFromJSON(json: any): Pet {
+    return PetFromJSONTyped(json, false);
+}
+
+export function PetFromJSONTyped(json: any, ignoreDiscriminator: boolean): Pet {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'id': json['id'] ?? undefined,
+        'name': json['name'],
+    };
+}
+
+export function PetToJSON(value?: Pet | null): any {
+    return PetToJSONTyped(value, false);
+}
+
+export function PetToJSONTyped(value?: Pet | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'id': value['id'],
+        'name': value['name'],
+    };
+}
+
+export const PetPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+    'name': { minLength: 1 },
+};
+
+export function PetValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+    validateProperty(value['name'], PetPropertyValidationAttributesMap['name'], `${path}.name`, violations);
+}
\ No newline at end of file
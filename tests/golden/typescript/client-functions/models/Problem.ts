@@ -0,0 +1,72 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Client Functions API
+ *
+ * Operations generated as standalone functions
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Problem {
+title?: string
+}
+
+export function instanceOfProblem(value: object): value is Problem {
+    return true;
+}
+
+export function ProblemFromJSON(json: any): Problem {
+    return ProblemFromJSONTyped(json, false);
+}
+
+export function ProblemFromJSONTyped(json: any, ignoreDiscriminator: boolean): Problem {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'title': json['title'] ?? undefined,
+    };
+}
+
+export function ProblemToJSON(value?: Problem | null): any {
+    return ProblemToJSONTyped(value, false);
+}
+
+export function ProblemToJSONTyped(value?: Problem | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'title': value['title'],
+    };
+}
+
+export const ProblemPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function ProblemValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from './Pet';
+export * from './Problem';
\ No newline at end of file
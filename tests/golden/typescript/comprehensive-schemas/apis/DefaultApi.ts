@@ -11,15 +11,15 @@
  * Do not edit the class manually.
  */
 
-import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction } from '../runtime/runtime';
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
 
 /** API client for default operations */
 
 export interface DefaultApiInterface {
   /** Test endpoint */
-  testRaw: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
+  testRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
   /** Test endpoint */
-  test: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  test: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
 }
 
 export class DefaultApi extends BaseAPI implements DefaultApiInterface {
@@ -31,7 +31,7 @@ export class DefaultApi extends BaseAPI implements DefaultApiInterface {
   }
 
   /** Test endpoint */
-  async testRaw(initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async testRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/test`;
 
@@ -53,12 +53,12 @@ export class DefaultApi extends BaseAPI implements DefaultApiInterface {
         query: queryParameters,
     }, initOverrides);
 
-    return new JSONApiResponse(response);
+    return new VoidApiResponse(response);
   }
 
   /** Test endpoint */
-  async test(initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.testRaw(initOverrides);
-      return await response.value();
+  async test(initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.testRaw(initOverrides);
+    return await response.value();
   }
 }
\ No newline at end of file
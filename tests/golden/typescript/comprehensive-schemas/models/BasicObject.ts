@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Basic object with properties */
 export interface BasicObject {
 active?: boolean,
@@ -69,4 +71,17 @@ export const BasicObjectPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function BasicObjectValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['id'] === undefined) {
+        violations.push({ path: `${path}.id`, message: 'is required' });
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+}
\ No newline at end of file
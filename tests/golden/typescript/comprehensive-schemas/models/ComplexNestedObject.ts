@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 export interface ComplexNestedObject {
 id: number,
 user: { id: number; profile: { contact: { addresses: Array<{ city: string; street: string; zip: string }>; email: string; phone: string }; firstName: string; lastName: string } }
@@ -65,4 +67,17 @@ export const ComplexNestedObjectPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function ComplexNestedObjectValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['id'] === undefined) {
+        violations.push({ path: `${path}.id`, message: 'is required' });
+    }
+    if (value['user'] === undefined) {
+        violations.push({ path: `${path}.user`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Comprehensive Schema Types Example
+ *
+ * Comprehensive test for all OpenAPI v3.1.2 schema types
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface DiscriminatedCat {
+meows: boolean, petType: "DiscriminatedCat"
+}
+
+export function instanceOfDiscriminatedCat(value: object): value is DiscriminatedCat {
+    if (!('meows' in value) || (value as any)['meows'] === undefined) return false;
+    if (!('petType' in value) || (value as any)['petType'] === undefined) return false;
+    return true;
+}
+
+export function DiscriminatedCatFromJSON(json: any): DiscriminatedCat {
+    return DiscriminatedCatFromJSONTyped(json, false);
+}
+
+export function DiscriminatedCatFromJSONTyped(json: any, ignoreDiscriminator: boolean): DiscriminatedCat {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'meows': json['meows'],
+        'petType': json['petType'],
+    };
+}
+
+export function DiscriminatedCatToJSON(value?: DiscriminatedCat | null): any {
+    return DiscriminatedCatToJSONTyped(value, false);
+}
+
+export function DiscriminatedCatToJSONTyped(value?: DiscriminatedCat | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'meows': value['meows'],
+        'petType': value['petType'],
+    };
+}
+
+export const DiscriminatedCatPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function DiscriminatedCatValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['meows'] === undefined) {
+        violations.push({ path: `${path}.meows`, message: 'is required' });
+    }
+    if (value['petType'] === undefined) {
+        violations.push({ path: `${path}.petType`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Comprehensive Schema Types Example
+ *
+ * Comprehensive test for all OpenAPI v3.1.2 schema types
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface DiscriminatedDog {
+barks: boolean, petType: "dog"
+}
+
+export function instanceOfDiscriminatedDog(value: object): value is DiscriminatedDog {
+    if (!('barks' in value) || (value as any)['barks'] === undefined) return false;
+    if (!('petType' in value) || (value as any)['petType'] === undefined) return false;
+    return true;
+}
+
+export function DiscriminatedDogFromJSON(json: any): DiscriminatedDog {
+    return DiscriminatedDogFromJSONTyped(json, false);
+}
+
+export function DiscriminatedDogFromJSONTyped(json: any, ignoreDiscriminator: boolean): DiscriminatedDog {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'barks': json['barks'],
+        'petType': json['petType'],
+    };
+}
+
+export function DiscriminatedDogToJSON(value?: DiscriminatedDog | null): any {
+    return DiscriminatedDogToJSONTyped(value, false);
+}
+
+export function DiscriminatedDogToJSONTyped(value?: DiscriminatedDog | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'barks': value['barks'],
+        'petType': value['petType'],
+    };
+}
+
+export const DiscriminatedDogPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function DiscriminatedDogValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['barks'] === undefined) {
+        violations.push({ path: `${path}.barks`, message: 'is required' });
+    }
+    if (value['petType'] === undefined) {
+        violations.push({ path: `${path}.petType`, message: 'is required' });
+    }
+}
\ No newline at end of file
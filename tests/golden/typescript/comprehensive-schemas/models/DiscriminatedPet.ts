@@ -0,0 +1,76 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Comprehensive Schema Types Example
+ *
+ * Comprehensive test for all OpenAPI v3.1.2 schema types
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+import { DiscriminatedCat, DiscriminatedCatFromJSONTyped, DiscriminatedCatToJSON, instanceOfDiscriminatedCat, DiscriminatedCatValidate } from './DiscriminatedCat';
+import { DiscriminatedDog, DiscriminatedDogFromJSONTyped, DiscriminatedDogToJSON, instanceOfDiscriminatedDog, DiscriminatedDogValidate } from './DiscriminatedDog';
+
+export type DiscriminatedPet = DiscriminatedCat | DiscriminatedDog
+
+export function instanceOfDiscriminatedPet(value: object): value is DiscriminatedPet {
+    switch ((value as any)['petType']) {
+        case "DiscriminatedCat":
+            return instanceOfDiscriminatedCat(value);
+        case "dog":
+            return instanceOfDiscriminatedDog(value);
+        default:
+            return false;
+    }
+}
+
+export function DiscriminatedPetFromJSON(json: any): DiscriminatedPet {
+    return DiscriminatedPetFromJSONTyped(json, false);
+}
+
+export function DiscriminatedPetFromJSONTyped(json: any, ignoreDiscriminator: boolean): DiscriminatedPet {
+    if (json == null) {
+        return json;
+    }
+    switch (json['petType']) {
+        case "DiscriminatedCat":
+            return Object.assign({}, DiscriminatedCatFromJSONTyped(json, true), { 'petType': "DiscriminatedCat" } as const);
+        case "dog":
+            return Object.assign({}, DiscriminatedDogFromJSONTyped(json, true), { 'petType': "dog" } as const);
+        default:
+            return json;
+    }
+}
+
+export function DiscriminatedPetToJSON(value?: DiscriminatedPet | null): any {
+    return DiscriminatedPetToJSONTyped(value, false);
+}
+
+export function DiscriminatedPetToJSONTyped(value?: DiscriminatedPet | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    switch (value['petType']) {
+        case "DiscriminatedCat":
+            return Object.assign({}, DiscriminatedCatToJSON(value), { 'petType': "DiscriminatedCat" } as const);
+        case "dog":
+            return Object.assign({}, DiscriminatedDogToJSON(value), { 'petType': "dog" } as const);
+        default:
+            return value;
+    }
+}
+
+export function DiscriminatedPetValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    switch (value?.['petType']) {
+        case "DiscriminatedCat":
+            return DiscriminatedCatValidate(value, path, violations);
+        case "dog":
+            return DiscriminatedDogValidate(value, path, violations);
+        default:
+            violations.push({ path: `${path}.petType`, message: 'does not select a member of DiscriminatedPet' });
+    }
+}
\ No newline at end of file
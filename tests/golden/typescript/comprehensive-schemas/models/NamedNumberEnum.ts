@@ -0,0 +1,19 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Comprehensive Schema Types Example
+ *
+ * Comprehensive test for all OpenAPI v3.1.2 schema types
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+/** Enum with variant names from x-enum-varnames */
+export enum NamedNumberEnum {
+Ok = 200,
+NotFound = 404,
+InternalServerError = 500
+}
\ No newline at end of file
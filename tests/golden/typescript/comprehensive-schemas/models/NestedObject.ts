@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Object with nested object property */
 export interface NestedObject {
 metadata?: { created: string }, user?: { id: number; name: string }
@@ -63,4 +65,11 @@ export const NestedObjectPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function NestedObjectValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
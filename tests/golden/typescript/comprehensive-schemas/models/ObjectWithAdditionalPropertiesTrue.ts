@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Object allowing any additional properties */
 export interface ObjectWithAdditionalPropertiesTrue {
 name: string, [key: string]: any
@@ -62,4 +64,14 @@ export const ObjectWithAdditionalPropertiesTruePropertyValidationAttributesMap:
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function ObjectWithAdditionalPropertiesTrueValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Object with default values */
 export interface ObjectWithDefaults {
 active?: boolean,
@@ -67,4 +69,11 @@ export const ObjectWithDefaultsPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function ObjectWithDefaultsValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
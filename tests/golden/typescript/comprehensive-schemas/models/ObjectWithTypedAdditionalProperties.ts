@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Object with typed additional properties */
 export interface ObjectWithTypedAdditionalProperties {
 name: string,
@@ -63,4 +65,14 @@ export const ObjectWithTypedAdditionalPropertiesPropertyValidationAttributesMap:
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function ObjectWithTypedAdditionalPropertiesValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Required enum field in object */
 export interface RequiredEnumField {
 status: "active" | "inactive"
@@ -62,4 +64,14 @@ export const RequiredEnumFieldPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function RequiredEnumFieldValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['status'] === undefined) {
+        violations.push({ path: `${path}.status`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -13,7 +13,7 @@
 
 /** Enum of string values */
 export enum StringEnum {
-Active = active,
-Inactive = inactive,
-Pending = pending
+Active = "active",
+Inactive = "inactive",
+Pending = "pending"
 }
\ No newline at end of file
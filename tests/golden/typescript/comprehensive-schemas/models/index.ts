@@ -9,6 +9,9 @@ export * from './BooleanType';
 export * from './ByteString';
 export * from './ComplexNestedObject';
 export * from './DateTimeString';
+export * from './DiscriminatedCat';
+export * from './DiscriminatedDog';
+export * from './DiscriminatedPet';
 export * from './DoubleType';
 export * from './EmailString';
 export * from './EmptyObject';
@@ -16,6 +19,7 @@ export * from './FloatType';
 export * from './Integer32';
 export * from './Integer64';
 export * from './IntegerType';
+export * from './NamedNumberEnum';
 export * from './NestedArray';
 export * from './NestedObject';
 export * from './NullableString';
@@ -0,0 +1,67 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Date Types API
+ *
+ * Date and date-time values mapped to Date
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, formatDate, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Event, EventFromJSON, EventValidate } from '../models/Event';
+
+/** API client for events operations */
+
+export interface EventsApiInterface {
+  listEventsRaw: (day: Date, since?: Date, holidays?: Array<Date>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Event>>;
+  listEvents: (day: Date, since?: Date, holidays?: Array<Date>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Event>;
+}
+
+export class EventsApi extends BaseAPI implements EventsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async listEventsRaw(day: Date, since?: Date, holidays?: Array<Date>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Event>> {
+    // Build path with path parameters
+    let urlPath = `/events/${formatDate(day)}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+    if (since !== undefined) {
+      queryParameters['since'] = since.toISOString();
+    }
+    if (holidays !== undefined) {
+      queryParameters['holidays'] = holidays.map((item) => formatDate(item));
+    }
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => EventFromJSON(this.validateBody(EventValidate, jsonValue)));
+  }
+
+  async listEvents(day: Date, since?: Date, holidays?: Array<Date>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Event> {
+    const response = await this.listEventsRaw(day, since, holidays, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+export * from './EventsApi';
\ No newline at end of file
@@ -0,0 +1,92 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Date Types API
+ *
+ * Date and date-time values mapped to Date
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { formatDate, parseDate, type ValidationViolation } from '../runtime/runtime';
+
+export interface Event {
+day: Date,
+endsAt?: null | Date,
+name?: string,
+reminders?: Array<Date>,
+startsAt: Date
+}
+
+export function instanceOfEvent(value: object): value is Event {
+    if (!('day' in value) || (value as any)['day'] === undefined) return false;
+    if (!('startsAt' in value) || (value as any)['startsAt'] === undefined) return false;
+    return true;
+}
+
+export function EventFromJSON(json: any): Event {
+    return EventFromJSONTyped(json, false);
+}
+
+export function EventFromJSONTyped(json: any, ignoreDiscriminator: boolean): Event {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'day': json['day'] == null ? json['day'] : parseDate(json['day']),
+        'endsAt': json['endsAt'] == null ? json['endsAt'] : new Date(json['endsAt']),
+        'name': json['name'] ?? undefined,
+        'reminders': json['reminders'] == null ? undefined : (json['reminders'] as any[]).map((item) => new Date(item)),
+        'startsAt': json['startsAt'] == null ? json['startsAt'] : new Date(json['startsAt']),
+    };
+}
+
+export function EventToJSON(value?: Event | null): any {
+    return EventToJSONTyped(value, false);
+}
+
+export function EventToJSONTyped(value?: Event | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'day': value['day'] == null ? value['day'] : formatDate(value['day']),
+        'endsAt': value['endsAt'] == null ? value['endsAt'] : value['endsAt'].toISOString(),
+        'name': value['name'],
+        'reminders': value['reminders'] == null ? value['reminders'] : value['reminders'].map((item) => item.toISOString()),
+        'startsAt': value['startsAt'] == null ? value['startsAt'] : value['startsAt'].toISOString(),
+    };
+}
+
+export const EventPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function EventValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['day'] === undefined) {
+        violations.push({ path: `${path}.day`, message: 'is required' });
+    }
+    if (value['startsAt'] === undefined) {
+        violations.push({ path: `${path}.startsAt`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+export * from './Event';
\ No newline at end of file
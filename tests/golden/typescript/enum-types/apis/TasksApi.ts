@@ -0,0 +1,61 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Enum Types API
+ *
+ * String, numeric, boolean and mixed enums
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Task, TaskFromJSON, TaskValidate } from '../models/Task';
+
+/** API client for tasks operations */
+
+export interface TasksApiInterface {
+  getTaskRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Task>>;
+  getTask: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Task>;
+}
+
+export class TasksApi extends BaseAPI implements TasksApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async getTaskRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Task>> {
+    // Build path with path parameters
+    let urlPath = `/tasks/${id}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => TaskFromJSON(this.validateBody(TaskValidate, jsonValue)));
+  }
+
+  async getTask(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Task> {
+    const response = await this.getTaskRaw(id, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+export * from './TasksApi';
\ No newline at end of file
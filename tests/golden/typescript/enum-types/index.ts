@@ -0,0 +1,5 @@
+export * from './runtime/api';
+export * from './runtime/config';
+export * from './runtime/core';
+export * from './apis';
+export * from './models';
\ No newline at end of file
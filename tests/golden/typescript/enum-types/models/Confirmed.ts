@@ -0,0 +1,15 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Enum Types API
+ *
+ * String, numeric, boolean and mixed enums
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+/** Only ever true once set */
+export type Confirmed = true
\ No newline at end of file
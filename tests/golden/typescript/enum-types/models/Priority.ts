@@ -0,0 +1,18 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Enum Types API
+ *
+ * String, numeric, boolean and mixed enums
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+export enum Priority {
+_1 = 1,
+_2 = 2,
+_3 = 3
+}
\ No newline at end of file
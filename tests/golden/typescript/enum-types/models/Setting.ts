@@ -0,0 +1,15 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Enum Types API
+ *
+ * String, numeric, boolean and mixed enums
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+/** A mode name, a level or a switch */
+export type Setting = "auto" | 1 | false
\ No newline at end of file
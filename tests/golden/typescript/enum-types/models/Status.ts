@@ -0,0 +1,17 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Enum Types API
+ *
+ * String, numeric, boolean and mixed enums
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+/** Lifecycle of a task */
+export enum Status {
+Open = "open", Done = "done"
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Enum Types API
+ *
+ * String, numeric, boolean and mixed enums
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+import { Confirmed } from './Confirmed';
+import { Priority } from './Priority';
+import { Setting } from './Setting';
+import { Status } from './Status';
+
+export interface Task {
+confirmed?: Confirmed,
+priority?: Priority,
+setting?: Setting,
+status: Status
+}
+
+export function instanceOfTask(value: object): value is Task {
+    if (!('status' in value) || (value as any)['status'] === undefined) return false;
+    return true;
+}
+
+export function TaskFromJSON(json: any): Task {
+    return TaskFromJSONTyped(json, false);
+}
+
+export function TaskFromJSONTyped(json: any, ignoreDiscriminator: boolean): Task {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'confirmed': json['confirmed'] ?? undefined,
+        'priority': json['priority'] ?? undefined,
+        'setting': json['setting'] ?? undefined,
+        'status': json['status'],
+    };
+}
+
+export function TaskToJSON(value?: Task | null): any {
+    return TaskToJSONTyped(value, false);
+}
+
+export function TaskToJSONTyped(value?: Task | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'confirmed': value['confirmed'],
+        'priority': value['priority'],
+        'setting': value['setting'],
+        'status': value['status'],
+    };
+}
+
+export const TaskPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function TaskValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['status'] === undefined) {
+        violations.push({ path: `${path}.status`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+export * from './Confirmed';
+export * from './Priority';
+export * from './Setting';
+export * from './Status';
+export * from './Task';
\ No newline at end of file
@@ -0,0 +1,109 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Event Streams API
+ *
+ * Operations streaming Server-Sent Events and newline-delimited JSON
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, EventStreamApiResponse, JsonLinesApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Build, BuildFromJSON, BuildValidate } from '../models/Build';
+import { type BuildEvent, BuildEventFromJSON, BuildEventValidate } from '../models/BuildEvent';
+import { type Exportrequest, ExportrequestToJSON, ExportrequestValidate } from '../models/Exportrequest';
+
+/** API client for builds operations */
+
+export interface BuildsApiInterface {
+  /** Export every build, one per line */
+  exportBuildsRaw: (body: Exportrequest, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JsonLinesApiResponse<Build>>;
+  /** Export every build, one per line */
+  exportBuilds: (body: Exportrequest, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<Build>;
+  /** Follow the progress of a build */
+  watchBuildRaw: (buildId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<EventStreamApiResponse<BuildEvent>>;
+  /** Follow the progress of a build */
+  watchBuild: (buildId: string, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<BuildEvent>;
+}
+
+export class BuildsApi extends BaseAPI implements BuildsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** Export every build, one per line */
+  async exportBuildsRaw(body: Exportrequest, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JsonLinesApiResponse<Build>> {
+    // Build path with path parameters
+    let urlPath = `/builds/export`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Content-Type': 'application/json',
+      'Accept': 'application/x-ndjson',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = this.validateBody(ExportrequestValidate, ExportrequestToJSON(body));
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new JsonLinesApiResponse(response, (jsonValue) => BuildFromJSON(this.validateBody(BuildValidate, jsonValue)));
+  }
+
+  /** Export every build, one per line */
+  async *exportBuilds(body: Exportrequest, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Build> {
+    const response = await this.exportBuildsRaw(body, initOverrides);
+    yield* response.value();
+  }
+
+  /** Follow the progress of a build */
+  async watchBuildRaw(buildId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<EventStreamApiResponse<BuildEvent>> {
+    // Build path with path parameters
+    let urlPath = `/builds/${buildId}/events`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Accept': 'text/event-stream',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new EventStreamApiResponse(response, (jsonValue) => BuildEventFromJSON(this.validateBody(BuildEventValidate, jsonValue)));
+  }
+
+  /** Follow the progress of a build */
+  async *watchBuild(buildId: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<BuildEvent> {
+    yield* this.readEventStream((api) => api.watchBuildRaw(buildId, initOverrides), initOverrides);
+  }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Event Streams API
+ *
+ * Operations streaming Server-Sent Events and newline-delimited JSON
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, EventStreamApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+
+/** API client for logs operations */
+
+export interface LogsApiInterface {
+  /** Tail the raw log lines */
+  tailLogsRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<EventStreamApiResponse<string>>;
+  /** Tail the raw log lines */
+  tailLogs: (initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<string>;
+}
+
+export class LogsApi extends BaseAPI implements LogsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** Tail the raw log lines */
+  async tailLogsRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<EventStreamApiResponse<string>> {
+    // Build path with path parameters
+    let urlPath = `/logs`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Accept': 'text/event-stream',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new EventStreamApiResponse(response);
+  }
+
+  /** Tail the raw log lines */
+  async *tailLogs(initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<string> {
+    yield* this.readEventStream((api) => api.tailLogsRaw(initOverrides), initOverrides);
+  }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from './BuildsApi';
+export * from './LogsApi';
\ No newline at end of file
@@ -0,0 +1,78 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Event Streams API
+ *
+ * Operations streaming Server-Sent Events and newline-delimited JSON
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Build {
+finishedAt?: string, id: string
+}
+
+export function instanceOfBuild(value: object): value is Build {
+    if (!('id' in value) || (value as any)['id'] === undefined) return false;
+    return true;
+}
+
+export function BuildFromJSON(json: any): Build {
+    return BuildFromJSONTyped(json, false);
+}
+
+export function BuildFromJSONTyped(json: any, ignoreDiscriminator: boolean): Build {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'finishedAt': json['finishedAt'] ?? undefined,
+        'id': json['id'],
+    };
+}
+
+export function BuildToJSON(value?: Build | null): any {
+    return BuildToJSONTyped(value, false);
+}
+
+export function BuildToJSONTyped(value?: Build | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'finishedAt': value['finishedAt'],
+        'id': value['id'],
+    };
+}
+
+export const BuildPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function BuildValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['id'] === undefined) {
+        violations.push({ path: `${path}.id`, message: 'is required' });
+    }
+}
\ No newline at end of file
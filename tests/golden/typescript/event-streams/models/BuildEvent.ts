@@ -0,0 +1,78 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Event Streams API
+ *
+ * Operations streaming Server-Sent Events and newline-delimited JSON
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface BuildEvent {
+progress?: number, status: string
+}
+
+export function instanceOfBuildEvent(value: object): value is BuildEvent {
+    if (!('status' in value) || (value as any)['status'] === undefined) return false;
+    return true;
+}
+
+export function BuildEventFromJSON(json: any): BuildEvent {
+    return BuildEventFromJSONTyped(json, false);
+}
+
+export function BuildEventFromJSONTyped(json: any, ignoreDiscriminator: boolean): BuildEvent {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'progress': json['progress'] ?? undefined,
+        'status': json['status'],
+    };
+}
+
+export function BuildEventToJSON(value?: BuildEvent | null): any {
+    return BuildEventToJSONTyped(value, false);
+}
+
+export function BuildEventToJSONTyped(value?: BuildEvent | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'progress': value['progress'],
+        'status': value['status'],
+    };
+}
+
+export const BuildEventPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function BuildEventValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['status'] === undefined) {
+        violations.push({ path: `${path}.status`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Event Streams API
+ *
+ * Operations streaming Server-Sent Events and newline-delimited JSON
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface ExportRequest {
+since?: string
+}
+
+export function instanceOfExportRequest(value: object): value is ExportRequest {
+    return true;
+}
+
+export function ExportRequestFromJSON(json: any): ExportRequest {
+    return ExportRequestFromJSONTyped(json, false);
+}
+
+export function ExportRequestFromJSONTyped(json: any, ignoreDiscriminator: boolean): ExportRequest {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'since': json['since'] ?? undefined,
+    };
+}
+
+export function ExportRequestToJSON(value?: ExportRequest | null): any {
+    return ExportRequestToJSONTyped(value, false);
+}
+
+export function ExportRequestToJSONTyped(value?: ExportRequest | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'since': value['since'],
+    };
+}
+
+export const ExportRequestPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function ExportRequestValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
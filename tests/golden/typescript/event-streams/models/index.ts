@@ -0,0 +1,3 @@
+export * from './Build';
+export * from './BuildEvent';
+export * from './ExportRequest';
\ No newline at end of file
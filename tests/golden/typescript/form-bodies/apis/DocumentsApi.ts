@@ -0,0 +1,173 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Form Bodies API
+ *
+ * Multipart and URL-encoded request bodies
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+
+/** API client for documents operations */
+
+export interface DocumentsApiInterface {
+  uploadDocumentsRaw: (files: Array<Blob>, title: string, labels?: Array<string>, metadata?: object, pageCount?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  uploadDocuments: (files: Array<Blob>, title: string, labels?: Array<string>, metadata?: object, pageCount?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  uploadRawFormRaw: (body: FormData, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  uploadRawForm: (body: FormData, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  searchDocumentsRaw: (authors?: Array<string>, filter?: object, query?: string, tags?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  searchDocuments: (authors?: Array<string>, filter?: object, query?: string, tags?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+}
+
+export class DocumentsApi extends BaseAPI implements DocumentsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async uploadDocumentsRaw(files: Array<Blob>, title: string, labels?: Array<string>, metadata?: object, pageCount?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/documents`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const formParams = new FormData();
+    if (files !== undefined) {
+      for (const item of files) {
+        formParams.append('files', item);
+      }
+    }
+    if (labels !== undefined) {
+      for (const item of labels) {
+        formParams.append('labels', String(item));
+      }
+    }
+    if (metadata !== undefined) {
+      formParams.append('metadata', new Blob([JSON.stringify(metadata)], { type: 'application/json' }));
+    }
+    if (pageCount !== undefined) {
+      formParams.append('pageCount', String(pageCount));
+    }
+    if (title !== undefined) {
+      formParams.append('title', String(title));
+    }
+    const requestBody = formParams;
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async uploadDocuments(files: Array<Blob>, title: string, labels?: Array<string>, metadata?: object, pageCount?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.uploadDocumentsRaw(files, title, labels, metadata, pageCount, initOverrides);
+    return await response.value();
+  }
+
+  async uploadRawFormRaw(body: FormData, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/documents/raw`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = body;
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async uploadRawForm(body: FormData, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.uploadRawFormRaw(body, initOverrides);
+    return await response.value();
+  }
+
+  async searchDocumentsRaw(authors?: Array<string>, filter?: object, query?: string, tags?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/documents/search`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const formParams = new URLSearchParams();
+    if (authors !== undefined) {
+      formParams.append('authors', authors.join('|'));
+    }
+    if (filter !== undefined) {
+      for (const [key, item] of Object.entries(filter)) {
+        formParams.append(`filter[${key}]`, String(item));
+      }
+    }
+    if (query !== undefined) {
+      formParams.append('query', String(query));
+    }
+    if (tags !== undefined) {
+      for (const item of tags) {
+        formParams.append('tags', String(item));
+      }
+    }
+    const requestBody = formParams;
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async searchDocuments(authors?: Array<string>, filter?: object, query?: string, tags?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.searchDocumentsRaw(authors, filter, query, tags, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
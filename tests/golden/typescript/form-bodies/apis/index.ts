@@ -0,0 +1 @@
+export * from './DocumentsApi';
\ No newline at end of file
@@ -0,0 +1,72 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Form Bodies API
+ *
+ * Multipart and URL-encoded request bodies
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface DocumentForm {
+file?: string
+}
+
+export function instanceOfDocumentForm(value: object): value is DocumentForm {
+    return true;
+}
+
+export function DocumentFormFromJSON(json: any): DocumentForm {
+    return DocumentFormFromJSONTyped(json, false);
+}
+
+export function DocumentFormFromJSONTyped(json: any, ignoreDiscriminator: boolean): DocumentForm {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'file': json['file'] ?? undefined,
+    };
+}
+
+export function DocumentFormToJSON(value?: DocumentForm | null): any {
+    return DocumentFormToJSONTyped(value, false);
+}
+
+export function DocumentFormToJSONTyped(value?: DocumentForm | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'file': value['file'],
+    };
+}
+
+export const DocumentFormPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function DocumentFormValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
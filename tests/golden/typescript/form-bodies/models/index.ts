@@ -0,0 +1 @@
+export * from './DocumentForm';
\ No newline at end of file
@@ -0,0 +1,181 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Idempotent Operations API
+ *
+ * Operations overriding whether they may be retried with x-idempotent
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, validateItems, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Job, JobFromJSON, JobValidate } from '../models/Job';
+import { type JobInput, JobInputToJSON, JobInputValidate } from '../models/JobInput';
+
+/** API client for jobs operations */
+
+export interface JobsApiInterface {
+  /** List jobs, retried as a GET */
+  listJobsRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Job>>>;
+  /** List jobs, retried as a GET */
+  listJobs: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Job>>;
+  /** Submit a job, deduplicated by its name */
+  submitJobRaw: (body: JobInput, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Job>>;
+  /** Submit a job, deduplicated by its name */
+  submitJob: (body: JobInput, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Job>;
+  /** Take the next task of a job, which must not be retried */
+  takeNextTaskRaw: (jobId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<string>>;
+  /** Take the next task of a job, which must not be retried */
+  takeNextTask: (jobId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<string>;
+  /** Clear the tasks of a job */
+  clearTasksRaw: (jobId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  /** Clear the tasks of a job */
+  clearTasks: (jobId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+}
+
+export class JobsApi extends BaseAPI implements JobsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** List jobs, retried as a GET */
+  async listJobsRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Job>>> {
+    // Build path with path parameters
+    let urlPath = `/jobs`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(JobValidate), jsonValue) as Array<any>).map(JobFromJSON));
+  }
+
+  /** List jobs, retried as a GET */
+  async listJobs(initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Job>> {
+    const response = await this.listJobsRaw(initOverrides);
+    return await response.value();
+  }
+
+  /** Submit a job, deduplicated by its name */
+  async submitJobRaw(body: JobInput, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Job>> {
+    // Build path with path parameters
+    let urlPath = `/jobs`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Content-Type': 'application/json',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = this.validateBody(JobInputValidate, JobInputToJSON(body));
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+        idempotent: true,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => JobFromJSON(this.validateBody(JobValidate, jsonValue)));
+  }
+
+  /** Submit a job, deduplicated by its name */
+  async submitJob(body: JobInput, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Job> {
+    const response = await this.submitJobRaw(body, initOverrides);
+    return await response.value();
+  }
+
+  /** Take the next task of a job, which must not be retried */
+  async takeNextTaskRaw(jobId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<string>> {
+    // Build path with path parameters
+    let urlPath = `/jobs/${jobId}/next`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+        idempotent: false,
+    }, initOverrides);
+
+    return new JSONApiResponse(response);
+  }
+
+  /** Take the next task of a job, which must not be retried */
+  async takeNextTask(jobId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<string> {
+    const response = await this.takeNextTaskRaw(jobId, initOverrides);
+    return await response.value();
+  }
+
+  /** Clear the tasks of a job */
+  async clearTasksRaw(jobId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/jobs/${jobId}/next`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'DELETE',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  /** Clear the tasks of a job */
+  async clearTasks(jobId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.clearTasksRaw(jobId, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
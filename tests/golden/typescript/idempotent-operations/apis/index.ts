@@ -0,0 +1 @@
+export * from './JobsApi';
\ No newline at end of file
@@ -0,0 +1,76 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Idempotent Operations API
+ *
+ * Operations overriding whether they may be retried with x-idempotent
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface JobInput {
+name: string
+}
+
+export function instanceOfJobInput(value: object): value is JobInput {
+    if (!('name' in value) || (value as any)['name'] === undefined) return false;
+    return true;
+}
+
+export function JobInputFromJSON(json: any): JobInput {
+    return JobInputFromJSONTyped(json, false);
+}
+
+export function JobInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): JobInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'name': json['name'],
+    };
+}
+
+export function JobInputToJSON(value?: JobInput | null): any {
+    return JobInputToJSONTyped(value, false);
+}
+
+export function JobInputToJSONTyped(value?: JobInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'name': value['name'],
+    };
+}
+
+export const JobInputPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function JobInputValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from './Job';
+export * from './JobInput';
\ No newline at end of file
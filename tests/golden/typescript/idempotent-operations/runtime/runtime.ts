@@ -0,0 +1,1315 @@
+// DO NOT EDIT - This file is automatically generated.
+// Any manual changes will be overwritten on the next generation.
+// To make changes, modify the source code and regenerate this file.
+
+export const BASE_PATH = "https://jobs.example.com".replace(/\/+$/, "");
+
+/**
+ * A server the API is available on, whose URL may contain `{variable}` placeholders
+ */
+export class ServerConfiguration<V extends { [name: string]: string } = {}> {
+    constructor(public readonly template: string, public readonly description?: string, public readonly defaults: V = {} as V) {}
+
+    /**
+     * URL of the server, using the defaults for variables that are not given
+     */
+    url(variables: Partial<V> = {}): string {
+        const values: { [name: string]: string | undefined } = { ...this.defaults, ...variables };
+        return this.template
+            .replace(/\{([^}]+)\}/g, (placeholder, name: string) => values[name] ?? placeholder)
+            .replace(/\/+$/, '');
+    }
+}
+
+/**
+ * Servers of the API, in the order of the specification
+ */
+export const servers = [
+    new ServerConfiguration("https://jobs.example.com"),
+] as const;
+
+export interface ConfigurationParameters {
+    basePath?: string; // override base path
+    fetchApi?: FetchAPI; // override for fetch implementation
+    middleware?: Middleware[]; // middleware to apply before/after fetch requests
+    queryParamsStringify?: (params: HTTPQuery) => string; // stringify function for query strings
+    username?: string; // parameter for basic security
+    password?: string; // parameter for basic security
+    apiKey?: string | Promise<string> | ((name: string) => string | Promise<string>); // parameter for apiKey security
+    accessToken?: string | Promise<string> | ((name?: string, scopes?: string[]) => string | Promise<string>); // parameter for oauth2 security
+    headers?: HTTPHeaders; //header params we want to use on every request
+    credentials?: RequestCredentials; //value for the credentials param we want to use on each request
+    retry?: Partial<RetryPolicy>; // retry failed requests, with the defaults of `DEFAULT_RETRY_POLICY` for unset fields
+    timeout?: number; // milliseconds to wait for the response headers of each attempt
+    validateModels?: boolean; // check request and response bodies against the constraints of their models
+}
+
+export class Configuration {
+    constructor(private configuration: ConfigurationParameters = {}) {}
+
+    set config(configuration: Configuration) {
+        this.configuration = configuration;
+    }
+
+    get basePath(): string {
+        return this.configuration.basePath != null ? this.configuration.basePath : BASE_PATH;
+    }
+
+    /** Whether a base path was configured, which then takes precedence over the servers of operations */
+    get hasBasePath(): boolean {
+        return this.configuration.basePath != null;
+    }
+
+    get fetchApi(): FetchAPI | undefined {
+        return this.configuration.fetchApi;
+    }
+
+    get middleware(): Middleware[] {
+        return this.configuration.middleware || [];
+    }
+
+    get queryParamsStringify(): (params: HTTPQuery) => string {
+        return this.configuration.queryParamsStringify || querystring;
+    }
+
+    get username(): string | undefined {
+        return this.configuration.username;
+    }
+
+    get password(): string | undefined {
+        return this.configuration.password;
+    }
+
+    get apiKey(): ((name: string) => string | Promise<string>) | undefined {
+        const apiKey = this.configuration.apiKey;
+        if (apiKey) {
+            return typeof apiKey === 'function' ? apiKey : () => apiKey;
+        }
+        return undefined;
+    }
+
+    get accessToken(): ((name?: string, scopes?: string[]) => string | Promise<string>) | undefined {
+        const accessToken = this.configuration.accessToken;
+        if (accessToken) {
+            return typeof accessToken === 'function' ? accessToken : async () => accessToken;
+        }
+        return undefined;
+    }
+
+    get headers(): HTTPHeaders | undefined {
+        return this.configuration.headers;
+    }
+
+    get credentials(): RequestCredentials | undefined {
+        return this.configuration.credentials;
+    }
+
+    get retry(): RetryPolicy | undefined {
+        return this.configuration.retry && { ...DEFAULT_RETRY_POLICY, ...this.configuration.retry };
+    }
+
+    get timeout(): number | undefined {
+        return this.configuration.timeout;
+    }
+
+    get validateModels(): boolean {
+        return this.configuration.validateModels ?? false;
+    }
+}
+
+export const DefaultConfig = new Configuration();
+
+/**
+ * How failed requests are retried, with exponential backoff between attempts
+ */
+export interface RetryPolicy {
+    /** Attempts made in total, including the first one */
+    maxAttempts: number;
+    /** Milliseconds to wait before the first retry */
+    initialDelay: number;
+    /** Upper bound of the wait between attempts, in milliseconds */
+    maxDelay: number;
+    /** Factor the wait grows by after each attempt */
+    multiplier: number;
+    /** Wait a random time up to the backoff delay, so that clients do not retry in lockstep */
+    jitter: boolean;
+    /** Response statuses that are retried; network errors and timeouts always are */
+    retryableStatuses: number[];
+    /** Wait as long as the `Retry-After` header of a response asks, up to `maxDelay` */
+    respectRetryAfter: boolean;
+}
+
+export const DEFAULT_RETRY_POLICY: RetryPolicy = {
+    maxAttempts: 3,
+    initialDelay: 500,
+    maxDelay: 30000,
+    multiplier: 2,
+    jitter: true,
+    retryableStatuses: [408, 425, 429, 500, 502, 503, 504],
+    respectRetryAfter: true,
+};
+
+/**
+ * Methods that are retried unless their operation sets `x-idempotent: false`;
+ * other operations opt in with `x-idempotent: true`
+ */
+const IDEMPOTENT_METHODS: ReadonlySet<HTTPMethod> = new Set<HTTPMethod>(['GET', 'HEAD', 'OPTIONS', 'PUT', 'DELETE']);
+
+/**
+ * Options of a single request, on top of the `fetch` ones
+ */
+export interface RequestOptions extends RequestInit {
+    /** Milliseconds to wait for the response headers of each attempt, overriding the configuration */
+    timeout?: number;
+    /** Retry policy overriding the configured one, or `false` to make a single attempt */
+    retry?: Partial<RetryPolicy> | false;
+    /**
+     * Index of the server to send the request to, among the servers of the
+     * operation or else those of the API, overriding the configured base path
+     */
+    server?: number;
+    /** Values of the variables of the chosen server's URL, defaulting to those of the specification */
+    serverVariables?: { [name: string]: string };
+}
+
+/**
+ * How a security scheme applies credentials; OAuth2 and OpenID Connect send bearer tokens
+ */
+export type SecurityScheme =
+    | { type: 'apiKey'; in: 'header' | 'query' | 'cookie'; name: string }
+    | { type: 'basic' }
+    | { type: 'bearer' };
+
+/**
+ * Security schemes of the API, keyed by name
+ */
+export const SECURITY_SCHEMES: { [name: string]: SecurityScheme } = {
+};
+
+/**
+ * Security scheme required by an operation, with the scopes it needs
+ */
+export interface SecurityRequirement {
+    scheme: string;
+    scopes: string[];
+}
+
+/**
+ * Checks a JSON response body before it is converted, as the API it was
+ * received by is configured to
+ */
+export type ResponseCheck = (value: any, validate: ModelValidator) => any;
+
+/**
+ * This is the base class for all generated API classes.
+ */
+export class BaseAPI {
+
+    private static readonly jsonRegex = new RegExp('^(:?application\/json|[^;/ \t]+\/[^;/ \t]+[+]json)[ \t]*(:?;.*)?$', 'i');
+    private middleware: Middleware[];
+
+    constructor(protected configuration = DefaultConfig) {
+        this.middleware = configuration.middleware;
+    }
+
+    withMiddleware<T extends BaseAPI>(this: T, ...middlewares: Middleware[]) {
+        const next = this.clone<T>();
+        next.middleware = next.middleware.concat(...middlewares);
+        return next;
+    }
+
+    withPreMiddleware<T extends BaseAPI>(this: T, ...preMiddlewares: Array<Middleware['pre']>) {
+        const middlewares = preMiddlewares.map((pre) => ({ pre }));
+        return this.withMiddleware<T>(...middlewares);
+    }
+
+    withPostMiddleware<T extends BaseAPI>(this: T, ...postMiddlewares: Array<Middleware['post']>) {
+        const middlewares = postMiddlewares.map((post) => ({ post }));
+        return this.withMiddleware<T>(...middlewares);
+    }
+
+    /**
+     * Check if the given MIME is a JSON MIME.
+     * JSON MIME examples:
+     *   application/json
+     *   application/json; charset=UTF8
+     *   APPLICATION/JSON
+     *   application/vnd.company+json
+     * @param mime - MIME (Multipurpose Internet Mail Extensions)
+     * @return True if the given MIME is JSON, false otherwise.
+     */
+    protected isJsonMime(mime: string | null | undefined): boolean {
+        if (!mime) {
+            return false;
+        }
+        return BaseAPI.jsonRegex.test(mime);
+    }
+
+    /**
+     * Check a request or response body against its model when model validation is enabled,
+     * throwing a `ValidationError` listing every violation
+     */
+    protected validateBody<T>(validate: ModelValidator, value: T): T {
+        if (this.configuration.validateModels && value != null) {
+            const violations: ValidationViolation[] = [];
+            validate(value, '$', violations);
+            if (violations.length > 0) {
+                throw new ValidationError(violations);
+            }
+        }
+        return value;
+    }
+
+    /**
+     * Check a JSON response body the way the transformers of `JSONApiResponse` do,
+     * for the bodies of status-keyed results
+     */
+    protected checkResponse(value: any, validate: ModelValidator): any {
+        return this.validateBody(validate, value);
+    }
+
+    /**
+     * Iterate the values of an event stream opened by the given Raw call. When the
+     * stream ends or its connection fails, it is reopened after the delay the server
+     * asked for, sending the `Last-Event-ID` of the last event received; it stops when
+     * aborted, on an error response, or when the server answers 204 No Content.
+     */
+    protected async *readEventStream<T>(open: (api: this) => Promise<EventStreamApiResponse<T>>, initOverrides?: RequestOptions | InitOverrideFunction): AsyncGenerator<T> {
+        const signal = typeof initOverrides === 'function' ? undefined : initOverrides?.signal ?? undefined;
+        let lastEventId: string | undefined;
+        let delay = 3000;
+        for (;;) {
+            const eventId = lastEventId;
+            const api = eventId ? this.withPreMiddleware(async ({ url, init }) => {
+                const headers = new Headers(init.headers);
+                headers.set('Last-Event-ID', eventId);
+                return { url, init: { ...init, headers } };
+            }) : this;
+            let response: EventStreamApiResponse<T>;
+            try {
+                response = await open(api);
+            } catch (e) {
+                if (!(e instanceof FetchError || e instanceof TimeoutError) || signal?.aborted) {
+                    throw e;
+                }
+                await sleep(delay, signal);
+                continue;
+            }
+            if (response.raw.status === 204) {
+                return;
+            }
+            const events = response.events();
+            try {
+                for (;;) {
+                    let next: IteratorResult<ServerSentEvent>;
+                    try {
+                        next = await events.next();
+                    } catch (e) {
+                        if (signal?.aborted) {
+                            throw e;
+                        }
+                        break;
+                    }
+                    if (next.done) {
+                        break;
+                    }
+                    lastEventId = next.value.id;
+                    delay = next.value.retry ?? delay;
+                    yield response.parse(next.value);
+                }
+            } finally {
+                await events.return(undefined);
+            }
+            await sleep(delay, signal);
+        }
+    }
+
+    protected async request(context: RequestOpts, initOverrides?: RequestOptions | InitOverrideFunction, errorFactory?: ErrorFactory): Promise<Response> {
+        const options = typeof initOverrides === 'function' ? {} : initOverrides ?? {};
+        const timeout = options.timeout ?? this.configuration.timeout;
+        const retry = this.retryPolicy(context, options);
+        for (let attempt = 1; ; attempt++) {
+            const { url, init } = await this.createFetchParams(context, initOverrides);
+            const callerSignal = init.signal ?? undefined;
+            let response: Response;
+            try {
+                response = await this.fetchWithTimeout(url, init, timeout);
+            } catch (e) {
+                const retryable = e instanceof TimeoutError || e instanceof FetchError;
+                if (!retry || attempt >= retry.maxAttempts || !retryable || callerSignal?.aborted) {
+                    throw e;
+                }
+                await sleep(backoffDelay(retry, attempt), callerSignal);
+                continue;
+            }
+            if (response && (response.status >= 200 && response.status < 300)) {
+                return response;
+            }
+            if (retry && attempt < retry.maxAttempts && retry.retryableStatuses.includes(response.status)) {
+                const retryAfter = retry.respectRetryAfter ? parseRetryAfter(response.headers.get('Retry-After')) : undefined;
+                await response.body?.cancel();
+                await sleep(retryAfter !== undefined ? Math.min(retryAfter, retry.maxDelay) : backoffDelay(retry, attempt), callerSignal);
+                continue;
+            }
+            if (response && errorFactory) {
+                throw await errorFactory(response);
+            }
+            throw new ResponseError(response, 'Response returned an error code');
+        }
+    }
+
+    /**
+     * Retry policy of a request, `undefined` when it is made only once: retries
+     * are disabled, or the operation is not idempotent
+     */
+    private retryPolicy(context: RequestOpts, options: RequestOptions): RetryPolicy | undefined {
+        if (options.retry === false || !(context.idempotent ?? IDEMPOTENT_METHODS.has(context.method))) {
+            return undefined;
+        }
+        const configured = this.configuration.retry;
+        if (options.retry === undefined) {
+            return configured;
+        }
+        return { ...DEFAULT_RETRY_POLICY, ...configured, ...options.retry };
+    }
+
+    /**
+     * Fetch, aborting with a `TimeoutError` when the response headers do not
+     * arrive in time. The caller's signal still aborts the request.
+     */
+    private async fetchWithTimeout(url: string, init: RequestInit, timeout: number | undefined): Promise<Response> {
+        if (timeout === undefined) {
+            return this.fetchApi(url, init);
+        }
+        const controller = new AbortController();
+        const callerSignal = init.signal ?? undefined;
+        const abort = () => controller.abort(callerSignal?.reason);
+        if (callerSignal?.aborted) {
+            abort();
+        }
+        callerSignal?.addEventListener('abort', abort);
+        let timedOut = false;
+        const timer = setTimeout(() => {
+            timedOut = true;
+            controller.abort();
+        }, timeout);
+        try {
+            return await this.fetchApi(url, { ...init, signal: controller.signal });
+        } catch (e) {
+            throw timedOut ? new TimeoutError(timeout) : e;
+        } finally {
+            clearTimeout(timer);
+            callerSignal?.removeEventListener('abort', abort);
+        }
+    }
+
+    /**
+     * Add the credentials of the first security requirement the configuration
+     * can satisfy. Requirements without schemes, which make authentication
+     * optional, never add any.
+     */
+    private async applySecurity(context: RequestOpts): Promise<RequestOpts> {
+        const requirement = context.security?.find(schemes =>
+            schemes.length > 0 && schemes.every(({ scheme }) => this.hasCredentials(SECURITY_SCHEMES[scheme])));
+        if (requirement === undefined) {
+            return context;
+        }
+
+        const headers: HTTPHeaders = { ...context.headers };
+        const query: HTTPQuery = { ...context.query };
+        const cookies = [...(context.cookies ?? [])];
+        for (const { scheme, scopes } of requirement) {
+            const definition = SECURITY_SCHEMES[scheme];
+            switch (definition.type) {
+                case 'apiKey': {
+                    const key = await this.configuration.apiKey!(definition.name);
+                    if (definition.in === 'header') {
+                        headers[definition.name] = key;
+                    } else if (definition.in === 'query') {
+                        query[definition.name] = key;
+                    } else {
+                        cookies.push(`${definition.name}=${encodeURIComponent(key)}`);
+                    }
+                    break;
+                }
+                case 'basic': {
+                    const { username = '', password = '' } = this.configuration;
+                    headers['Authorization'] = `Basic ${btoa(`${username}:${password}`)}`;
+                    break;
+                }
+                case 'bearer':
+                    headers['Authorization'] = `Bearer ${await this.configuration.accessToken!(scheme, scopes)}`;
+                    break;
+            }
+        }
+        return { ...context, headers, query, cookies };
+    }
+
+    private hasCredentials(definition: SecurityScheme | undefined): boolean {
+        switch (definition?.type) {
+            case 'apiKey':
+                return this.configuration.apiKey !== undefined;
+            case 'basic':
+                return this.configuration.username !== undefined || this.configuration.password !== undefined;
+            case 'bearer':
+                return this.configuration.accessToken !== undefined;
+            default:
+                return false;
+        }
+    }
+
+    /**
+     * Base URL of a request: the server chosen in the request options, else the
+     * configured base path, else the first server of the operation, else `BASE_PATH`
+     */
+    private basePath(context: RequestOpts, initOverrides?: RequestOptions | InitOverrideFunction): string {
+        const options = typeof initOverrides === 'function' ? {} : initOverrides ?? {};
+        if (options.server !== undefined || options.serverVariables !== undefined) {
+            const candidates: readonly ServerConfiguration<any>[] = context.servers ?? servers;
+            const server = candidates[options.server ?? 0];
+            if (server === undefined) {
+                throw new RangeError(`No server at index ${options.server ?? 0}`);
+            }
+            return server.url(options.serverVariables);
+        }
+        if (this.configuration.hasBasePath || !context.servers?.length) {
+            return this.configuration.basePath;
+        }
+        return context.servers[0].url();
+    }
+
+    private async createFetchParams(context: RequestOpts, initOverrides?: RequestOptions | InitOverrideFunction) {
+        context = await this.applySecurity(context);
+        let url = this.basePath(context, initOverrides) + context.path;
+        if (context.query !== undefined && Object.keys(context.query).length !== 0) {
+            // only add the querystring to the URL if there are query parameters.
+            // this is done to avoid urls ending with a "?" character which buggy webservers
+            // do not handle correctly sometimes.
+            url += '?' + this.configuration.queryParamsStringify(context.query);
+        }
+
+        const headers = Object.assign({}, this.configuration.headers, context.headers);
+        Object.keys(headers).forEach(key => headers[key] === undefined ? delete headers[key] : {});
+        if (context.cookies !== undefined && context.cookies.length > 0) {
+            headers['Cookie'] = [headers['Cookie'], ...context.cookies].filter(cookie => cookie).join('; ');
+        }
+
+        const initOverrideFn =
+            typeof initOverrides === "function"
+                ? initOverrides
+                : async () => initOverrides;
+
+        const initParams = {
+            method: context.method,
+            headers,
+            body: context.body,
+            credentials: this.configuration.credentials,
+        };
+
+        const { timeout: _timeout, retry: _retry, server: _server, serverVariables: _serverVariables, ...overrides }: RequestOptions = await initOverrideFn({
+            init: initParams,
+            context,
+        }) ?? {};
+        const overriddenInit: RequestInit = {
+            ...initParams,
+            ...overrides
+        };
+
+        let body: any;
+        if (isFormData(overriddenInit.body)
+            || (overriddenInit.body instanceof URLSearchParams)
+            || isBlob(overriddenInit.body)) {
+          body = overriddenInit.body;
+        } else if (this.isJsonMime(headers['Content-Type'])) {
+          body = JSON.stringify(overriddenInit.body);
+        } else {
+          body = overriddenInit.body;
+        }
+
+        const init: RequestInit = {
+            ...overriddenInit,
+            body
+        };
+
+        return { url, init };
+    }
+
+    private fetchApi = async (url: string, init: RequestInit) => {
+        let fetchParams = { url, init };
+        for (const middleware of this.middleware) {
+            if (middleware.pre) {
+                fetchParams = await middleware.pre({
+                    fetch: this.fetchApi,
+                    ...fetchParams,
+                }) || fetchParams;
+            }
+        }
+        let response: Response | undefined = undefined;
+        try {
+            response = await (this.configuration.fetchApi || fetch)(fetchParams.url, fetchParams.init);
+        } catch (e) {
+            for (const middleware of this.middleware) {
+                if (middleware.onError) {
+                    response = await middleware.onError({
+                        fetch: this.fetchApi,
+                        url: fetchParams.url,
+                        init: fetchParams.init,
+                        error: e,
+                        response: response ? response.clone() : undefined,
+                    }) || response;
+                }
+            }
+            if (response === undefined) {
+              if (e instanceof Error) {
+                throw new FetchError(e, 'The request failed and the interceptors did not return an alternative response');
+              } else {
+                throw e;
+              }
+            }
+        }
+        for (const middleware of this.middleware) {
+            if (middleware.post) {
+                response = await middleware.post({
+                    fetch: this.fetchApi,
+                    url: fetchParams.url,
+                    init: fetchParams.init,
+                    response: response.clone(),
+                }) || response;
+            }
+        }
+        return response;
+    }
+
+    /**
+     * Create a shallow clone of `this` by constructing a new instance
+     * and then shallow cloning data members.
+     */
+    private clone<T extends BaseAPI>(this: T): T {
+        const constructor = this.constructor as any;
+        const next = new constructor(this.configuration);
+        next.middleware = this.middleware.slice();
+        return next;
+    }
+};
+
+/**
+ * Client of the standalone operation functions, opening up to them the request
+ * helpers the API classes inherit from `BaseAPI`
+ */
+export class Client extends BaseAPI {
+    constructor(public override configuration = DefaultConfig) {
+        super(configuration);
+    }
+
+    override request(context: RequestOpts, initOverrides?: RequestOptions | InitOverrideFunction, errorFactory?: ErrorFactory): Promise<Response> {
+        return super.request(context, initOverrides, errorFactory);
+    }
+
+    override validateBody<T>(validate: ModelValidator, value: T): T {
+        return super.validateBody(validate, value);
+    }
+
+    override checkResponse(value: any, validate: ModelValidator): any {
+        return super.checkResponse(value, validate);
+    }
+
+    override readEventStream<T>(open: (api: this) => Promise<EventStreamApiResponse<T>>, initOverrides?: RequestOptions | InitOverrideFunction): AsyncGenerator<T> {
+        return super.readEventStream(open, initOverrides);
+    }
+}
+
+function isBlob(value: any): value is Blob {
+    return typeof Blob !== 'undefined' && value instanceof Blob;
+}
+
+function isFormData(value: any): value is FormData {
+    return typeof FormData !== "undefined" && value instanceof FormData;
+}
+
+/**
+ * HTTP status codes by class, e.g. `StatusCodes[4]` is every `4XX` code
+ */
+export type StatusCodes = {
+    1: 100 | 101 | 102 | 103 | 104 | 105 | 106 | 107 | 108 | 109 | 110 | 111 | 112 | 113 | 114 | 115 | 116 | 117 | 118 | 119 | 120 | 121 | 122 | 123 | 124 | 125 | 126 | 127 | 128 | 129 | 130 | 131 | 132 | 133 | 134 | 135 | 136 | 137 | 138 | 139 | 140 | 141 | 142 | 143 | 144 | 145 | 146 | 147 | 148 | 149 | 150 | 151 | 152 | 153 | 154 | 155 | 156 | 157 | 158 | 159 | 160 | 161 | 162 | 163 | 164 | 165 | 166 | 167 | 168 | 169 | 170 | 171 | 172 | 173 | 174 | 175 | 176 | 177 | 178 | 179 | 180 | 181 | 182 | 183 | 184 | 185 | 186 | 187 | 188 | 189 | 190 | 191 | 192 | 193 | 194 | 195 | 196 | 197 | 198 | 199;
+    2: 200 | 201 | 202 | 203 | 204 | 205 | 206 | 207 | 208 | 209 | 210 | 211 | 212 | 213 | 214 | 215 | 216 | 217 | 218 | 219 | 220 | 221 | 222 | 223 | 224 | 225 | 226 | 227 | 228 | 229 | 230 | 231 | 232 | 233 | 234 | 235 | 236 | 237 | 238 | 239 | 240 | 241 | 242 | 243 | 244 | 245 | 246 | 247 | 248 | 249 | 250 | 251 | 252 | 253 | 254 | 255 | 256 | 257 | 258 | 259 | 260 | 261 | 262 | 263 | 264 | 265 | 266 | 267 | 268 | 269 | 270 | 271 | 272 | 273 | 274 | 275 | 276 | 277 | 278 | 279 | 280 | 281 | 282 | 283 | 284 | 285 | 286 | 287 | 288 | 289 | 290 | 291 | 292 | 293 | 294 | 295 | 296 | 297 | 298 | 299;
+    3: 300 | 301 | 302 | 303 | 304 | 305 | 306 | 307 | 308 | 309 | 310 | 311 | 312 | 313 | 314 | 315 | 316 | 317 | 318 | 319 | 320 | 321 | 322 | 323 | 324 | 325 | 326 | 327 | 328 | 329 | 330 | 331 | 332 | 333 | 334 | 335 | 336 | 337 | 338 | 339 | 340 | 341 | 342 | 343 | 344 | 345 | 346 | 347 | 348 | 349 | 350 | 351 | 352 | 353 | 354 | 355 | 356 | 357 | 358 | 359 | 360 | 361 | 362 | 363 | 364 | 365 | 366 | 367 | 368 | 369 | 370 | 371 | 372 | 373 | 374 | 375 | 376 | 377 | 378 | 379 | 380 | 381 | 382 | 383 | 384 | 385 | 386 | 387 | 388 | 389 | 390 | 391 | 392 | 393 | 394 | 395 | 396 | 397 | 398 | 399;
+    4: 400 | 401 | 402 | 403 | 404 | 405 | 406 | 407 | 408 | 409 | 410 | 411 | 412 | 413 | 414 | 415 | 416 | 417 | 418 | 419 | 420 | 421 | 422 | 423 | 424 | 425 | 426 | 427 | 428 | 429 | 430 | 431 | 432 | 433 | 434 | 435 | 436 | 437 | 438 | 439 | 440 | 441 | 442 | 443 | 444 | 445 | 446 | 447 | 448 | 449 | 450 | 451 | 452 | 453 | 454 | 455 | 456 | 457 | 458 | 459 | 460 | 461 | 462 | 463 | 464 | 465 | 466 | 467 | 468 | 469 | 470 | 471 | 472 | 473 | 474 | 475 | 476 | 477 | 478 | 479 | 480 | 481 | 482 | 483 | 484 | 485 | 486 | 487 | 488 | 489 | 490 | 491 | 492 | 493 | 494 | 495 | 496 | 497 | 498 | 499;
+    5: 500 | 501 | 502 | 503 | 504 | 505 | 506 | 507 | 508 | 509 | 510 | 511 | 512 | 513 | 514 | 515 | 516 | 517 | 518 | 519 | 520 | 521 | 522 | 523 | 524 | 525 | 526 | 527 | 528 | 529 | 530 | 531 | 532 | 533 | 534 | 535 | 536 | 537 | 538 | 539 | 540 | 541 | 542 | 543 | 544 | 545 | 546 | 547 | 548 | 549 | 550 | 551 | 552 | 553 | 554 | 555 | 556 | 557 | 558 | 559 | 560 | 561 | 562 | 563 | 564 | 565 | 566 | 567 | 568 | 569 | 570 | 571 | 572 | 573 | 574 | 575 | 576 | 577 | 578 | 579 | 580 | 581 | 582 | 583 | 584 | 585 | 586 | 587 | 588 | 589 | 590 | 591 | 592 | 593 | 594 | 595 | 596 | 597 | 598 | 599;
+};
+
+export class ResponseError extends Error {
+    override name: "ResponseError" = "ResponseError";
+    constructor(public response: Response, msg?: string) {
+        super(msg);
+    }
+}
+
+/**
+ * Builds the error thrown for a non-2xx response, e.g. a typed operation error.
+ */
+export type ErrorFactory = (response: Response) => Promise<ResponseError>;
+
+/**
+ * Read an error body: JSON when it parses, converted by the given transformer,
+ * and the raw text otherwise, including when the body is empty.
+ */
+export async function readErrorBody(response: Response): Promise<unknown>;
+export async function readErrorBody<T>(response: Response, transformer: ResponseTransformer<T>): Promise<T | string>;
+export async function readErrorBody(response: Response, transformer: ResponseTransformer<unknown> = (json) => json): Promise<unknown> {
+    const text = await response.text();
+    let json: unknown;
+    try {
+        json = JSON.parse(text);
+    } catch {
+        return text;
+    }
+    return transformer(json);
+}
+
+export class FetchError extends Error {
+    override name: "FetchError" = "FetchError";
+    constructor(public cause: Error, msg?: string) {
+        super(msg);
+    }
+}
+
+export class TimeoutError extends Error {
+    override name: "TimeoutError" = "TimeoutError";
+    constructor(public timeout: number, msg?: string) {
+        super(msg ?? `The request timed out after ${timeout}ms`);
+    }
+}
+
+/**
+ * Milliseconds to wait before the attempt following the given one
+ */
+function backoffDelay(policy: RetryPolicy, attempt: number): number {
+    const delay = Math.min(policy.maxDelay, policy.initialDelay * Math.pow(policy.multiplier, attempt - 1));
+    return policy.jitter ? Math.random() * delay : delay;
+}
+
+/**
+ * Milliseconds a `Retry-After` header asks to wait, given in seconds or as an HTTP date
+ */
+function parseRetryAfter(value: string | null): number | undefined {
+    if (value === null || value.trim() === '') {
+        return undefined;
+    }
+    const seconds = Number(value);
+    if (!isNaN(seconds)) {
+        return Math.max(0, seconds * 1000);
+    }
+    const date = Date.parse(value);
+    return isNaN(date) ? undefined : Math.max(0, date - Date.now());
+}
+
+function sleep(ms: number, signal?: AbortSignal): Promise<void> {
+    return new Promise((resolve, reject) => {
+        if (signal?.aborted) {
+            reject(signal.reason);
+            return;
+        }
+        const onAbort = () => {
+            clearTimeout(timer);
+            reject(signal!.reason);
+        };
+        const timer = setTimeout(() => {
+            signal?.removeEventListener('abort', onAbort);
+            resolve();
+        }, ms);
+        signal?.addEventListener('abort', onAbort, { once: true });
+    });
+}
+
+export class RequiredError extends Error {
+    override name: "RequiredError" = "RequiredError";
+    constructor(public field: string, msg?: string) {
+        super(msg);
+    }
+}
+
+/**
+ * A value breaking a constraint of its model, at a JSON path such as `$.items[0].name`
+ */
+export interface ValidationViolation {
+    path: string;
+    message: string;
+}
+
+export class ValidationError extends Error {
+    override name: "ValidationError" = "ValidationError";
+    constructor(public violations: ValidationViolation[], msg?: string) {
+        super(msg ?? violations.map(({ path, message }) => `${path} ${message}`).join('; '));
+    }
+}
+
+/**
+ * Checks a value against a model, collecting the violations found under the given path
+ */
+export type ModelValidator = (value: any, path: string, violations: ValidationViolation[]) => void;
+
+/**
+ * Constraints of a model property, as listed in the model's validation attributes map
+ */
+export interface PropertyValidationAttributes {
+    maxLength?: number;
+    minLength?: number;
+    pattern?: string;
+    maximum?: number;
+    exclusiveMaximum?: boolean;
+    minimum?: number;
+    exclusiveMinimum?: boolean;
+    multipleOf?: number;
+    maxItems?: number;
+    minItems?: number;
+    uniqueItems?: boolean;
+}
+
+/**
+ * Check a property value against its constraints, and against its model for
+ * model values or items
+ */
+export function validateProperty(value: any, attributes: PropertyValidationAttributes | undefined, path: string, violations: ValidationViolation[], validateModel?: ModelValidator): void {
+    if (value == null) {
+        return;
+    }
+    const violate = (message: string) => violations.push({ path, message });
+    if (Array.isArray(value)) {
+        if (attributes?.minItems != null && value.length < attributes.minItems) {
+            violate(`must have at least ${attributes.minItems} items`);
+        }
+        if (attributes?.maxItems != null && value.length > attributes.maxItems) {
+            violate(`must have at most ${attributes.maxItems} items`);
+        }
+        if (attributes?.uniqueItems && new Set(value.map((item) => JSON.stringify(item))).size !== value.length) {
+            violate('must have unique items');
+        }
+        if (validateModel) {
+            value.forEach((item, index) => validateProperty(item, undefined, `${path}[${index}]`, violations, validateModel));
+        }
+        return;
+    }
+    if (typeof value === 'string') {
+        if (attributes?.minLength != null && value.length < attributes.minLength) {
+            violate(`must be at least ${attributes.minLength} characters long`);
+        }
+        if (attributes?.maxLength != null && value.length > attributes.maxLength) {
+            violate(`must be at most ${attributes.maxLength} characters long`);
+        }
+        if (attributes?.pattern != null && !new RegExp(attributes.pattern).test(value)) {
+            violate(`must match ${attributes.pattern}`);
+        }
+    } else if (typeof value === 'number' || typeof value === 'bigint') {
+        if (attributes?.minimum != null) {
+            if (attributes.exclusiveMinimum ? value <= attributes.minimum : value < attributes.minimum) {
+                violate(`must be ${attributes.exclusiveMinimum ? 'greater than' : 'at least'} ${attributes.minimum}`);
+            }
+        }
+        if (attributes?.maximum != null) {
+            if (attributes.exclusiveMaximum ? value >= attributes.maximum : value > attributes.maximum) {
+                violate(`must be ${attributes.exclusiveMaximum ? 'less than' : 'at most'} ${attributes.maximum}`);
+            }
+        }
+        if (attributes?.multipleOf != null) {
+            const quotient = Number(value) / attributes.multipleOf;
+            if (Math.abs(quotient - Math.round(quotient)) > 1e-9) {
+                violate(`must be a multiple of ${attributes.multipleOf}`);
+            }
+        }
+    }
+    if (validateModel) {
+        validateModel(value, path, violations);
+    }
+}
+
+/**
+ * Validator of arrays whose items are checked against a model
+ */
+export function validateItems(validateModel: ModelValidator): ModelValidator {
+    return (value, path, violations) => {
+        if (!Array.isArray(value)) {
+            violations.push({ path, message: 'must be an array' });
+            return;
+        }
+        value.forEach((item, index) => validateModel(item, `${path}[${index}]`, violations));
+    };
+}
+
+/**
+ * URL of the next page linked by the `Link` header of a response, resolved
+ * against the response URL; `undefined` on the last page
+ */
+export function nextPageLink(response: Response): string | undefined {
+    const header = response.headers.get('Link');
+    if (header === null) {
+        return undefined;
+    }
+    for (const [, target, params] of header.matchAll(/<([^>]*)>([^,]*)/g)) {
+        const rel = /;\s*rel\s*=\s*"?([^";]*)"?/i.exec(params);
+        if (rel && rel[1].toLowerCase().split(/\s+/).includes('next')) {
+            return new URL(target, response.url || undefined).toString();
+        }
+    }
+    return undefined;
+}
+
+/**
+ * Event dispatched by a `text/event-stream` response
+ */
+export interface ServerSentEvent {
+    /** Type of the event, `message` when the stream does not name it */
+    event: string;
+    data: string;
+    /** Last event ID set by the stream when the event was dispatched */
+    id?: string;
+    /** Reconnection delay last asked for by the stream, in milliseconds */
+    retry?: number;
+}
+
+/**
+ * Lines of a text body as they arrive, ended by CRLF, LF or CR
+ */
+export async function* readLines(body: ReadableStream<Uint8Array>): AsyncGenerator<string> {
+    const reader = body.getReader();
+    const decoder = new TextDecoder();
+    let buffer = '';
+    try {
+        for (;;) {
+            const { done, value } = await reader.read();
+            buffer += done ? decoder.decode() : decoder.decode(value, { stream: true });
+            let start = 0;
+            for (let i = 0; i < buffer.length; i++) {
+                const c = buffer[i];
+                if (c !== '\n' && c !== '\r') {
+                    continue;
+                }
+                // A CR ending the chunk may be the start of a CRLF split across chunks
+                if (c === '\r' && i === buffer.length - 1 && !done) {
+                    break;
+                }
+                yield buffer.slice(start, i);
+                if (c === '\r' && buffer[i + 1] === '\n') {
+                    i++;
+                }
+                start = i + 1;
+            }
+            buffer = buffer.slice(start);
+            if (done) {
+                if (buffer !== '') {
+                    yield buffer;
+                }
+                return;
+            }
+        }
+    } finally {
+        await reader.cancel().catch(() => undefined);
+    }
+}
+
+/**
+ * Events of a `text/event-stream` body as they are dispatched, following the
+ * parsing rules of the HTML standard
+ */
+export async function* parseServerSentEvents(body: ReadableStream<Uint8Array>): AsyncGenerator<ServerSentEvent> {
+    let event = '';
+    let data: string[] = [];
+    let id: string | undefined;
+    let retry: number | undefined;
+    for await (const line of readLines(body)) {
+        if (line === '') {
+            if (data.length > 0) {
+                yield { event: event || 'message', data: data.join('\n'), id, retry };
+            }
+            event = '';
+            data = [];
+            continue;
+        }
+        const colon = line.indexOf(':');
+        if (colon === 0) {
+            continue;
+        }
+        const field = colon < 0 ? line : line.slice(0, colon);
+        let value = colon < 0 ? '' : line.slice(colon + 1);
+        if (value.startsWith(' ')) {
+            value = value.slice(1);
+        }
+        switch (field) {
+            case 'event':
+                event = value;
+                break;
+            case 'data':
+                data.push(value);
+                break;
+            case 'id':
+                if (!value.includes('\0')) {
+                    id = value;
+                }
+                break;
+            case 'retry':
+                if (/^\d+$/.test(value)) {
+                    retry = Number(value);
+                }
+                break;
+        }
+    }
+}
+
+export const COLLECTION_FORMATS = {
+    csv: ",",
+    ssv: " ",
+    tsv: "\t",
+    pipes: "|",
+};
+
+export type FetchAPI = WindowOrWorkerGlobalScope['fetch'];
+
+export type Json = any;
+export type HTTPMethod = 'GET' | 'POST' | 'PUT' | 'PATCH' | 'DELETE' | 'OPTIONS' | 'HEAD';
+export type HTTPHeaders = { [key: string]: string };
+export type HTTPQuery = { [key: string]: string | number | null | boolean | Array<string | number | null | boolean> | Set<string | number | null | boolean> | QueryParameter | HTTPQuery };
+export type HTTPBody = Json | FormData | URLSearchParams;
+export type HTTPRequestInit = { headers?: HTTPHeaders; method: HTTPMethod; credentials?: RequestCredentials; body?: HTTPBody };
+export type ModelPropertyNaming = 'camelCase' | 'snake_case' | 'PascalCase' | 'original';
+
+export type InitOverrideFunction = (requestContext: { init: HTTPRequestInit, context: RequestOpts }) => Promise<RequestInit>
+
+export interface FetchParams {
+    url: string;
+    init: RequestInit;
+}
+
+export interface RequestOpts {
+    /** Servers of operations that override the API's servers */
+    servers?: readonly ServerConfiguration<any>[];
+    path: string;
+    method: HTTPMethod;
+    headers: HTTPHeaders;
+    query?: HTTPQuery;
+    body?: HTTPBody;
+    /** Serialized `name=value` cookie pairs */
+    cookies?: string[];
+    /** Alternative security requirements, each a list of schemes to apply together */
+    security?: SecurityRequirement[][];
+    /** Whether the request may be retried, overriding the default of its method */
+    idempotent?: boolean;
+}
+
+export function querystring(params: HTTPQuery, prefix: string = ''): string {
+    return Object.keys(params)
+        .map(key => querystringSingleKey(key, params[key], prefix))
+        .filter(part => part.length > 0)
+        .join('&');
+}
+
+function querystringSingleKey(key: string, value: string | number | null | undefined | boolean | Array<string | number | null | boolean> | Set<string | number | null | boolean> | QueryParameter | HTTPQuery, keyPrefix: string = ''): string {
+    const fullKey = keyPrefix + (keyPrefix.length ? `[${key}]` : key);
+    if (value instanceof QueryParameter) {
+        return serializeParameter(fullKey, value.value, value.style, value.explode);
+    }
+    if (value instanceof Array) {
+        const multiValue = value.map(singleValue => encodeURIComponent(String(singleValue)))
+            .join(`&${encodeURIComponent(fullKey)}=`);
+        return `${encodeURIComponent(fullKey)}=${multiValue}`;
+    }
+    if (value instanceof Set) {
+        const valueAsArray = Array.from(value);
+        return querystringSingleKey(key, valueAsArray, keyPrefix);
+    }
+    if (value instanceof Date) {
+        return `${encodeURIComponent(fullKey)}=${encodeURIComponent(value.toISOString())}`;
+    }
+    if (value instanceof Object) {
+        return querystring(value as HTTPQuery, fullKey);
+    }
+    return `${encodeURIComponent(fullKey)}=${encodeURIComponent(String(value))}`;
+}
+
+/**
+ * OpenAPI parameter serialization styles
+ */
+export type ParameterStyle = 'simple' | 'label' | 'matrix' | 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject';
+
+/**
+ * Query parameter value serialized with an explicit style instead of the
+ * default `querystring` encoding
+ */
+export class QueryParameter {
+    constructor(public value: unknown, public style: ParameterStyle = 'form', public explode: boolean = true) {}
+}
+
+/**
+ * Serialize a parameter value following the OpenAPI `style` and `explode` rules
+ *
+ * `simple`, `label` and `matrix` yield a path segment or header value, the
+ * other styles the `name=value` pairs of a query string. Names and values are
+ * percent-encoded unless `encode` is false, as for header values. Empty arrays
+ * and objects are treated as undefined and yield an empty string.
+ */
+export function serializeParameter(name: string, value: unknown, style: ParameterStyle, explode: boolean, encode: boolean = true): string {
+    const escape = (text: string) => encode ? encodeURIComponent(text) : text;
+    const text = (item: unknown) => escape(item instanceof Date ? item.toISOString() : String(item));
+    const key = escape(name);
+    const pair = ([k, v]: [string, string]) => `${k}=${v}`;
+
+    if (value === undefined || value === null) {
+        return '';
+    }
+
+    if (Array.isArray(value) || value instanceof Set) {
+        const items = Array.from(value).map(text);
+        if (items.length === 0) {
+            return '';
+        }
+        switch (style) {
+            case 'simple':
+                return items.join(',');
+            case 'label':
+                return '.' + items.join(explode ? '.' : ',');
+            case 'matrix':
+                return explode
+                    ? items.map(item => `;${key}=${item}`).join('')
+                    : `;${key}=${items.join(',')}`;
+            case 'spaceDelimited':
+            case 'pipeDelimited':
+                if (!explode) {
+                    return `${key}=${items.join(style === 'spaceDelimited' ? escape(' ') : '|')}`;
+                }
+                break;
+            case 'form':
+                if (!explode) {
+                    return `${key}=${items.join(',')}`;
+                }
+                break;
+        }
+        return items.map(item => `${key}=${item}`).join('&');
+    }
+
+    if (typeof value === 'object' && !(value instanceof Date)) {
+        const entries: Array<[string, string]> = Object.entries(value)
+            .filter(([, item]) => item !== undefined)
+            .map(([k, item]) => [escape(k), text(item)]);
+        if (entries.length === 0) {
+            return '';
+        }
+        const flat = entries.flat();
+        switch (style) {
+            case 'simple':
+                return explode ? entries.map(pair).join(',') : flat.join(',');
+            case 'label':
+                return '.' + (explode ? entries.map(pair).join('.') : flat.join(','));
+            case 'matrix':
+                return explode
+                    ? entries.map(entry => `;${pair(entry)}`).join('')
+                    : `;${key}=${flat.join(',')}`;
+            case 'deepObject':
+                return Object.entries(value)
+                    .filter(([, item]) => item !== undefined)
+                    .map(([k, item]) => `${escape(`${name}[${k}]`)}=${text(item)}`)
+                    .join('&');
+            case 'spaceDelimited':
+                return explode ? entries.map(pair).join('&') : `${key}=${flat.join(escape(' '))}`;
+            case 'pipeDelimited':
+                return explode ? entries.map(pair).join('&') : `${key}=${flat.join('|')}`;
+            case 'form':
+                return explode ? entries.map(pair).join('&') : `${key}=${flat.join(',')}`;
+        }
+    }
+
+    switch (style) {
+        case 'simple':
+            return text(value);
+        case 'label':
+            return `.${text(value)}`;
+        case 'matrix':
+            return value === '' ? `;${key}` : `;${key}=${text(value)}`;
+        default:
+            return `${key}=${text(value)}`;
+    }
+}
+
+/**
+ * Write a `date` value as `YYYY-MM-DD`, from its local calendar date
+ */
+export function formatDate(date: Date): string {
+    const pad = (value: number, width: number) => String(value).padStart(width, '0');
+    return `${pad(date.getFullYear(), 4)}-${pad(date.getMonth() + 1, 2)}-${pad(date.getDate(), 2)}`;
+}
+
+/**
+ * Read a `YYYY-MM-DD` value as local midnight of that day, other values as `Date` does
+ */
+export function parseDate(text: string): Date {
+    const match = /^(\d{4})-(\d{2})-(\d{2})$/.exec(text);
+    if (!match) {
+        return new Date(text);
+    }
+    const date = new Date(0);
+    date.setFullYear(Number(match[1]), Number(match[2]) - 1, Number(match[3]));
+    date.setHours(0, 0, 0, 0);
+    return date;
+}
+
+export function exists(json: any, key: string) {
+    const value = json[key];
+    return value !== null && value !== undefined;
+}
+
+export function mapValues(data: any, fn: (item: any) => any) {
+    const result: { [key: string]: any } = {};
+    for (const key of Object.keys(data)) {
+        result[key] = fn(data[key]);
+    }
+    return result;
+}
+
+export function canConsumeForm(consumes: Consume[]): boolean {
+    for (const consume of consumes) {
+        if ('multipart/form-data' === consume.contentType) {
+            return true;
+        }
+    }
+    return false;
+}
+
+export interface Consume {
+    contentType: string;
+}
+
+export interface RequestContext {
+    fetch: FetchAPI;
+    url: string;
+    init: RequestInit;
+}
+
+export interface ResponseContext {
+    fetch: FetchAPI;
+    url: string;
+    init: RequestInit;
+    response: Response;
+}
+
+export interface ErrorContext {
+    fetch: FetchAPI;
+    url: string;
+    init: RequestInit;
+    error: unknown;
+    response?: Response;
+}
+
+export interface Middleware {
+    pre?(context: RequestContext): Promise<FetchParams | void>;
+    post?(context: ResponseContext): Promise<Response | void>;
+    onError?(context: ErrorContext): Promise<Response | void>;
+}
+
+export interface ApiResponse<T> {
+    raw: Response;
+    value(): Promise<T>;
+}
+
+export interface ResponseTransformer<T> {
+    (json: any): T;
+}
+
+/**
+ * Read a JSON response body
+ */
+export async function readJson(response: Response): Promise<any> {
+    return await response.json();
+}
+
+export class JSONApiResponse<T> {
+    constructor(public raw: Response, private transformer: ResponseTransformer<T> = (jsonValue: any) => jsonValue) {}
+
+    async value(): Promise<T> {
+        return this.transformer(await readJson(this.raw));
+    }
+}
+
+export class VoidApiResponse {
+    constructor(public raw: Response) {}
+
+    async value(): Promise<void> {
+        return undefined;
+    }
+}
+
+export class BlobApiResponse {
+    constructor(public raw: Response) {}
+
+    async value(): Promise<Blob> {
+        return await this.raw.blob();
+    };
+}
+
+export class TextApiResponse {
+    constructor(public raw: Response) {}
+
+    async value(): Promise<string> {
+        return await this.raw.text();
+    };
+}
+
+export class StatusApiResponse<T> {
+    constructor(public raw: Response, private parser: (response: Response) => Promise<T>) {}
+
+    async value(): Promise<T> {
+        return await this.parser(this.raw);
+    }
+}
+
+export class StreamApiResponse {
+    constructor(public raw: Response) {}
+
+    async value(): Promise<ReadableStream<Uint8Array>> {
+        if (this.raw.body == null) {
+            throw new ResponseError(this.raw, 'Response has no body to stream');
+        }
+        return this.raw.body;
+    }
+}
+
+export class EventStreamApiResponse<T> {
+    constructor(public raw: Response, private transformer: ResponseTransformer<T> = (jsonValue: any) => jsonValue) {}
+
+    async *events(): AsyncGenerator<ServerSentEvent> {
+        if (this.raw.body == null) {
+            throw new ResponseError(this.raw, 'Response has no body to stream');
+        }
+        yield* parseServerSentEvents(this.raw.body);
+    }
+
+    /**
+     * Value of an event: its data parsed as JSON, or the data itself when it is not JSON
+     */
+    parse(event: ServerSentEvent): T {
+        let jsonValue: any;
+        try {
+            jsonValue = JSON.parse(event.data);
+        } catch {
+            jsonValue = event.data;
+        }
+        return this.transformer(jsonValue);
+    }
+
+    async *value(): AsyncGenerator<T> {
+        for await (const event of this.events()) {
+            yield this.parse(event);
+        }
+    }
+}
+
+export class JsonLinesApiResponse<T> {
+    constructor(public raw: Response, private transformer: ResponseTransformer<T> = (jsonValue: any) => jsonValue) {}
+
+    async *value(): AsyncGenerator<T> {
+        if (this.raw.body == null) {
+            throw new ResponseError(this.raw, 'Response has no body to stream');
+        }
+        for await (const line of readLines(this.raw.body)) {
+            if (line.trim() !== '') {
+                yield this.transformer(JSON.parse(line));
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Int64 Types API
+ *
+ * Integers that do not fit a double
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Payment, PaymentFromJSON, PaymentValidate } from '../models/Payment';
+
+/** API client for payments operations */
+
+export interface PaymentsApiInterface {
+  getPaymentRaw: (paymentId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Payment>>;
+  getPayment: (paymentId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Payment>;
+}
+
+export class PaymentsApi extends BaseAPI implements PaymentsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async getPaymentRaw(paymentId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Payment>> {
+    // Build path with path parameters
+    let urlPath = `/payments/${paymentId}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => PaymentFromJSON(this.validateBody(PaymentValidate, jsonValue)));
+  }
+
+  async getPayment(paymentId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Payment> {
+    const response = await this.getPaymentRaw(paymentId, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+export * from './PaymentsApi';
\ No newline at end of file
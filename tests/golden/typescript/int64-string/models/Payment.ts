@@ -0,0 +1,89 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Int64 Types API
+ *
+ * Integers that do not fit a double
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Payment {
+amount: number,
+id: string,
+parentId?: string | null,
+refundIds?: Array<string>
+}
+
+export function instanceOfPayment(value: object): value is Payment {
+    if (!('amount' in value) || (value as any)['amount'] === undefined) return false;
+    if (!('id' in value) || (value as any)['id'] === undefined) return false;
+    return true;
+}
+
+export function PaymentFromJSON(json: any): Payment {
+    return PaymentFromJSONTyped(json, false);
+}
+
+export function PaymentFromJSONTyped(json: any, ignoreDiscriminator: boolean): Payment {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'amount': json['amount'],
+        'id': json['id'] == null ? json['id'] : String(json['id']),
+        'parentId': json['parentId'] == null ? json['parentId'] : String(json['parentId']),
+        'refundIds': json['refundIds'] == null ? undefined : (json['refundIds'] as any[]).map((item) => String(item)),
+    };
+}
+
+export function PaymentToJSON(value?: Payment | null): any {
+    return PaymentToJSONTyped(value, false);
+}
+
+export function PaymentToJSONTyped(value?: Payment | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'amount': value['amount'],
+        'id': value['id'] == null ? value['id'] : BigInt(value['id']),
+        'parentId': value['parentId'] == null ? value['parentId'] : BigInt(value['parentId']),
+        'refundIds': value['refundIds'] == null ? value['refundIds'] : value['refundIds'].map((item) => BigInt(item)),
+    };
+}
+
+export const PaymentPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function PaymentValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['amount'] === undefined) {
+        violations.push({ path: `${path}.amount`, message: 'is required' });
+    }
+    if (value['id'] === undefined) {
+        violations.push({ path: `${path}.id`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+export * from './Payment';
\ No newline at end of file
@@ -9,13 +9,13 @@
  * Do not edit the class manually.
  */
 
-import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction } from '../runtime/runtime';
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
 
 /** API client for default operations */
 
 export interface DefaultApiInterface {
-  getTestRaw: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
-  getTest: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  getTestRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  getTest: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
 }
 
 export class DefaultApi extends BaseAPI implements DefaultApiInterface {
@@ -26,7 +26,7 @@ export class DefaultApi extends BaseAPI implements DefaultApiInterface {
     super(configuration ?? DefaultConfig);
   }
 
-  async getTestRaw(initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async getTestRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/test`;
 
@@ -48,11 +48,11 @@ export class DefaultApi extends BaseAPI implements DefaultApiInterface {
         query: queryParameters,
     }, initOverrides);
 
-    return new JSONApiResponse(response);
+    return new VoidApiResponse(response);
   }
 
-  async getTest(initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.getTestRaw(initOverrides);
-      return await response.value();
+  async getTest(initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.getTestRaw(initOverrides);
+    return await response.value();
   }
 }
\ No newline at end of file
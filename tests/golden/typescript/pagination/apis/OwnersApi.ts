@@ -0,0 +1,163 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Pagination API
+ *
+ * List operations paginated with cursors, offsets and Link headers
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, validateItems, nextPageLink, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Owner, OwnerFromJSON, OwnerValidate } from '../models/Owner';
+import { type Visit } from '../models/Visit';
+import { type VisitPage, VisitPageFromJSON, VisitPageValidate } from '../models/VisitPage';
+
+/** API client for owners operations */
+
+export interface OwnersApiInterface {
+  /** List owners by offset */
+  listOwnersRaw: (offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Owner>>>;
+  /** List owners by offset */
+  listOwners: (offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Owner>>;
+  /** Walk the pages of `listOwners`, from the given `offset` on */
+  listOwnersAllPages: (offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<Array<Owner>>;
+  /** Iterate the items of every page of `listOwners` */
+  listOwnersPaginated: (offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<Owner>;
+  /** List the visits of an owner, linked page by page */
+  listVisitsRaw: (ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<VisitPage>>;
+  /** List the visits of an owner, linked page by page */
+  listVisits: (ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VisitPage>;
+  /**
+   * Walk the pages of `listVisits`, following the `next` links of their responses
+   */
+  listVisitsAllPages: (ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<VisitPage>;
+  /** Iterate the items of every page of `listVisits` */
+  listVisitsPaginated: (ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<Visit>;
+}
+
+export class OwnersApi extends BaseAPI implements OwnersApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** List owners by offset */
+  async listOwnersRaw(offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Owner>>> {
+    // Build path with path parameters
+    let urlPath = `/owners`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+    if (offset !== undefined) {
+      queryParameters['offset'] = offset;
+    }
+    if (limit !== undefined) {
+      queryParameters['limit'] = limit;
+    }
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(OwnerValidate), jsonValue) as Array<any>).map(OwnerFromJSON));
+  }
+
+  /** List owners by offset */
+  async listOwners(offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Owner>> {
+    const response = await this.listOwnersRaw(offset, limit, initOverrides);
+    return await response.value();
+  }
+
+  /** Walk the pages of `listOwners`, from the given `offset` on */
+  async *listOwnersAllPages(offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Array<Owner>> {
+    for (;;) {
+      const pageResponse = await this.listOwnersRaw(offset, limit, initOverrides);
+      const currentPage = await pageResponse.value();
+      yield currentPage;
+      const pageItems = currentPage;
+      if (pageItems.length === 0 || (limit !== undefined && pageItems.length < Number(limit))) {
+        return;
+      }
+      offset = String(Number(offset ?? 0) + pageItems.length);
+    }
+  }
+
+  /** Iterate the items of every page of `listOwners` */
+  async *listOwnersPaginated(offset?: string, limit?: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Owner> {
+    for await (const currentPage of this.listOwnersAllPages(offset, limit, initOverrides)) {
+      yield* currentPage;
+    }
+  }
+
+  /** List the visits of an owner, linked page by page */
+  async listVisitsRaw(ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<VisitPage>> {
+    // Build path with path parameters
+    let urlPath = `/owners/${ownerId}/visits`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => VisitPageFromJSON(this.validateBody(VisitPageValidate, jsonValue)));
+  }
+
+  /** List the visits of an owner, linked page by page */
+  async listVisits(ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VisitPage> {
+    const response = await this.listVisitsRaw(ownerId, initOverrides);
+    return await response.value();
+  }
+
+  /**
+   * Walk the pages of `listVisits`, following the `next` links of their responses
+   */
+  async *listVisitsAllPages(ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<VisitPage> {
+    let pagesApi = this;
+    for (;;) {
+      const pageResponse = await pagesApi.listVisitsRaw(ownerId, initOverrides);
+      yield await pageResponse.value();
+      const next = nextPageLink(pageResponse.raw);
+      if (next === undefined) {
+        return;
+      }
+      pagesApi = this.withPreMiddleware(async ({ init }) => ({ url: next, init }));
+    }
+  }
+
+  /** Iterate the items of every page of `listVisits` */
+  async *listVisitsPaginated(ownerId: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Visit> {
+    for await (const currentPage of this.listVisitsAllPages(ownerId, initOverrides)) {
+      yield* currentPage['visits'] ?? [];
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Pagination API
+ *
+ * List operations paginated with cursors, offsets and Link headers
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Pet } from '../models/Pet';
+import { type PetPage, PetPageFromJSON, PetPageValidate } from '../models/PetPage';
+
+/** API client for pets operations */
+
+export interface PetsApiInterface {
+  /** List pets by cursor */
+  listPetsRaw: (status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<PetPage>>;
+  /** List pets by cursor */
+  listPets: (status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<PetPage>;
+  /** Walk the pages of `listPets`, from the given `after` on */
+  listPetsAllPages: (status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<PetPage>;
+  /** Iterate the items of every page of `listPets` */
+  listPetsPaginated: (status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<Pet>;
+}
+
+export class PetsApi extends BaseAPI implements PetsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** List pets by cursor */
+  async listPetsRaw(status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<PetPage>> {
+    // Build path with path parameters
+    let urlPath = `/pets`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+    if (status !== undefined) {
+      queryParameters['status'] = status;
+    }
+    if (after !== undefined) {
+      queryParameters['after'] = after;
+    }
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => PetPageFromJSON(this.validateBody(PetPageValidate, jsonValue)));
+  }
+
+  /** List pets by cursor */
+  async listPets(status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<PetPage> {
+    const response = await this.listPetsRaw(status, after, initOverrides);
+    return await response.value();
+  }
+
+  /** Walk the pages of `listPets`, from the given `after` on */
+  async *listPetsAllPages(status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<PetPage> {
+    for (;;) {
+      const pageResponse = await this.listPetsRaw(status, after, initOverrides);
+      const currentPage = await pageResponse.value();
+      yield currentPage;
+      const nextCursor = currentPage['meta']?.['nextCursor'];
+      if (nextCursor == null || nextCursor === '') {
+        return;
+      }
+      after = nextCursor;
+    }
+  }
+
+  /** Iterate the items of every page of `listPets` */
+  async *listPetsPaginated(status?: string, after?: string, initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Pet> {
+    for await (const currentPage of this.listPetsAllPages(status, after, initOverrides)) {
+      yield* currentPage['data'] ?? [];
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from './OwnersApi';
+export * from './PetsApi';
\ No newline at end of file
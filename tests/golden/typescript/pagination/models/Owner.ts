@@ -0,0 +1,76 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Pagination API
+ *
+ * List operations paginated with cursors, offsets and Link headers
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Owner {
+name: string
+}
+
+export function instanceOfOwner(value: object): value is Owner {
+    if (!('name' in value) || (value as any)['name'] === undefined) return false;
+    return true;
+}
+
+export function OwnerFromJSON(json: any): Owner {
+    return OwnerFromJSONTyped(json, false);
+}
+
+export function OwnerFromJSONTyped(json: any, ignoreDiscriminator: boolean): Owner {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'name': json['name'],
+    };
+}
+
+export function OwnerToJSON(value?: Owner | null): any {
+    return OwnerToJSONTyped(value, false);
+}
+
+export function OwnerToJSONTyped(value?: Owner | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'name': value['name'],
+    };
+}
+
+export const OwnerPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function OwnerValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+}
\ No newline at end of file
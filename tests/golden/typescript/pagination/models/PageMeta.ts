@@ -0,0 +1,72 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Pagination API
+ *
+ * List operations paginated with cursors, offsets and Link headers
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface PageMeta {
+nextCursor?: string | null
+}
+
+export function instanceOfPageMeta(value: object): value is PageMeta {
+    return true;
+}
+
+export function PageMetaFromJSON(json: any): PageMeta {
+    return PageMetaFromJSONTyped(json, false);
+}
+
+export function PageMetaFromJSONTyped(json: any, ignoreDiscriminator: boolean): PageMeta {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'nextCursor': json['nextCursor'],
+    };
+}
+
+export function PageMetaToJSON(value?: PageMeta | null): any {
+    return PageMetaToJSONTyped(value, false);
+}
+
+export function PageMetaToJSONTyped(value?: PageMeta | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'nextCursor': value['nextCursor'],
+    };
+}
+
+export const PageMetaPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function PageMetaValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
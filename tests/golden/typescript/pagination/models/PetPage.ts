@@ -0,0 +1,83 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Pagination API
+ *
+ * List operations paginated with cursors, offsets and Link headers
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface PetPage {
+data: Array<{ id: string; name: string }>,
+meta: { nextCursor: string | null }
+}
+
+export function instanceOfPetPage(value: object): value is PetPage {
+    if (!('data' in value) || (value as any)['data'] === undefined) return false;
+    if (!('meta' in value) || (value as any)['meta'] === undefined) return false;
+    return true;
+}
+
+export function PetPageFromJSON(json: any): PetPage {
+    return PetPageFromJSONTyped(json, false);
+}
+
+export function PetPageFromJSONTyped(json: any, ignoreDiscriminator: boolean): PetPage {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'data': json['data'],
+        'meta': json['meta'],
+    };
+}
+
+export function PetPageToJSON(value?: PetPage | null): any {
+    return PetPageToJSONTyped(value, false);
+}
+
+export function PetPageToJSONTyped(value?: PetPage | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'data': value['data'],
+        'meta': value['meta'],
+    };
+}
+
+export const PetPagePropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function PetPageValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['data'] === undefined) {
+        violations.push({ path: `${path}.data`, message: 'is required' });
+    }
+    if (value['meta'] === undefined) {
+        violations.push({ path: `${path}.meta`, message: 'is required' });
+    }
+}
\ No newline at end of file
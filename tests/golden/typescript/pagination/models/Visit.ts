@@ -0,0 +1,78 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Pagination API
+ *
+ * List operations paginated with cursors, offsets and Link headers
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Visit {
+date: string, notes?: string
+}
+
+export function instanceOfVisit(value: object): value is Visit {
+    if (!('date' in value) || (value as any)['date'] === undefined) return false;
+    return true;
+}
+
+export function VisitFromJSON(json: any): Visit {
+    return VisitFromJSONTyped(json, false);
+}
+
+export function VisitFromJSONTyped(json: any, ignoreDiscriminator: boolean): Visit {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'date': json['date'],
+        'notes': json['notes'] ?? undefined,
+    };
+}
+
+export function VisitToJSON(value?: Visit | null): any {
+    return VisitToJSONTyped(value, false);
+}
+
+export function VisitToJSONTyped(value?: Visit | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'date': value['date'],
+        'notes': value['notes'],
+    };
+}
+
+export const VisitPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function VisitValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['date'] === undefined) {
+        violations.push({ path: `${path}.date`, message: 'is required' });
+    }
+}
\ No newline at end of file
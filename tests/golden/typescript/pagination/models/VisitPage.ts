@@ -0,0 +1,72 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Pagination API
+ *
+ * List operations paginated with cursors, offsets and Link headers
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface VisitPage {
+visits?: Array<{ date: string; notes: string }>
+}
+
+export function instanceOfVisitPage(value: object): value is VisitPage {
+    return true;
+}
+
+export function VisitPageFromJSON(json: any): VisitPage {
+    return VisitPageFromJSONTyped(json, false);
+}
+
+export function VisitPageFromJSONTyped(json: any, ignoreDiscriminator: boolean): VisitPage {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'visits': json['visits'] ?? undefined,
+    };
+}
+
+export function VisitPageToJSON(value?: VisitPage | null): any {
+    return VisitPageToJSONTyped(value, false);
+}
+
+export function VisitPageToJSONTyped(value?: VisitPage | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'visits': value['visits'],
+    };
+}
+
+export const VisitPagePropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function VisitPageValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
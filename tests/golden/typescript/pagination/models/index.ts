@@ -0,0 +1,6 @@
+export * from './Owner';
+export * from './PageMeta';
+export * from './Pet';
+export * from './PetPage';
+export * from './Visit';
+export * from './VisitPage';
\ No newline at end of file
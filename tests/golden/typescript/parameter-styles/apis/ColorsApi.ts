@@ -0,0 +1,81 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Parameter Styles API
+ *
+ * Path, query and header parameters in every serialization style
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, QueryParameter, serializeParameter, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+
+/** API client for colors operations */
+
+export interface ColorsApiInterface {
+  getColorsRaw: (label: Array<string>, matrix: string, simple: Array<string>, tags?: Array<string>, ids?: Array<string>, words?: Array<string>, levels?: Array<string>, filter?: object, page?: string, traceIds?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  getColors: (label: Array<string>, matrix: string, simple: Array<string>, tags?: Array<string>, ids?: Array<string>, words?: Array<string>, levels?: Array<string>, filter?: object, page?: string, traceIds?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+}
+
+export class ColorsApi extends BaseAPI implements ColorsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async getColorsRaw(label: Array<string>, matrix: string, simple: Array<string>, tags?: Array<string>, ids?: Array<string>, words?: Array<string>, levels?: Array<string>, filter?: object, page?: string, traceIds?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/colors/${serializeParameter('label', label, 'label', true)}/${serializeParameter('matrix', matrix, 'matrix', false)}/${serializeParameter('simple', simple, 'simple', false)}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+    if (tags !== undefined) {
+      queryParameters['tags'] = tags;
+    }
+    if (ids !== undefined) {
+      queryParameters['ids'] = new QueryParameter(ids, 'form', false);
+    }
+    if (words !== undefined) {
+      queryParameters['words'] = new QueryParameter(words, 'spaceDelimited', false);
+    }
+    if (levels !== undefined) {
+      queryParameters['levels'] = new QueryParameter(levels, 'pipeDelimited', false);
+    }
+    if (filter !== undefined) {
+      queryParameters['filter'] = new QueryParameter(filter, 'deepObject', true);
+    }
+    if (page !== undefined) {
+      queryParameters['page'] = page;
+    }
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+    if (traceIds !== undefined) {
+      headerParameters['traceIds'] = String(serializeParameter('traceIds', traceIds, 'simple', false, false));
+    }
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async getColors(label: Array<string>, matrix: string, simple: Array<string>, tags?: Array<string>, ids?: Array<string>, words?: Array<string>, levels?: Array<string>, filter?: object, page?: string, traceIds?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.getColorsRaw(label, matrix, simple, tags, ids, words, levels, filter, page, traceIds, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
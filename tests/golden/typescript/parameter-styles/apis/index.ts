@@ -0,0 +1 @@
+export * from './ColorsApi';
\ No newline at end of file
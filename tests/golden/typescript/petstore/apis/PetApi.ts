@@ -11,45 +11,226 @@
  * Do not edit the class manually.
  */
 
-import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction } from '../runtime/runtime';
-import { ApiResponseFromJSON } from '../models/ApiResponse';
-import { PetFromJSON } from '../models/Pet';
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, validateItems, readErrorBody, type StatusCodes, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type ApiResponse, ApiResponseFromJSON, ApiResponseValidate } from '../models/ApiResponse';
+import { type ErrorResponse, ErrorResponseFromJSON } from '../models/ErrorResponse';
+import { type Pet, PetFromJSON, PetToJSON, PetValidate } from '../models/Pet';
+
+/** Error responses of addPet, keyed by status */
+export type AddPetErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 422; body: ErrorResponse | string }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 422>; body: unknown };
+
+/** Error thrown by addPet for non-2xx responses, with the parsed body */
+export class AddPetError extends ResponseError {
+  constructor(response: Response, public readonly payload: AddPetErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<AddPetError> {
+    return new AddPetError(response, await AddPetError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<AddPetErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 422:
+        return { status: 422, body: await readErrorBody(response, ErrorResponseFromJSON) };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 422>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of updatePet, keyed by status */
+export type UpdatePetErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 404; body: undefined }
+  | { status: 422; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404 | 422>; body: unknown };
+
+/** Error thrown by updatePet for non-2xx responses, with the parsed body */
+export class UpdatePetError extends ResponseError {
+  constructor(response: Response, public readonly payload: UpdatePetErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<UpdatePetError> {
+    return new UpdatePetError(response, await UpdatePetError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<UpdatePetErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 404:
+        return { status: 404, body: undefined };
+      case 422:
+        return { status: 422, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404 | 422>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of findPetsByStatus, keyed by status */
+export type FindPetsByStatusErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400>; body: unknown };
+
+/** Error thrown by findPetsByStatus for non-2xx responses, with the parsed body */
+export class FindPetsByStatusError extends ResponseError {
+  constructor(response: Response, public readonly payload: FindPetsByStatusErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<FindPetsByStatusError> {
+    return new FindPetsByStatusError(response, await FindPetsByStatusError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<FindPetsByStatusErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of findPetsByTags, keyed by status */
+export type FindPetsByTagsErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400>; body: unknown };
+
+/** Error thrown by findPetsByTags for non-2xx responses, with the parsed body */
+export class FindPetsByTagsError extends ResponseError {
+  constructor(response: Response, public readonly payload: FindPetsByTagsErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<FindPetsByTagsError> {
+    return new FindPetsByTagsError(response, await FindPetsByTagsError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<FindPetsByTagsErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of getPetById, keyed by status */
+export type GetPetByIdErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 404; body: ErrorResponse | string }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>; body: unknown };
+
+/** Error thrown by getPetById for non-2xx responses, with the parsed body */
+export class GetPetByIdError extends ResponseError {
+  constructor(response: Response, public readonly payload: GetPetByIdErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<GetPetByIdError> {
+    return new GetPetByIdError(response, await GetPetByIdError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<GetPetByIdErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 404:
+        return { status: 404, body: await readErrorBody(response, ErrorResponseFromJSON) };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of updatePetWithForm, keyed by status */
+export type UpdatePetWithFormErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400>; body: unknown };
+
+/** Error thrown by updatePetWithForm for non-2xx responses, with the parsed body */
+export class UpdatePetWithFormError extends ResponseError {
+  constructor(response: Response, public readonly payload: UpdatePetWithFormErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<UpdatePetWithFormError> {
+    return new UpdatePetWithFormError(response, await UpdatePetWithFormError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<UpdatePetWithFormErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of deletePet, keyed by status */
+export type DeletePetErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400>; body: unknown };
+
+/** Error thrown by deletePet for non-2xx responses, with the parsed body */
+export class DeletePetError extends ResponseError {
+  constructor(response: Response, public readonly payload: DeletePetErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<DeletePetError> {
+    return new DeletePetError(response, await DeletePetError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<DeletePetErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400>, body: await readErrorBody(response) };
+  }
+}
 
 /** API client for pet operations */
 
 export interface PetApiInterface {
   /** Add a new pet to the store */
-  addPetRaw: (body: Pet, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Pet>>;
+  addPetRaw: (body: Pet, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Pet>>;
   /** Add a new pet to the store */
-  addPet: (body: Pet, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Pet>;
+  addPet: (body: Pet, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Pet>;
   /** Update an existing pet */
-  updatePetRaw: (body: Pet, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Pet>>;
+  updatePetRaw: (body: Pet, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Pet>>;
   /** Update an existing pet */
-  updatePet: (body: Pet, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Pet>;
+  updatePet: (body: Pet, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Pet>;
   /** Find pets by status */
-  findPetsByStatusRaw: (status: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Array<string>>>;
+  findPetsByStatusRaw: (status: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Pet>>>;
   /** Find pets by status */
-  findPetsByStatus: (status: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Array<string>>;
+  findPetsByStatus: (status: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Pet>>;
   /** Find pets by tags */
-  findPetsByTagsRaw: (tags: Array<string>, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Array<string>>>;
+  findPetsByTagsRaw: (tags: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Pet>>>;
   /** Find pets by tags */
-  findPetsByTags: (tags: Array<string>, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Array<string>>;
+  findPetsByTags: (tags: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Pet>>;
   /** Find pet by ID */
-  getPetByIdRaw: (petId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Pet>>;
+  getPetByIdRaw: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Pet>>;
   /** Find pet by ID */
-  getPetById: (petId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Pet>;
+  getPetById: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Pet>;
   /** Update a pet in the store with form data */
-  updatePetWithFormRaw: (petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Pet>>;
+  updatePetWithFormRaw: (petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Pet>>;
   /** Update a pet in the store with form data */
-  updatePetWithForm: (petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Pet>;
+  updatePetWithForm: (petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Pet>;
   /** Delete a pet */
-  deletePetRaw: (petId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
+  deletePetRaw: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
   /** Delete a pet */
-  deletePet: (petId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  deletePet: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
   /** Upload an image */
-  uploadFileRaw: (petId: string, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<ApiResponse>>;
+  uploadFileRaw: (petId: string, file: Blob, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<ApiResponse>>;
   /** Upload an image */
-  uploadFile: (petId: string, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<ApiResponse>;
+  uploadFile: (petId: string, file: Blob, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<ApiResponse>;
 }
 
 export class PetApi extends BaseAPI implements PetApiInterface {
@@ -61,12 +242,13 @@ export class PetApi extends BaseAPI implements PetApiInterface {
   }
 
   /** Add a new pet to the store */
-  async addPetRaw(body: Pet, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Pet>> {
+  async addPetRaw(body: Pet, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
     // Build path with path parameters
     let urlPath = `/pet`;
 
     // Build query parameters
     const queryParameters: any = {};
+
     // Build headers
     const headerParameters: Record<string, string> = {
       'Content-Type': 'application/json',
@@ -74,8 +256,9 @@ export class PetApi extends BaseAPI implements PetApiInterface {
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = body;
+    const requestBody = this.validateBody(PetValidate, PetToJSON(body));
 
     // Make request
     const response = await this.request({
@@ -83,25 +266,26 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'POST',
         headers: headerParameters,
         query: queryParameters,
-        body,
-    }, initOverrides);
+        body: requestBody,
+    }, initOverrides, AddPetError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(this.validateBody(PetValidate, jsonValue)));
   }
 
   /** Add a new pet to the store */
-  async addPet(body: Pet, initOverrides?: InitOverrideFunction | RequestInit): Promise<Pet> {
-      const response = await this.addPetRaw(body, initOverrides);
-      return await response.value();
+  async addPet(body: Pet, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+    const response = await this.addPetRaw(body, initOverrides);
+    return await response.value();
   }
 
   /** Update an existing pet */
-  async updatePetRaw(body: Pet, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Pet>> {
+  async updatePetRaw(body: Pet, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
     // Build path with path parameters
     let urlPath = `/pet`;
 
     // Build query parameters
     const queryParameters: any = {};
+
     // Build headers
     const headerParameters: Record<string, string> = {
       'Content-Type': 'application/json',
@@ -109,8 +293,9 @@ export class PetApi extends BaseAPI implements PetApiInterface {
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = body;
+    const requestBody = this.validateBody(PetValidate, PetToJSON(body));
 
     // Make request
     const response = await this.request({
@@ -118,25 +303,28 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'PUT',
         headers: headerParameters,
         query: queryParameters,
-        body,
-    }, initOverrides);
+        body: requestBody,
+    }, initOverrides, UpdatePetError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(this.validateBody(PetValidate, jsonValue)));
   }
 
   /** Update an existing pet */
-  async updatePet(body: Pet, initOverrides?: InitOverrideFunction | RequestInit): Promise<Pet> {
-      const response = await this.updatePetRaw(body, initOverrides);
-      return await response.value();
+  async updatePet(body: Pet, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+    const response = await this.updatePetRaw(body, initOverrides);
+    return await response.value();
   }
 
   /** Find pets by status */
-  async findPetsByStatusRaw(status: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Array<string>>> {
+  async findPetsByStatusRaw(status: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Pet>>> {
     // Build path with path parameters
     let urlPath = `/pet/findByStatus`;
 
     // Build query parameters
     const queryParameters: any = {};
+    if (status !== undefined) {
+      queryParameters['status'] = status;
+    }
 
     // Build headers
     const headerParameters: Record<string, string> = {
@@ -151,24 +339,27 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'GET',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, FindPetsByStatusError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => (jsonValue as Array<any>).map(PetFromJSON));
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(PetValidate), jsonValue) as Array<any>).map(PetFromJSON));
   }
 
   /** Find pets by status */
-  async findPetsByStatus(status: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<Array<string>> {
-      const response = await this.findPetsByStatusRaw(status, initOverrides);
-      return await response.value();
+  async findPetsByStatus(status: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Pet>> {
+    const response = await this.findPetsByStatusRaw(status, initOverrides);
+    return await response.value();
   }
 
   /** Find pets by tags */
-  async findPetsByTagsRaw(tags: Array<string>, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Array<string>>> {
+  async findPetsByTagsRaw(tags: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Pet>>> {
     // Build path with path parameters
     let urlPath = `/pet/findByTags`;
 
     // Build query parameters
     const queryParameters: any = {};
+    if (tags !== undefined) {
+      queryParameters['tags'] = tags;
+    }
 
     // Build headers
     const headerParameters: Record<string, string> = {
@@ -183,19 +374,19 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'GET',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, FindPetsByTagsError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => (jsonValue as Array<any>).map(PetFromJSON));
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(PetValidate), jsonValue) as Array<any>).map(PetFromJSON));
   }
 
   /** Find pets by tags */
-  async findPetsByTags(tags: Array<string>, initOverrides?: InitOverrideFunction | RequestInit): Promise<Array<string>> {
-      const response = await this.findPetsByTagsRaw(tags, initOverrides);
-      return await response.value();
+  async findPetsByTags(tags: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Pet>> {
+    const response = await this.findPetsByTagsRaw(tags, initOverrides);
+    return await response.value();
   }
 
   /** Find pet by ID */
-  async getPetByIdRaw(petId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Pet>> {
+  async getPetByIdRaw(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
     // Build path with path parameters
     let urlPath = `/pet/${petId}`;
 
@@ -215,32 +406,40 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'GET',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, GetPetByIdError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(this.validateBody(PetValidate, jsonValue)));
   }
 
   /** Find pet by ID */
-  async getPetById(petId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<Pet> {
-      const response = await this.getPetByIdRaw(petId, initOverrides);
-      return await response.value();
+  async getPetById(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+    const response = await this.getPetByIdRaw(petId, initOverrides);
+    return await response.value();
   }
 
   /** Update a pet in the store with form data */
-  async updatePetWithFormRaw(petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Pet>> {
+  async updatePetWithFormRaw(petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
     // Build path with path parameters
     let urlPath = `/pet/${petId}`;
 
     // Build query parameters
     const queryParameters: any = {};
+    if (name !== undefined) {
+      queryParameters['name'] = name;
+    }
+    if (status !== undefined) {
+      queryParameters['status'] = status;
+    }
+
     // Build headers
     const headerParameters: Record<string, string> = {
       ...this.configuration?.headers,
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = undefined;
+    const requestBody = undefined;
 
     // Make request
     const response = await this.request({
@@ -248,20 +447,20 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'POST',
         headers: headerParameters,
         query: queryParameters,
-        body,
-    }, initOverrides);
+        body: requestBody,
+    }, initOverrides, UpdatePetWithFormError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(this.validateBody(PetValidate, jsonValue)));
   }
 
   /** Update a pet in the store with form data */
-  async updatePetWithForm(petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<Pet> {
-      const response = await this.updatePetWithFormRaw(petId, name, status, initOverrides);
-      return await response.value();
+  async updatePetWithForm(petId: string, name?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+    const response = await this.updatePetWithFormRaw(petId, name, status, initOverrides);
+    return await response.value();
   }
 
   /** Delete a pet */
-  async deletePetRaw(petId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async deletePetRaw(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/pet/${petId}`;
 
@@ -281,32 +480,41 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'DELETE',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, DeletePetError.fromResponse);
 
     return new VoidApiResponse(response);
   }
 
   /** Delete a pet */
-  async deletePet(petId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.deletePetRaw(petId, initOverrides);
-      return await response.value();
+  async deletePet(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.deletePetRaw(petId, initOverrides);
+    return await response.value();
   }
 
   /** Upload an image */
-  async uploadFileRaw(petId: string, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<ApiResponse>> {
+  async uploadFileRaw(petId: string, file: Blob, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<ApiResponse>> {
     // Build path with path parameters
     let urlPath = `/pet/${petId}/uploadImage`;
 
     // Build query parameters
     const queryParameters: any = {};
+    if (additionalMetadata !== undefined) {
+      queryParameters['additionalMetadata'] = additionalMetadata;
+    }
+
     // Build headers
     const headerParameters: Record<string, string> = {
       ...this.configuration?.headers,
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = undefined;
+    const formParams = new FormData();
+    if (file !== undefined) {
+      formParams.append('file', file);
+    }
+    const requestBody = formParams;
 
     // Make request
     const response = await this.request({
@@ -314,15 +522,15 @@ export class PetApi extends BaseAPI implements PetApiInterface {
         method: 'POST',
         headers: headerParameters,
         query: queryParameters,
-        body,
+        body: requestBody,
     }, initOverrides);
 
-    return new JSONApiResponse(response, (jsonValue) => ApiResponseFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => ApiResponseFromJSON(this.validateBody(ApiResponseValidate, jsonValue)));
   }
 
   /** Upload an image */
-  async uploadFile(petId: string, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<ApiResponse> {
-      const response = await this.uploadFileRaw(petId, additionalMetadata, initOverrides);
-      return await response.value();
+  async uploadFile(petId: string, file: Blob, additionalMetadata?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<ApiResponse> {
+    const response = await this.uploadFileRaw(petId, file, additionalMetadata, initOverrides);
+    return await response.value();
   }
 }
\ No newline at end of file
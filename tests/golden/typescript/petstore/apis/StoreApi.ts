@@ -11,28 +11,106 @@
  * Do not edit the class manually.
  */
 
-import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction } from '../runtime/runtime';
-import { OrderFromJSON } from '../models/Order';
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, readErrorBody, type StatusCodes, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Order, OrderFromJSON, OrderToJSON, OrderValidate } from '../models/Order';
+
+/** Error responses of placeOrder, keyed by status */
+export type PlaceOrderErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400>; body: unknown };
+
+/** Error thrown by placeOrder for non-2xx responses, with the parsed body */
+export class PlaceOrderError extends ResponseError {
+  constructor(response: Response, public readonly payload: PlaceOrderErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<PlaceOrderError> {
+    return new PlaceOrderError(response, await PlaceOrderError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<PlaceOrderErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of getOrderById, keyed by status */
+export type GetOrderByIdErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 404; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>; body: unknown };
+
+/** Error thrown by getOrderById for non-2xx responses, with the parsed body */
+export class GetOrderByIdError extends ResponseError {
+  constructor(response: Response, public readonly payload: GetOrderByIdErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<GetOrderByIdError> {
+    return new GetOrderByIdError(response, await GetOrderByIdError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<GetOrderByIdErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 404:
+        return { status: 404, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of deleteOrder, keyed by status */
+export type DeleteOrderErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 404; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>; body: unknown };
+
+/** Error thrown by deleteOrder for non-2xx responses, with the parsed body */
+export class DeleteOrderError extends ResponseError {
+  constructor(response: Response, public readonly payload: DeleteOrderErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<DeleteOrderError> {
+    return new DeleteOrderError(response, await DeleteOrderError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<DeleteOrderErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 404:
+        return { status: 404, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>, body: await readErrorBody(response) };
+  }
+}
 
 /** API client for store operations */
 
 export interface StoreApiInterface {
   /** Returns pet inventories by status */
-  getInventoryRaw: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<string>>;
+  getInventoryRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<string>>;
   /** Returns pet inventories by status */
-  getInventory: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<string>;
+  getInventory: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<string>;
   /** Place an order for a pet */
-  placeOrderRaw: (body: Order, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Order>>;
+  placeOrderRaw: (body: Order, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Order>>;
   /** Place an order for a pet */
-  placeOrder: (body: Order, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Order>;
+  placeOrder: (body: Order, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Order>;
   /** Find purchase order by ID */
-  getOrderByIdRaw: (orderId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Order>>;
+  getOrderByIdRaw: (orderId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Order>>;
   /** Find purchase order by ID */
-  getOrderById: (orderId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<Order>;
+  getOrderById: (orderId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Order>;
   /** Delete purchase order by ID */
-  deleteOrderRaw: (orderId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
+  deleteOrderRaw: (orderId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
   /** Delete purchase order by ID */
-  deleteOrder: (orderId: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  deleteOrder: (orderId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
 }
 
 export class StoreApi extends BaseAPI implements StoreApiInterface {
@@ -44,7 +122,7 @@ export class StoreApi extends BaseAPI implements StoreApiInterface {
   }
 
   /** Returns pet inventories by status */
-  async getInventoryRaw(initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<string>> {
+  async getInventoryRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<string>> {
     // Build path with path parameters
     let urlPath = `/store/inventory`;
 
@@ -70,18 +148,19 @@ export class StoreApi extends BaseAPI implements StoreApiInterface {
   }
 
   /** Returns pet inventories by status */
-  async getInventory(initOverrides?: InitOverrideFunction | RequestInit): Promise<string> {
-      const response = await this.getInventoryRaw(initOverrides);
-      return await response.value();
+  async getInventory(initOverrides?: InitOverrideFunction | RequestOptions): Promise<string> {
+    const response = await this.getInventoryRaw(initOverrides);
+    return await response.value();
   }
 
   /** Place an order for a pet */
-  async placeOrderRaw(body: Order, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Order>> {
+  async placeOrderRaw(body: Order, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Order>> {
     // Build path with path parameters
     let urlPath = `/store/order`;
 
     // Build query parameters
     const queryParameters: any = {};
+
     // Build headers
     const headerParameters: Record<string, string> = {
       'Content-Type': 'application/json',
@@ -89,8 +168,9 @@ export class StoreApi extends BaseAPI implements StoreApiInterface {
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = body;
+    const requestBody = this.validateBody(OrderValidate, OrderToJSON(body));
 
     // Make request
     const response = await this.request({
@@ -98,20 +178,20 @@ export class StoreApi extends BaseAPI implements StoreApiInterface {
         method: 'POST',
         headers: headerParameters,
         query: queryParameters,
-        body,
-    }, initOverrides);
+        body: requestBody,
+    }, initOverrides, PlaceOrderError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => OrderFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => OrderFromJSON(this.validateBody(OrderValidate, jsonValue)));
   }
 
   /** Place an order for a pet */
-  async placeOrder(body: Order, initOverrides?: InitOverrideFunction | RequestInit): Promise<Order> {
-      const response = await this.placeOrderRaw(body, initOverrides);
-      return await response.value();
+  async placeOrder(body: Order, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Order> {
+    const response = await this.placeOrderRaw(body, initOverrides);
+    return await response.value();
   }
 
   /** Find purchase order by ID */
-  async getOrderByIdRaw(orderId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Order>> {
+  async getOrderByIdRaw(orderId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Order>> {
     // Build path with path parameters
     let urlPath = `/store/order/${orderId}`;
 
@@ -131,19 +211,19 @@ export class StoreApi extends BaseAPI implements StoreApiInterface {
         method: 'GET',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, GetOrderByIdError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => OrderFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => OrderFromJSON(this.validateBody(OrderValidate, jsonValue)));
   }
 
   /** Find purchase order by ID */
-  async getOrderById(orderId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<Order> {
-      const response = await this.getOrderByIdRaw(orderId, initOverrides);
-      return await response.value();
+  async getOrderById(orderId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Order> {
+    const response = await this.getOrderByIdRaw(orderId, initOverrides);
+    return await response.value();
   }
 
   /** Delete purchase order by ID */
-  async deleteOrderRaw(orderId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async deleteOrderRaw(orderId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/store/order/${orderId}`;
 
@@ -163,14 +243,14 @@ export class StoreApi extends BaseAPI implements StoreApiInterface {
         method: 'DELETE',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, DeleteOrderError.fromResponse);
 
     return new VoidApiResponse(response);
   }
 
   /** Delete purchase order by ID */
-  async deleteOrder(orderId: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.deleteOrderRaw(orderId, initOverrides);
-      return await response.value();
+  async deleteOrder(orderId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.deleteOrderRaw(orderId, initOverrides);
+    return await response.value();
   }
 }
\ No newline at end of file
@@ -11,40 +11,145 @@
  * Do not edit the class manually.
  */
 
-import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction } from '../runtime/runtime';
-import { UserFromJSON } from '../models/User';
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, validateItems, readErrorBody, type StatusCodes, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type User, UserFromJSON, UserToJSON, UserValidate } from '../models/User';
+
+/** Error responses of loginUser, keyed by status */
+export type LoginUserErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400>; body: unknown };
+
+/** Error thrown by loginUser for non-2xx responses, with the parsed body */
+export class LoginUserError extends ResponseError {
+  constructor(response: Response, public readonly payload: LoginUserErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<LoginUserError> {
+    return new LoginUserError(response, await LoginUserError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<LoginUserErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of getUserByName, keyed by status */
+export type GetUserByNameErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 404; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>; body: unknown };
+
+/** Error thrown by getUserByName for non-2xx responses, with the parsed body */
+export class GetUserByNameError extends ResponseError {
+  constructor(response: Response, public readonly payload: GetUserByNameErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<GetUserByNameError> {
+    return new GetUserByNameError(response, await GetUserByNameError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<GetUserByNameErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 404:
+        return { status: 404, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of updateUser, keyed by status */
+export type UpdateUserErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 404; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>; body: unknown };
+
+/** Error thrown by updateUser for non-2xx responses, with the parsed body */
+export class UpdateUserError extends ResponseError {
+  constructor(response: Response, public readonly payload: UpdateUserErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<UpdateUserError> {
+    return new UpdateUserError(response, await UpdateUserError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<UpdateUserErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 404:
+        return { status: 404, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>, body: await readErrorBody(response) };
+  }
+}
+
+/** Error responses of deleteUser, keyed by status */
+export type DeleteUserErrorResponse =
+  | { status: 400; body: undefined }
+  | { status: 404; body: undefined }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>; body: unknown };
+
+/** Error thrown by deleteUser for non-2xx responses, with the parsed body */
+export class DeleteUserError extends ResponseError {
+  constructor(response: Response, public readonly payload: DeleteUserErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<DeleteUserError> {
+    return new DeleteUserError(response, await DeleteUserError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<DeleteUserErrorResponse> {
+    switch (response.status) {
+      case 400:
+        return { status: 400, body: undefined };
+      case 404:
+        return { status: 404, body: undefined };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 400 | 404>, body: await readErrorBody(response) };
+  }
+}
 
 /** API client for user operations */
 
 export interface UserApiInterface {
   /** Create user */
-  createUserRaw: (body: User, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<User>>;
+  createUserRaw: (body: User, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<User>>;
   /** Create user */
-  createUser: (body: User, initOverrides?: InitOverrideFunction | RequestInit) => Promise<User>;
+  createUser: (body: User, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<User>;
   /** Creates list of users with given input array */
-  createUsersWithListInputRaw: (body: Array<string>, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<User>>;
+  createUsersWithListInputRaw: (body: Array<User>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<User>>;
   /** Creates list of users with given input array */
-  createUsersWithListInput: (body: Array<string>, initOverrides?: InitOverrideFunction | RequestInit) => Promise<User>;
+  createUsersWithListInput: (body: Array<User>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<User>;
   /** Logs user into the system */
-  loginUserRaw: (username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
+  loginUserRaw: (username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
   /** Logs user into the system */
-  loginUser: (username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  loginUser: (username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
   /** Logs out current logged in user session */
-  logoutUserRaw: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
+  logoutUserRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
   /** Logs out current logged in user session */
-  logoutUser: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  logoutUser: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
   /** Get user by user name */
-  getUserByNameRaw: (username: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<User>>;
+  getUserByNameRaw: (username: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<User>>;
   /** Get user by user name */
-  getUserByName: (username: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<User>;
+  getUserByName: (username: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<User>;
   /** Update user */
-  updateUserRaw: (username: string, body: User, initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
+  updateUserRaw: (username: string, body: User, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
   /** Update user */
-  updateUser: (username: string, body: User, initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  updateUser: (username: string, body: User, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
   /** Delete user */
-  deleteUserRaw: (username: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<VoidApiResponse>;
+  deleteUserRaw: (username: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
   /** Delete user */
-  deleteUser: (username: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<void>;
+  deleteUser: (username: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
 }
 
 export class UserApi extends BaseAPI implements UserApiInterface {
@@ -56,12 +161,13 @@ export class UserApi extends BaseAPI implements UserApiInterface {
   }
 
   /** Create user */
-  async createUserRaw(body: User, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<User>> {
+  async createUserRaw(body: User, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<User>> {
     // Build path with path parameters
     let urlPath = `/user`;
 
     // Build query parameters
     const queryParameters: any = {};
+
     // Build headers
     const headerParameters: Record<string, string> = {
       'Content-Type': 'application/json',
@@ -69,8 +175,9 @@ export class UserApi extends BaseAPI implements UserApiInterface {
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = body;
+    const requestBody = this.validateBody(UserValidate, UserToJSON(body));
 
     // Make request
     const response = await this.request({
@@ -78,25 +185,26 @@ export class UserApi extends BaseAPI implements UserApiInterface {
         method: 'POST',
         headers: headerParameters,
         query: queryParameters,
-        body,
+        body: requestBody,
     }, initOverrides);
 
-    return new JSONApiResponse(response, (jsonValue) => UserFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => UserFromJSON(this.validateBody(UserValidate, jsonValue)));
   }
 
   /** Create user */
-  async createUser(body: User, initOverrides?: InitOverrideFunction | RequestInit): Promise<User> {
-      const response = await this.createUserRaw(body, initOverrides);
-      return await response.value();
+  async createUser(body: User, initOverrides?: InitOverrideFunction | RequestOptions): Promise<User> {
+    const response = await this.createUserRaw(body, initOverrides);
+    return await response.value();
   }
 
   /** Creates list of users with given input array */
-  async createUsersWithListInputRaw(body: Array<string>, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<User>> {
+  async createUsersWithListInputRaw(body: Array<User>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<User>> {
     // Build path with path parameters
     let urlPath = `/user/createWithList`;
 
     // Build query parameters
     const queryParameters: any = {};
+
     // Build headers
     const headerParameters: Record<string, string> = {
       'Content-Type': 'application/json',
@@ -104,8 +212,9 @@ export class UserApi extends BaseAPI implements UserApiInterface {
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = body;
+    const requestBody = this.validateBody(validateItems(UserValidate), body.map(UserToJSON));
 
     // Make request
     const response = await this.request({
@@ -113,25 +222,31 @@ export class UserApi extends BaseAPI implements UserApiInterface {
         method: 'POST',
         headers: headerParameters,
         query: queryParameters,
-        body,
+        body: requestBody,
     }, initOverrides);
 
-    return new JSONApiResponse(response, (jsonValue) => UserFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => UserFromJSON(this.validateBody(UserValidate, jsonValue)));
   }
 
   /** Creates list of users with given input array */
-  async createUsersWithListInput(body: Array<string>, initOverrides?: InitOverrideFunction | RequestInit): Promise<User> {
-      const response = await this.createUsersWithListInputRaw(body, initOverrides);
-      return await response.value();
+  async createUsersWithListInput(body: Array<User>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<User> {
+    const response = await this.createUsersWithListInputRaw(body, initOverrides);
+    return await response.value();
   }
 
   /** Logs user into the system */
-  async loginUserRaw(username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async loginUserRaw(username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/user/login`;
 
     // Build query parameters
     const queryParameters: any = {};
+    if (username !== undefined) {
+      queryParameters['username'] = username;
+    }
+    if (password !== undefined) {
+      queryParameters['password'] = password;
+    }
 
     // Build headers
     const headerParameters: Record<string, string> = {
@@ -146,19 +261,19 @@ export class UserApi extends BaseAPI implements UserApiInterface {
         method: 'GET',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, LoginUserError.fromResponse);
 
-    return new JSONApiResponse(response);
+    return new VoidApiResponse(response);
   }
 
   /** Logs user into the system */
-  async loginUser(username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.loginUserRaw(username, password, initOverrides);
-      return await response.value();
+  async loginUser(username?: string, password?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.loginUserRaw(username, password, initOverrides);
+    return await response.value();
   }
 
   /** Logs out current logged in user session */
-  async logoutUserRaw(initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async logoutUserRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/user/logout`;
 
@@ -180,17 +295,17 @@ export class UserApi extends BaseAPI implements UserApiInterface {
         query: queryParameters,
     }, initOverrides);
 
-    return new JSONApiResponse(response);
+    return new VoidApiResponse(response);
   }
 
   /** Logs out current logged in user session */
-  async logoutUser(initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.logoutUserRaw(initOverrides);
-      return await response.value();
+  async logoutUser(initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.logoutUserRaw(initOverrides);
+    return await response.value();
   }
 
   /** Get user by user name */
-  async getUserByNameRaw(username: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<User>> {
+  async getUserByNameRaw(username: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<User>> {
     // Build path with path parameters
     let urlPath = `/user/${username}`;
 
@@ -210,24 +325,25 @@ export class UserApi extends BaseAPI implements UserApiInterface {
         method: 'GET',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, GetUserByNameError.fromResponse);
 
-    return new JSONApiResponse(response, (jsonValue) => UserFromJSON(jsonValue));
+    return new JSONApiResponse(response, (jsonValue) => UserFromJSON(this.validateBody(UserValidate, jsonValue)));
   }
 
   /** Get user by user name */
-  async getUserByName(username: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<User> {
-      const response = await this.getUserByNameRaw(username, initOverrides);
-      return await response.value();
+  async getUserByName(username: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<User> {
+    const response = await this.getUserByNameRaw(username, initOverrides);
+    return await response.value();
   }
 
   /** Update user */
-  async updateUserRaw(username: string, body: User, initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async updateUserRaw(username: string, body: User, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/user/${username}`;
 
     // Build query parameters
     const queryParameters: any = {};
+
     // Build headers
     const headerParameters: Record<string, string> = {
       'Content-Type': 'application/json',
@@ -235,8 +351,9 @@ export class UserApi extends BaseAPI implements UserApiInterface {
     };
 
     // Add header parameters
+
     // Prepare request body
-    const body = body;
+    const requestBody = this.validateBody(UserValidate, UserToJSON(body));
 
     // Make request
     const response = await this.request({
@@ -244,20 +361,20 @@ export class UserApi extends BaseAPI implements UserApiInterface {
         method: 'PUT',
         headers: headerParameters,
         query: queryParameters,
-        body,
-    }, initOverrides);
+        body: requestBody,
+    }, initOverrides, UpdateUserError.fromResponse);
 
-    return new JSONApiResponse(response);
+    return new VoidApiResponse(response);
   }
 
   /** Update user */
-  async updateUser(username: string, body: User, initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.updateUserRaw(username, body, initOverrides);
-      return await response.value();
+  async updateUser(username: string, body: User, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.updateUserRaw(username, body, initOverrides);
+    return await response.value();
   }
 
   /** Delete user */
-  async deleteUserRaw(username: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<VoidApiResponse> {
+  async deleteUserRaw(username: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
     // Build path with path parameters
     let urlPath = `/user/${username}`;
 
@@ -277,14 +394,14 @@ export class UserApi extends BaseAPI implements UserApiInterface {
         method: 'DELETE',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, DeleteUserError.fromResponse);
 
     return new VoidApiResponse(response);
   }
 
   /** Delete user */
-  async deleteUser(username: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<void> {
-      const response = await this.deleteUserRaw(username, initOverrides);
-      return await response.value();
+  async deleteUser(username: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.deleteUserRaw(username, initOverrides);
+    return await response.value();
   }
 }
\ No newline at end of file
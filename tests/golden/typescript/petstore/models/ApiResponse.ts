@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** API response model */
 export interface ApiResponse {
 code?: number | null,
@@ -31,9 +33,9 @@ export function ApiResponseFromJSONTyped(json: any, ignoreDiscriminator: boolean
         return json;
     }
     return {
-        'code': json['code'] ?? undefined,
-        'message': json['message'] ?? undefined,
-        'type': json['type'] ?? undefined,
+        'code': json['code'],
+        'message': json['message'],
+        'type': json['type'],
     };
 }
 
@@ -67,4 +69,11 @@ export const ApiResponsePropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function ApiResponseValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
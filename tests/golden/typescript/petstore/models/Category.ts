@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Category model */
 export interface Category {
 id?: number | null,
@@ -30,8 +32,8 @@ export function CategoryFromJSONTyped(json: any, ignoreDiscriminator: boolean):
         return json;
     }
     return {
-        'id': json['id'] ?? undefined,
-        'name': json['name'] ?? undefined,
+        'id': json['id'],
+        'name': json['name'],
     };
 }
 
@@ -64,4 +66,11 @@ export const CategoryPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function CategoryValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Error response model */
 export interface ErrorResponse {
 code: number, message: string
@@ -65,4 +67,17 @@ export const ErrorResponsePropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function ErrorResponseValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['code'] === undefined) {
+        violations.push({ path: `${path}.code`, message: 'is required' });
+    }
+    if (value['message'] === undefined) {
+        violations.push({ path: `${path}.message`, message: 'is required' });
+    }
+}
\ No newline at end of file
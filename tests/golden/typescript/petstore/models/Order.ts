@@ -11,6 +11,9 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+import { OrderStatus } from './OrderStatus';
+
 /** Order model */
 export interface Order {
 complete?: boolean | null,
@@ -18,7 +21,7 @@ id?: number | null,
 pet_id?: number | null,
 quantity?: number | null,
 ship_date?: string | null,
-status?: null | "approved" | "delivered" | "placed"
+status?: null | OrderStatus
 }
 
 export function instanceOfOrder(value: object): value is Order {
@@ -34,12 +37,12 @@ export function OrderFromJSONTyped(json: any, ignoreDiscriminator: boolean): Ord
         return json;
     }
     return {
-        'complete': json['complete'] ?? undefined,
-        'id': json['id'] ?? undefined,
-        'pet_id': json['pet_id'] ?? undefined,
-        'quantity': json['quantity'] ?? undefined,
-        'ship_date': json['ship_date'] ?? undefined,
-        'status': json['status'] ?? undefined,
+        'complete': json['complete'],
+        'id': json['id'],
+        'pet_id': json['pet_id'],
+        'quantity': json['quantity'],
+        'ship_date': json['ship_date'],
+        'status': json['status'],
     };
 }
 
@@ -76,4 +79,11 @@ export const OrderPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function OrderValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
@@ -13,7 +13,7 @@
 
 /** Order status enum */
 export enum OrderStatus {
-Placed = placed,
-Approved = approved,
-Delivered = delivered
+Placed = "placed",
+Approved = "approved",
+Delivered = "delivered"
 }
\ No newline at end of file
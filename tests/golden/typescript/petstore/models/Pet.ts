@@ -11,13 +11,16 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+import { PetStatus } from './PetStatus';
+
 /** Pet model */
 export interface Pet {
 category?: null | { id: number | null; name: string | null },
 id?: number | null,
 name: string,
 photo_urls: Array<string>,
-status?: null | "available" | "pending" | "sold",
+status?: null | PetStatus,
 tags?: Array<{ id: number | null; name: string | null }>
 }
 
@@ -36,11 +39,11 @@ export function PetFromJSONTyped(json: any, ignoreDiscriminator: boolean): Pet {
         return json;
     }
     return {
-        'category': json['category'] ?? undefined,
-        'id': json['id'] ?? undefined,
+        'category': json['category'],
+        'id': json['id'],
         'name': json['name'],
         'photo_urls': json['photo_urls'],
-        'status': json['status'] ?? undefined,
+        'status': json['status'],
         'tags': json['tags'] ?? undefined,
     };
 }
@@ -78,4 +81,17 @@ export const PetPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function PetValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+    if (value['photo_urls'] === undefined) {
+        violations.push({ path: `${path}.photo_urls`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -13,7 +13,7 @@
 
 /** Pet status enum */
 export enum PetStatus {
-Available = available,
-Pending = pending,
-Sold = sold
+Available = "available",
+Pending = "pending",
+Sold = "sold"
 }
\ No newline at end of file
@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** Tag model */
 export interface Tag {
 id?: number | null,
@@ -30,8 +32,8 @@ export function TagFromJSONTyped(json: any, ignoreDiscriminator: boolean): Tag {
         return json;
     }
     return {
-        'id': json['id'] ?? undefined,
-        'name': json['name'] ?? undefined,
+        'id': json['id'],
+        'name': json['name'],
     };
 }
 
@@ -64,4 +66,11 @@ export const TagPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function TagValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
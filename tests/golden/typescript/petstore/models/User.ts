@@ -11,6 +11,8 @@
  * Do not edit the class manually.
  */
 
+import { type ValidationViolation } from '../runtime/runtime';
+
 /** User model */
 export interface User {
 email?: string | null,
@@ -36,14 +38,14 @@ export function UserFromJSONTyped(json: any, ignoreDiscriminator: boolean): User
         return json;
     }
     return {
-        'email': json['email'] ?? undefined,
-        'first_name': json['first_name'] ?? undefined,
-        'id': json['id'] ?? undefined,
-        'last_name': json['last_name'] ?? undefined,
-        'password': json['password'] ?? undefined,
-        'phone': json['phone'] ?? undefined,
-        'user_status': json['user_status'] ?? undefined,
-        'username': json['username'] ?? undefined,
+        'email': json['email'],
+        'first_name': json['first_name'],
+        'id': json['id'],
+        'last_name': json['last_name'],
+        'password': json['password'],
+        'phone': json['phone'],
+        'user_status': json['user_status'],
+        'username': json['username'],
     };
 }
 
@@ -82,4 +84,11 @@ export const UserPropertyValidationAttributesMap: {
         uniqueItems?: boolean
     }
 } = {
-};
\ No newline at end of file
+};
+
+export function UserValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Read Write Only API
+ *
+ * Separate request and response shapes for readOnly and writeOnly properties
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, validateItems, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Account, AccountFromJSON, AccountValidate } from '../models/Account';
+import { type AccountInput, AccountInputToJSON, AccountInputValidate } from '../models/AccountInput';
+import { type Tag, TagFromJSON, TagToJSON, TagValidate } from '../models/Tag';
+
+/** API client for accounts operations */
+
+export interface AccountsApiInterface {
+  createAccountRaw: (body: AccountInput, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Account>>;
+  createAccount: (body: AccountInput, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Account>;
+  replaceAccountTagsRaw: (id: string, body: Array<Tag>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Tag>>>;
+  replaceAccountTags: (id: string, body: Array<Tag>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Tag>>;
+}
+
+export class AccountsApi extends BaseAPI implements AccountsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async createAccountRaw(body: AccountInput, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Account>> {
+    // Build path with path parameters
+    let urlPath = `/accounts`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Content-Type': 'application/json',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = this.validateBody(AccountInputValidate, AccountInputToJSON(body));
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => AccountFromJSON(this.validateBody(AccountValidate, jsonValue)));
+  }
+
+  async createAccount(body: AccountInput, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Account> {
+    const response = await this.createAccountRaw(body, initOverrides);
+    return await response.value();
+  }
+
+  async replaceAccountTagsRaw(id: string, body: Array<Tag>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Tag>>> {
+    // Build path with path parameters
+    let urlPath = `/accounts/${id}/tags`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Content-Type': 'application/json',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = this.validateBody(validateItems(TagValidate), body.map(TagToJSON));
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'PUT',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(TagValidate), jsonValue) as Array<any>).map(TagFromJSON));
+  }
+
+  async replaceAccountTags(id: string, body: Array<Tag>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Tag>> {
+    const response = await this.replaceAccountTagsRaw(id, body, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
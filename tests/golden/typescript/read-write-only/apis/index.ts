@@ -0,0 +1 @@
+export * from './AccountsApi';
\ No newline at end of file
@@ -0,0 +1,89 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Read Write Only API
+ *
+ * Separate request and response shapes for readOnly and writeOnly properties
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Account {
+createdAt?: string,
+email: string,
+id: string,
+settings?: { lastLogin: string; theme: string }
+}
+
+export function instanceOfAccount(value: object): value is Account {
+    if (!('email' in value) || (value as any)['email'] === undefined) return false;
+    if (!('id' in value) || (value as any)['id'] === undefined) return false;
+    return true;
+}
+
+export function AccountFromJSON(json: any): Account {
+    return AccountFromJSONTyped(json, false);
+}
+
+export function AccountFromJSONTyped(json: any, ignoreDiscriminator: boolean): Account {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'createdAt': json['createdAt'] ?? undefined,
+        'email': json['email'],
+        'id': json['id'],
+        'settings': json['settings'] ?? undefined,
+    };
+}
+
+export function AccountToJSON(value?: Account | null): any {
+    return AccountToJSONTyped(value, false);
+}
+
+export function AccountToJSONTyped(value?: Account | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'createdAt': value['createdAt'],
+        'email': value['email'],
+        'id': value['id'],
+        'settings': value['settings'],
+    };
+}
+
+export const AccountPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function AccountValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['email'] === undefined) {
+        violations.push({ path: `${path}.email`, message: 'is required' });
+    }
+    if (value['id'] === undefined) {
+        violations.push({ path: `${path}.id`, message: 'is required' });
+    }
+}
\ No newline at end of file
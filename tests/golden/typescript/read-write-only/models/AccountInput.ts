@@ -0,0 +1,86 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Read Write Only API
+ *
+ * Separate request and response shapes for readOnly and writeOnly properties
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface AccountInput {
+email: string,
+password: string,
+settings?: { theme: string }
+}
+
+export function instanceOfAccountInput(value: object): value is AccountInput {
+    if (!('email' in value) || (value as any)['email'] === undefined) return false;
+    if (!('password' in value) || (value as any)['password'] === undefined) return false;
+    return true;
+}
+
+export function AccountInputFromJSON(json: any): AccountInput {
+    return AccountInputFromJSONTyped(json, false);
+}
+
+export function AccountInputFromJSONTyped(json: any, ignoreDiscriminator: boolean): AccountInput {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'email': json['email'],
+        'password': json['password'],
+        'settings': json['settings'] ?? undefined,
+    };
+}
+
+export function AccountInputToJSON(value?: AccountInput | null): any {
+    return AccountInputToJSONTyped(value, false);
+}
+
+export function AccountInputToJSONTyped(value?: AccountInput | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'email': value['email'],
+        'password': value['password'],
+        'settings': value['settings'],
+    };
+}
+
+export const AccountInputPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function AccountInputValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['email'] === undefined) {
+        violations.push({ path: `${path}.email`, message: 'is required' });
+    }
+    if (value['password'] === undefined) {
+        violations.push({ path: `${path}.password`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+export * from './Account';
+export * from './AccountInput';
+export * from './Tag';
\ No newline at end of file
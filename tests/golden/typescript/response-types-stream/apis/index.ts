@@ -0,0 +1 @@
+export * from './ReportsApi';
\ No newline at end of file
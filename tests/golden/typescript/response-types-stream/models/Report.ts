@@ -0,0 +1,78 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Response Types API
+ *
+ * Text, binary, JSON, empty and multi-status responses
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Report {
+id: string, title?: string
+}
+
+export function instanceOfReport(value: object): value is Report {
+    if (!('id' in value) || (value as any)['id'] === undefined) return false;
+    return true;
+}
+
+export function ReportFromJSON(json: any): Report {
+    return ReportFromJSONTyped(json, false);
+}
+
+export function ReportFromJSONTyped(json: any, ignoreDiscriminator: boolean): Report {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'id': json['id'],
+        'title': json['title'] ?? undefined,
+    };
+}
+
+export function ReportToJSON(value?: Report | null): any {
+    return ReportToJSONTyped(value, false);
+}
+
+export function ReportToJSONTyped(value?: Report | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'id': value['id'],
+        'title': value['title'],
+    };
+}
+
+export const ReportPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function ReportValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['id'] === undefined) {
+        violations.push({ path: `${path}.id`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+export * from './Job';
+export * from './Problem';
+export * from './Report';
\ No newline at end of file
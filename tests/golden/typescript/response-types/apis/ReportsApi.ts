@@ -0,0 +1,275 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Response Types API
+ *
+ * Text, binary, JSON, empty and multi-status responses
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, TextApiResponse, BlobApiResponse, StatusApiResponse, ResponseError, readJson, readErrorBody, type StatusCodes, type ResponseCheck, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Job, JobFromJSON, JobValidate } from '../models/Job';
+import { type Problem, ProblemFromJSON } from '../models/Problem';
+import { type Report, ReportFromJSON, ReportValidate } from '../models/Report';
+
+/** Successful responses of createReport, keyed by status */
+export type CreateReportResult =
+  | { status: 201; body: Report }
+  | { status: 202; body: Job };
+
+/** Parse a successful response of createReport by its status, checking JSON bodies with `check` */
+async function CreateReportResultFromResponse(response: Response, check: ResponseCheck): Promise<CreateReportResult> {
+  switch (response.status) {
+    case 201:
+      return { status: 201, body: ReportFromJSON(check(await readJson(response), ReportValidate)) };
+    case 202:
+      return { status: 202, body: JobFromJSON(check(await readJson(response), JobValidate)) };
+  }
+  throw new ResponseError(response, 'Response returned an undocumented status');
+}
+
+/** Error responses of getReport, keyed by status */
+export type GetReportErrorResponse =
+  | { status: 404; body: string }
+  | { status: StatusCodes[5]; body: Problem | string }
+  | { status: Exclude<StatusCodes[1 | 3 | 4], 404>; body: any };
+
+/** Error thrown by getReport for non-2xx responses, with the parsed body */
+export class GetReportError extends ResponseError {
+  constructor(response: Response, public readonly payload: GetReportErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<GetReportError> {
+    return new GetReportError(response, await GetReportError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<GetReportErrorResponse> {
+    switch (response.status) {
+      case 404:
+        return { status: 404, body: await response.text() };
+    }
+    if (Math.floor(response.status / 100) === 5) {
+      return { status: response.status as StatusCodes[5], body: await readErrorBody(response, ProblemFromJSON) };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4], 404>, body: await readErrorBody(response) };
+  }
+}
+
+/** API client for reports operations */
+
+export interface ReportsApiInterface {
+  createReportRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<StatusApiResponse<CreateReportResult>>;
+  createReport: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<CreateReportResult>;
+  getReportRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Report>>;
+  getReport: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Report>;
+  deleteReportRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  deleteReport: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  archiveReportRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  archiveReport: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  downloadReportRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<BlobApiResponse>;
+  downloadReport: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Blob>;
+  getReportSummaryRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<TextApiResponse>;
+  getReportSummary: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<string>;
+}
+
+export class ReportsApi extends BaseAPI implements ReportsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async createReportRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<StatusApiResponse<CreateReportResult>> {
+    // Build path with path parameters
+    let urlPath = `/reports`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = undefined;
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new StatusApiResponse(response, (response) => CreateReportResultFromResponse(response, (value, validate) => this.checkResponse(value, validate)));
+  }
+
+  async createReport(initOverrides?: InitOverrideFunction | RequestOptions): Promise<CreateReportResult> {
+    const response = await this.createReportRaw(initOverrides);
+    return await response.value();
+  }
+
+  async getReportRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Report>> {
+    // Build path with path parameters
+    let urlPath = `/reports/${id}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides, GetReportError.fromResponse);
+
+    return new JSONApiResponse(response, (jsonValue) => ReportFromJSON(this.validateBody(ReportValidate, jsonValue)));
+  }
+
+  async getReport(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Report> {
+    const response = await this.getReportRaw(id, initOverrides);
+    return await response.value();
+  }
+
+  async deleteReportRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/reports/${id}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'DELETE',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async deleteReport(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.deleteReportRaw(id, initOverrides);
+    return await response.value();
+  }
+
+  async archiveReportRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/reports/${id}/archive`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = undefined;
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async archiveReport(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.archiveReportRaw(id, initOverrides);
+    return await response.value();
+  }
+
+  async downloadReportRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<BlobApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/reports/${id}/download`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new BlobApiResponse(response);
+  }
+
+  async downloadReport(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Blob> {
+    const response = await this.downloadReportRaw(id, initOverrides);
+    return await response.value();
+  }
+
+  async getReportSummaryRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<TextApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/reports/${id}/summary`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new TextApiResponse(response);
+  }
+
+  async getReportSummary(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<string> {
+    const response = await this.getReportSummaryRaw(id, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
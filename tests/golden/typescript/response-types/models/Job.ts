@@ -0,0 +1,78 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Response Types API
+ *
+ * Text, binary, JSON, empty and multi-status responses
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Job {
+jobId: string, position?: number
+}
+
+export function instanceOfJob(value: object): value is Job {
+    if (!('jobId' in value) || (value as any)['jobId'] === undefined) return false;
+    return true;
+}
+
+export function JobFromJSON(json: any): Job {
+    return JobFromJSONTyped(json, false);
+}
+
+export function JobFromJSONTyped(json: any, ignoreDiscriminator: boolean): Job {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'jobId': json['jobId'],
+        'position': json['position'] ?? undefined,
+    };
+}
+
+export function JobToJSON(value?: Job | null): any {
+    return JobToJSONTyped(value, false);
+}
+
+export function JobToJSONTyped(value?: Job | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'jobId': value['jobId'],
+        'position': value['position'],
+    };
+}
+
+export const JobPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function JobValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['jobId'] === undefined) {
+        violations.push({ path: `${path}.jobId`, message: 'is required' });
+    }
+}
\ No newline at end of file
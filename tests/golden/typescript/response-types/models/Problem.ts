@@ -0,0 +1,82 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Response Types API
+ *
+ * Text, binary, JSON, empty and multi-status responses
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Problem {
+detail?: string,
+title: string,
+traceId?: number
+}
+
+export function instanceOfProblem(value: object): value is Problem {
+    if (!('title' in value) || (value as any)['title'] === undefined) return false;
+    return true;
+}
+
+export function ProblemFromJSON(json: any): Problem {
+    return ProblemFromJSONTyped(json, false);
+}
+
+export function ProblemFromJSONTyped(json: any, ignoreDiscriminator: boolean): Problem {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'detail': json['detail'] ?? undefined,
+        'title': json['title'],
+        'traceId': json['traceId'] ?? undefined,
+    };
+}
+
+export function ProblemToJSON(value?: Problem | null): any {
+    return ProblemToJSONTyped(value, false);
+}
+
+export function ProblemToJSONTyped(value?: Problem | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'detail': value['detail'],
+        'title': value['title'],
+        'traceId': value['traceId'],
+    };
+}
+
+export const ProblemPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function ProblemValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['title'] === undefined) {
+        violations.push({ path: `${path}.title`, message: 'is required' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,141 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Security Schemes API
+ *
+ * API keys, HTTP, OAuth2 and OpenID Connect security with cookie parameters
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, serializeParameter, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+
+/** API client for accounts operations */
+
+export interface AccountsApiInterface {
+  listAccountsRaw: (session?: string, prefs?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  listAccounts: (session?: string, prefs?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  createAccountRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  createAccount: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  deleteAccountRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  deleteAccount: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+}
+
+export class AccountsApi extends BaseAPI implements AccountsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async listAccountsRaw(session?: string, prefs?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/accounts`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Build cookies
+    const cookieParameters: Array<string> = [];
+    if (session !== undefined) {
+      cookieParameters.push(serializeParameter('session', session, 'form', true));
+    }
+    if (prefs !== undefined) {
+      cookieParameters.push(serializeParameter('prefs', prefs, 'form', false));
+    }
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+        cookies: cookieParameters,
+        security: [[{ scheme: 'apiKeyHeader', scopes: [] }]],
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async listAccounts(session?: string, prefs?: Array<string>, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.listAccountsRaw(session, prefs, initOverrides);
+    return await response.value();
+  }
+
+  async createAccountRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/accounts`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = undefined;
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+        security: [[{ scheme: 'oauth', scopes: ['accounts:write', 'accounts:read'] }], [{ scheme: 'openId', scopes: [] }]],
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async createAccount(initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.createAccountRaw(initOverrides);
+    return await response.value();
+  }
+
+  async deleteAccountRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/accounts/${id}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'DELETE',
+        headers: headerParameters,
+        query: queryParameters,
+        security: [[{ scheme: 'apiKeyQuery', scopes: [] }, { scheme: 'basicAuth', scopes: [] }], [{ scheme: 'bearerAuth', scopes: [] }]],
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async deleteAccount(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.deleteAccountRaw(id, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
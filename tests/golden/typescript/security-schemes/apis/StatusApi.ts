@@ -0,0 +1,93 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Security Schemes API
+ *
+ * API keys, HTTP, OAuth2 and OpenID Connect security with cookie parameters
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+
+/** API client for status operations */
+
+export interface StatusApiInterface {
+  getStatusRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  getStatus: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  getStatusDetailsRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  getStatusDetails: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+}
+
+export class StatusApi extends BaseAPI implements StatusApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  async getStatusRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/status`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async getStatus(initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.getStatusRaw(initOverrides);
+    return await response.value();
+  }
+
+  async getStatusDetailsRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/status/details`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+        security: [[], [{ scheme: 'apiKeyCookie', scopes: [] }]],
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  async getStatusDetails(initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.getStatusDetailsRaw(initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from './AccountsApi';
+export * from './StatusApi';
\ No newline at end of file
@@ -11,19 +11,65 @@
  * Do not edit the class manually.
  */
 
-import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction } from '../runtime/runtime';
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, ServerConfiguration, readErrorBody, type StatusCodes, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+
+/**
+ * Servers of listFiles, overriding the API's ones
+ */
+export const listFilesServers = [
+  new ServerConfiguration<{ "bucket": string }>("https://files.example.com/{bucket}", "File storage", { "bucket": "public" }),
+] as const;
+
+/**
+ * Servers of uploadFile, overriding the API's ones
+ */
+export const uploadFileServers = [
+  new ServerConfiguration("https://upload.example.com"),
+] as const;
+
+/** Error responses of getUsers, keyed by status */
+export type GetUsersErrorResponse =
+  | { status: 404; body: any }
+  | { status: Exclude<StatusCodes[1 | 3 | 4 | 5], 404>; body: unknown };
+
+/** Error thrown by getUsers for non-2xx responses, with the parsed body */
+export class GetUsersError extends ResponseError {
+  constructor(response: Response, public readonly payload: GetUsersErrorResponse) {
+    super(response, 'Response returned an error code');
+  }
+
+  static async fromResponse(response: Response): Promise<GetUsersError> {
+    return new GetUsersError(response, await GetUsersError.parse(response));
+  }
+
+  private static async parse(response: Response): Promise<GetUsersErrorResponse> {
+    switch (response.status) {
+      case 404:
+        return { status: 404, body: await readErrorBody(response) };
+    }
+    return { status: response.status as Exclude<StatusCodes[1 | 3 | 4 | 5], 404>, body: await readErrorBody(response) };
+  }
+}
 
 /** API client for default operations */
 
 export interface DefaultApiInterface {
+  /** List files */
+  listFilesRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<string>>>;
+  /** List files */
+  listFiles: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<string>>;
+  /** Upload a file */
+  uploadFileRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  /** Upload a file */
+  uploadFile: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
   /** Get all users */
-  getUsersRaw: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<Array<string>>>;
+  getUsersRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<object>>>;
   /** Get all users */
-  getUsers: (initOverrides?: InitOverrideFunction | RequestInit) => Promise<Array<string>>;
+  getUsers: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<object>>;
   /** Get user by ID */
-  getUsersRaw: (id: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<JSONApiResponse<object>>;
+  getUsersRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<object>>;
   /** Get user by ID */
-  getUsers: (id: string, initOverrides?: InitOverrideFunction | RequestInit) => Promise<object>;
+  getUsers: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<object>;
 }
 
 export class DefaultApi extends BaseAPI implements DefaultApiInterface {
@@ -34,8 +80,78 @@ export class DefaultApi extends BaseAPI implements DefaultApiInterface {
     super(configuration ?? DefaultConfig);
   }
 
+  /** List files */
+  async listFilesRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<string>>> {
+    // Build path with path parameters
+    let urlPath = `/files`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        servers: listFilesServers,
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response);
+  }
+
+  /** List files */
+  async listFiles(initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<string>> {
+    const response = await this.listFilesRaw(initOverrides);
+    return await response.value();
+  }
+
+  /** Upload a file */
+  async uploadFileRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/files`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = undefined;
+
+    // Make request
+    const response = await this.request({
+        servers: uploadFileServers,
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  /** Upload a file */
+  async uploadFile(initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.uploadFileRaw(initOverrides);
+    return await response.value();
+  }
+
   /** Get all users */
-  async getUsersRaw(initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<Array<string>>> {
+  async getUsersRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<object>>> {
     // Build path with path parameters
     let urlPath = `/users`;
 
@@ -61,13 +177,13 @@ export class DefaultApi extends BaseAPI implements DefaultApiInterface {
   }
 
   /** Get all users */
-  async getUsers(initOverrides?: InitOverrideFunction | RequestInit): Promise<Array<string>> {
-      const response = await this.getUsersRaw(initOverrides);
-      return await response.value();
+  async getUsers(initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<object>> {
+    const response = await this.getUsersRaw(initOverrides);
+    return await response.value();
   }
 
   /** Get user by ID */
-  async getUsersRaw(id: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<JSONApiResponse<object>> {
+  async getUsersRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<object>> {
     // Build path with path parameters
     let urlPath = `/users/${id}`;
 
@@ -87,14 +203,14 @@ export class DefaultApi extends BaseAPI implements DefaultApiInterface {
         method: 'GET',
         headers: headerParameters,
         query: queryParameters,
-    }, initOverrides);
+    }, initOverrides, GetUsersError.fromResponse);
 
     return new JSONApiResponse(response);
   }
 
   /** Get user by ID */
-  async getUsers(id: string, initOverrides?: InitOverrideFunction | RequestInit): Promise<object> {
-      const response = await this.getUsersRaw(id, initOverrides);
-      return await response.value();
+  async getUsers(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<object> {
+    const response = await this.getUsersRaw(id, initOverrides);
+    return await response.value();
   }
 }
\ No newline at end of file
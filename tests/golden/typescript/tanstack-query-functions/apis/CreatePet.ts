@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Draft, DraftToJSON, DraftValidate } from '../models/Draft';
+import { type Pet, PetFromJSON, PetValidate } from '../models/Pet';
+
+/** Add a pet */
+export async function createPetRaw(client: Client, body: Draft, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
+  // Build path with path parameters
+  let urlPath = `/pets`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    'Content-Type': 'application/json',
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Prepare request body
+  const requestBody = client.validateBody(DraftValidate, DraftToJSON(body));
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'POST',
+      headers: headerParameters,
+      query: queryParameters,
+      body: requestBody,
+  }, initOverrides);
+
+  return new JSONApiResponse(response, (jsonValue) => PetFromJSON(client.validateBody(PetValidate, jsonValue)));
+}
+
+/** Add a pet */
+export async function createPet(client: Client, body: Draft, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+  const response = await createPetRaw(client, body, initOverrides);
+  return await response.value();
+}
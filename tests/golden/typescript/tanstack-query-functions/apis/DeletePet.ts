@@ -0,0 +1,46 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+
+/** Remove a pet */
+export async function deletePetRaw(client: Client, petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+  // Build path with path parameters
+  let urlPath = `/pets/${petId}`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'DELETE',
+      headers: headerParameters,
+      query: queryParameters,
+  }, initOverrides);
+
+  return new VoidApiResponse(response);
+}
+
+/** Remove a pet */
+export async function deletePet(client: Client, petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+  const response = await deletePetRaw(client, petId, initOverrides);
+  return await response.value();
+}
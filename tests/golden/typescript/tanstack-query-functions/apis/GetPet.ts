@@ -0,0 +1,47 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Pet, PetFromJSON, PetValidate } from '../models/Pet';
+
+/** Get a pet */
+export async function getPetRaw(client: Client, petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
+  // Build path with path parameters
+  let urlPath = `/pets/${petId}`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'GET',
+      headers: headerParameters,
+      query: queryParameters,
+  }, initOverrides);
+
+  return new JSONApiResponse(response, (jsonValue) => PetFromJSON(client.validateBody(PetValidate, jsonValue)));
+}
+
+/** Get a pet */
+export async function getPet(client: Client, petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+  const response = await getPetRaw(client, petId, initOverrides);
+  return await response.value();
+}
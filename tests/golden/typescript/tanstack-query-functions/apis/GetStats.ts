@@ -0,0 +1,47 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Stats, StatsFromJSON, StatsValidate } from '../models/Stats';
+
+/** Count the pets */
+export async function getStatsRaw(client: Client, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Stats>> {
+  // Build path with path parameters
+  let urlPath = `/stats`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'GET',
+      headers: headerParameters,
+      query: queryParameters,
+  }, initOverrides);
+
+  return new JSONApiResponse(response, (jsonValue) => StatsFromJSON(client.validateBody(StatsValidate, jsonValue)));
+}
+
+/** Count the pets */
+export async function getStats(client: Client, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Stats> {
+  const response = await getStatsRaw(client, initOverrides);
+  return await response.value();
+}
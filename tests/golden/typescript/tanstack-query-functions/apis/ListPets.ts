@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, validateItems, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Pet, PetFromJSON, PetValidate } from '../models/Pet';
+
+/** List pets */
+export async function listPetsRaw(client: Client, limit?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Pet>>> {
+  // Build path with path parameters
+  let urlPath = `/pets`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+  if (limit !== undefined) {
+    queryParameters['limit'] = limit;
+  }
+  if (status !== undefined) {
+    queryParameters['status'] = status;
+  }
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'GET',
+      headers: headerParameters,
+      query: queryParameters,
+  }, initOverrides);
+
+  return new JSONApiResponse(response, (jsonValue) => (client.validateBody(validateItems(PetValidate), jsonValue) as Array<any>).map(PetFromJSON));
+}
+
+/** List pets */
+export async function listPets(client: Client, limit?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Pet>> {
+  const response = await listPetsRaw(client, limit, status, initOverrides);
+  return await response.value();
+}
@@ -0,0 +1,53 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { JSONApiResponse, VoidApiResponse, ResponseError, validateItems, type Client, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Criteria, CriteriaToJSON, CriteriaValidate } from '../models/Criteria';
+import { type Pet, PetFromJSON, PetValidate } from '../models/Pet';
+
+/** Search pets by name */
+export async function searchPetsRaw(client: Client, body: Criteria, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Pet>>> {
+  // Build path with path parameters
+  let urlPath = `/pets/search`;
+
+  // Build query parameters
+  const queryParameters: any = {};
+
+  // Build headers
+  const headerParameters: Record<string, string> = {
+    'Content-Type': 'application/json',
+    ...client.configuration?.headers,
+  };
+
+  // Add header parameters
+
+  // Prepare request body
+  const requestBody = client.validateBody(CriteriaValidate, CriteriaToJSON(body));
+
+  // Make request
+  const response = await client.request({
+      path: urlPath,
+      method: 'POST',
+      headers: headerParameters,
+      query: queryParameters,
+      body: requestBody,
+  }, initOverrides);
+
+  return new JSONApiResponse(response, (jsonValue) => (client.validateBody(validateItems(PetValidate), jsonValue) as Array<any>).map(PetFromJSON));
+}
+
+/** Search pets by name */
+export async function searchPets(client: Client, body: Criteria, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Pet>> {
+  const response = await searchPetsRaw(client, body, initOverrides);
+  return await response.value();
+}
@@ -0,0 +1,8 @@
+export * from './CreatePet';
+export * from './DeletePet';
+export * from './GetPet';
+export * from './GetStats';
+export * from './ListPets';
+export * from './RefreshStats';
+export * from './SearchPets';
+export * from './WatchPets';
\ No newline at end of file
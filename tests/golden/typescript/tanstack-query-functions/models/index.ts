@@ -0,0 +1,4 @@
+export * from './Criteria';
+export * from './Draft';
+export * from './Pet';
+export * from './Stats';
\ No newline at end of file
@@ -0,0 +1,177 @@
+// DO NOT EDIT - This file is automatically generated.
+// Any manual changes will be overwritten on the next generation.
+// To make changes, modify the source code and regenerate this file.
+
+import { queryOptions, useMutation, useQuery, type QueryClient, type UseMutationOptions, type UseQueryOptions } from '@tanstack/react-query';
+import { createPet, deletePet, getPet, getStats, listPets, refreshStats, searchPets } from '../apis';
+import type { Client } from '../runtime/runtime';
+import type { Criteria, Draft } from '../models';
+
+/** Parameters of deletePet */
+export interface DeletePetParams {
+  petId: string;
+}
+
+/** Remove a pet */
+export function useDeletePetMutation(
+  client: Client,
+  options?: Omit<UseMutationOptions<Awaited<ReturnType<typeof deletePet>>, Error, DeletePetParams>, 'mutationFn'>,
+) {
+  return useMutation({
+    mutationKey: ['deletePet'],
+    mutationFn: (params: DeletePetParams) => deletePet(client, params.petId),
+    ...options,
+  });
+}
+
+/** Query key of getStats */
+export function getStatsQueryKey() {
+  return ['getStats'] as const;
+}
+
+/** Query options of getStats, for prefetching or `useQueries` */
+export function getStatsQueryOptions(client: Client) {
+  return queryOptions({
+    queryKey: getStatsQueryKey(),
+    queryFn: ({ signal }) => getStats(client, { signal }),
+  });
+}
+
+/** Count the pets */
+export function useGetStatsQuery(
+  client: Client,
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<typeof getStats>>, Error, Awaited<ReturnType<typeof getStats>>, ReturnType<typeof getStatsQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...getStatsQueryOptions(client), ...options });
+}
+
+/** Recompute the statistics */
+export function useRefreshStatsMutation(
+  client: Client,
+  options?: Omit<UseMutationOptions<Awaited<ReturnType<typeof refreshStats>>, Error, void>, 'mutationFn'>,
+) {
+  return useMutation({
+    mutationKey: ['refreshStats'],
+    mutationFn: () => refreshStats(client),
+    ...options,
+  });
+}
+
+/** Parameters of listPets */
+export interface ListPetsParams {
+  limit?: string;
+  status?: string;
+}
+
+/** Query key of listPets */
+export function listPetsQueryKey(params: ListPetsParams = {}) {
+  return ['listPets', params] as const;
+}
+
+/** Query options of listPets, for prefetching or `useQueries` */
+export function listPetsQueryOptions(client: Client, params: ListPetsParams = {}) {
+  return queryOptions({
+    queryKey: listPetsQueryKey(params),
+    queryFn: ({ signal }) => listPets(client, params.limit, params.status, { signal }),
+  });
+}
+
+/** List pets */
+export function useListPetsQuery(
+  client: Client,
+  params: ListPetsParams = {},
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<typeof listPets>>, Error, Awaited<ReturnType<typeof listPets>>, ReturnType<typeof listPetsQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...listPetsQueryOptions(client, params), ...options });
+}
+
+/** Parameters of createPet */
+export interface CreatePetParams {
+  body: Draft;
+}
+
+/** Add a pet */
+export function useCreatePetMutation(
+  client: Client,
+  options?: Omit<UseMutationOptions<Awaited<ReturnType<typeof createPet>>, Error, CreatePetParams>, 'mutationFn'>,
+) {
+  return useMutation({
+    mutationKey: ['createPet'],
+    mutationFn: (params: CreatePetParams) => createPet(client, params.body),
+    ...options,
+  });
+}
+
+/** Parameters of searchPets */
+export interface SearchPetsParams {
+  body: Criteria;
+}
+
+/** Query key of searchPets */
+export function searchPetsQueryKey(params: SearchPetsParams) {
+  return ['searchPets', params] as const;
+}
+
+/** Query options of searchPets, for prefetching or `useQueries` */
+export function searchPetsQueryOptions(client: Client, params: SearchPetsParams) {
+  return queryOptions({
+    queryKey: searchPetsQueryKey(params),
+    queryFn: ({ signal }) => searchPets(client, params.body, { signal }),
+  });
+}
+
+/** Search pets by name */
+export function useSearchPetsQuery(
+  client: Client,
+  params: SearchPetsParams,
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<typeof searchPets>>, Error, Awaited<ReturnType<typeof searchPets>>, ReturnType<typeof searchPetsQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...searchPetsQueryOptions(client, params), ...options });
+}
+
+/** Parameters of getPet */
+export interface GetPetParams {
+  petId: string;
+}
+
+/** Query key of getPet */
+export function getPetQueryKey(params: GetPetParams) {
+  return ['getPet', params] as const;
+}
+
+/** Query options of getPet, for prefetching or `useQueries` */
+export function getPetQueryOptions(client: Client, params: GetPetParams) {
+  return queryOptions({
+    queryKey: getPetQueryKey(params),
+    queryFn: ({ signal }) => getPet(client, params.petId, { signal }),
+  });
+}
+
+/** Get a pet */
+export function useGetPetQuery(
+  client: Client,
+  params: GetPetParams,
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<typeof getPet>>, Error, Awaited<ReturnType<typeof getPet>>, ReturnType<typeof getPetQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...getPetQueryOptions(client, params), ...options });
+}
+
+/** Keys of the queries of the admin operations */
+const ADMIN_QUERY_KEYS: ReadonlyArray<unknown> = ['getStats'];
+
+/** Invalidate the cached queries of the admin operations, e.g. after a mutation */
+export function invalidateAdminQueries(queryClient: QueryClient): Promise<void> {
+  return queryClient.invalidateQueries({
+    predicate: (query) => ADMIN_QUERY_KEYS.includes(query.queryKey[0]),
+  });
+}
+
+/** Keys of the queries of the pets operations */
+const PETS_QUERY_KEYS: ReadonlyArray<unknown> = ['listPets', 'searchPets', 'getPet'];
+
+/** Invalidate the cached queries of the pets operations, e.g. after a mutation */
+export function invalidatePetsQueries(queryClient: QueryClient): Promise<void> {
+  return queryClient.invalidateQueries({
+    predicate: (query) => PETS_QUERY_KEYS.includes(query.queryKey[0]),
+  });
+}
@@ -0,0 +1,137 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Stats, StatsFromJSON, StatsValidate } from '../models/Stats';
+
+/** API client for admin operations */
+
+export interface AdminApiInterface {
+  /** Remove a pet */
+  deletePetRaw: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  /** Remove a pet */
+  deletePet: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+  /** Count the pets */
+  getStatsRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Stats>>;
+  /** Count the pets */
+  getStats: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Stats>;
+  /** Recompute the statistics */
+  refreshStatsRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Stats>>;
+  /** Recompute the statistics */
+  refreshStats: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Stats>;
+}
+
+export class AdminApi extends BaseAPI implements AdminApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** Remove a pet */
+  async deletePetRaw(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/pets/${petId}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'DELETE',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  /** Remove a pet */
+  async deletePet(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.deletePetRaw(petId, initOverrides);
+    return await response.value();
+  }
+
+  /** Count the pets */
+  async getStatsRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Stats>> {
+    // Build path with path parameters
+    let urlPath = `/stats`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => StatsFromJSON(this.validateBody(StatsValidate, jsonValue)));
+  }
+
+  /** Count the pets */
+  async getStats(initOverrides?: InitOverrideFunction | RequestOptions): Promise<Stats> {
+    const response = await this.getStatsRaw(initOverrides);
+    return await response.value();
+  }
+
+  /** Recompute the statistics */
+  async refreshStatsRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Stats>> {
+    // Build path with path parameters
+    let urlPath = `/stats/refresh`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => StatsFromJSON(this.validateBody(StatsValidate, jsonValue)));
+  }
+
+  /** Recompute the statistics */
+  async refreshStats(initOverrides?: InitOverrideFunction | RequestOptions): Promise<Stats> {
+    const response = await this.refreshStatsRaw(initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
@@ -0,0 +1,263 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, EventStreamApiResponse, ResponseError, validateItems, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Criteria, CriteriaToJSON, CriteriaValidate } from '../models/Criteria';
+import { type Draft, DraftToJSON, DraftValidate } from '../models/Draft';
+import { type Pet, PetFromJSON, PetValidate } from '../models/Pet';
+
+/** API client for pets operations */
+
+export interface PetsApiInterface {
+  /** List pets */
+  listPetsRaw: (limit?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Pet>>>;
+  /** List pets */
+  listPets: (limit?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Pet>>;
+  /** Add a pet */
+  createPetRaw: (body: Draft, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Pet>>;
+  /** Add a pet */
+  createPet: (body: Draft, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Pet>;
+  /** Follow the changes of the pets */
+  watchPetsRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<EventStreamApiResponse<Pet>>;
+  /** Follow the changes of the pets */
+  watchPets: (initOverrides?: InitOverrideFunction | RequestOptions) => AsyncIterable<Pet>;
+  /** Search pets by name */
+  searchPetsRaw: (body: Criteria, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Pet>>>;
+  /** Search pets by name */
+  searchPets: (body: Criteria, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Pet>>;
+  /** Get a pet */
+  getPetRaw: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Pet>>;
+  /** Get a pet */
+  getPet: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Pet>;
+  /** Remove a pet */
+  deletePetRaw: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<VoidApiResponse>;
+  /** Remove a pet */
+  deletePet: (petId: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<void>;
+}
+
+export class PetsApi extends BaseAPI implements PetsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** List pets */
+  async listPetsRaw(limit?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Pet>>> {
+    // Build path with path parameters
+    let urlPath = `/pets`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+    if (limit !== undefined) {
+      queryParameters['limit'] = limit;
+    }
+    if (status !== undefined) {
+      queryParameters['status'] = status;
+    }
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(PetValidate), jsonValue) as Array<any>).map(PetFromJSON));
+  }
+
+  /** List pets */
+  async listPets(limit?: string, status?: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Pet>> {
+    const response = await this.listPetsRaw(limit, status, initOverrides);
+    return await response.value();
+  }
+
+  /** Add a pet */
+  async createPetRaw(body: Draft, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
+    // Build path with path parameters
+    let urlPath = `/pets`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Content-Type': 'application/json',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = this.validateBody(DraftValidate, DraftToJSON(body));
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(this.validateBody(PetValidate, jsonValue)));
+  }
+
+  /** Add a pet */
+  async createPet(body: Draft, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+    const response = await this.createPetRaw(body, initOverrides);
+    return await response.value();
+  }
+
+  /** Follow the changes of the pets */
+  async watchPetsRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<EventStreamApiResponse<Pet>> {
+    // Build path with path parameters
+    let urlPath = `/pets/events`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Accept': 'text/event-stream',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new EventStreamApiResponse(response, (jsonValue) => PetFromJSON(this.validateBody(PetValidate, jsonValue)));
+  }
+
+  /** Follow the changes of the pets */
+  async *watchPets(initOverrides?: InitOverrideFunction | RequestOptions): AsyncIterable<Pet> {
+    yield* this.readEventStream((api) => api.watchPetsRaw(initOverrides), initOverrides);
+  }
+
+  /** Search pets by name */
+  async searchPetsRaw(body: Criteria, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Pet>>> {
+    // Build path with path parameters
+    let urlPath = `/pets/search`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      'Content-Type': 'application/json',
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Prepare request body
+    const requestBody = this.validateBody(CriteriaValidate, CriteriaToJSON(body));
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'POST',
+        headers: headerParameters,
+        query: queryParameters,
+        body: requestBody,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(PetValidate), jsonValue) as Array<any>).map(PetFromJSON));
+  }
+
+  /** Search pets by name */
+  async searchPets(body: Criteria, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Pet>> {
+    const response = await this.searchPetsRaw(body, initOverrides);
+    return await response.value();
+  }
+
+  /** Get a pet */
+  async getPetRaw(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Pet>> {
+    // Build path with path parameters
+    let urlPath = `/pets/${petId}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => PetFromJSON(this.validateBody(PetValidate, jsonValue)));
+  }
+
+  /** Get a pet */
+  async getPet(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Pet> {
+    const response = await this.getPetRaw(petId, initOverrides);
+    return await response.value();
+  }
+
+  /** Remove a pet */
+  async deletePetRaw(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<VoidApiResponse> {
+    // Build path with path parameters
+    let urlPath = `/pets/${petId}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'DELETE',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new VoidApiResponse(response);
+  }
+
+  /** Remove a pet */
+  async deletePet(petId: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<void> {
+    const response = await this.deletePetRaw(petId, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
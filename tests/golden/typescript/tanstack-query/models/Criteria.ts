@@ -0,0 +1,72 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Criteria {
+name?: string
+}
+
+export function instanceOfCriteria(value: object): value is Criteria {
+    return true;
+}
+
+export function CriteriaFromJSON(json: any): Criteria {
+    return CriteriaFromJSONTyped(json, false);
+}
+
+export function CriteriaFromJSONTyped(json: any, ignoreDiscriminator: boolean): Criteria {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'name': json['name'] ?? undefined,
+    };
+}
+
+export function CriteriaToJSON(value?: Criteria | null): any {
+    return CriteriaToJSONTyped(value, false);
+}
+
+export function CriteriaToJSONTyped(value?: Criteria | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'name': value['name'],
+    };
+}
+
+export const CriteriaPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function CriteriaValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+}
\ No newline at end of file
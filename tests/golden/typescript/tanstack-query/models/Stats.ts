@@ -0,0 +1,76 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * TanStack Query API
+ *
+ * Operations wrapped in TanStack Query hooks
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface Stats {
+count: number
+}
+
+export function instanceOfStats(value: object): value is Stats {
+    if (!('count' in value) || (value as any)['count'] === undefined) return false;
+    return true;
+}
+
+export function StatsFromJSON(json: any): Stats {
+    return StatsFromJSONTyped(json, false);
+}
+
+export function StatsFromJSONTyped(json: any, ignoreDiscriminator: boolean): Stats {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'count': json['count'],
+    };
+}
+
+export function StatsToJSON(value?: Stats | null): any {
+    return StatsToJSONTyped(value, false);
+}
+
+export function StatsToJSONTyped(value?: Stats | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'count': value['count'],
+    };
+}
+
+export const StatsPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function StatsValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['count'] === undefined) {
+        violations.push({ path: `${path}.count`, message: 'is required' });
+    }
+}
\ No newline at end of file
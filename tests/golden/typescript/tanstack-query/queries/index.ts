@@ -0,0 +1,176 @@
+// DO NOT EDIT - This file is automatically generated.
+// Any manual changes will be overwritten on the next generation.
+// To make changes, modify the source code and regenerate this file.
+
+import { queryOptions, useMutation, useQuery, type QueryClient, type UseMutationOptions, type UseQueryOptions } from '@tanstack/react-query';
+import type { AdminApi, PetsApi } from '../apis';
+import type { Criteria, Draft } from '../models';
+
+/** Parameters of deletePet */
+export interface DeletePetParams {
+  petId: string;
+}
+
+/** Remove a pet */
+export function useDeletePetMutation(
+  api: PetsApi,
+  options?: Omit<UseMutationOptions<Awaited<ReturnType<PetsApi['deletePet']>>, Error, DeletePetParams>, 'mutationFn'>,
+) {
+  return useMutation({
+    mutationKey: ['deletePet'],
+    mutationFn: (params: DeletePetParams) => api.deletePet(params.petId),
+    ...options,
+  });
+}
+
+/** Query key of getStats */
+export function getStatsQueryKey() {
+  return ['getStats'] as const;
+}
+
+/** Query options of getStats, for prefetching or `useQueries` */
+export function getStatsQueryOptions(api: AdminApi) {
+  return queryOptions({
+    queryKey: getStatsQueryKey(),
+    queryFn: ({ signal }) => api.getStats({ signal }),
+  });
+}
+
+/** Count the pets */
+export function useGetStatsQuery(
+  api: AdminApi,
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<AdminApi['getStats']>>, Error, Awaited<ReturnType<AdminApi['getStats']>>, ReturnType<typeof getStatsQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...getStatsQueryOptions(api), ...options });
+}
+
+/** Recompute the statistics */
+export function useRefreshStatsMutation(
+  api: AdminApi,
+  options?: Omit<UseMutationOptions<Awaited<ReturnType<AdminApi['refreshStats']>>, Error, void>, 'mutationFn'>,
+) {
+  return useMutation({
+    mutationKey: ['refreshStats'],
+    mutationFn: () => api.refreshStats(),
+    ...options,
+  });
+}
+
+/** Parameters of listPets */
+export interface ListPetsParams {
+  limit?: string;
+  status?: string;
+}
+
+/** Query key of listPets */
+export function listPetsQueryKey(params: ListPetsParams = {}) {
+  return ['listPets', params] as const;
+}
+
+/** Query options of listPets, for prefetching or `useQueries` */
+export function listPetsQueryOptions(api: PetsApi, params: ListPetsParams = {}) {
+  return queryOptions({
+    queryKey: listPetsQueryKey(params),
+    queryFn: ({ signal }) => api.listPets(params.limit, params.status, { signal }),
+  });
+}
+
+/** List pets */
+export function useListPetsQuery(
+  api: PetsApi,
+  params: ListPetsParams = {},
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<PetsApi['listPets']>>, Error, Awaited<ReturnType<PetsApi['listPets']>>, ReturnType<typeof listPetsQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...listPetsQueryOptions(api, params), ...options });
+}
+
+/** Parameters of createPet */
+export interface CreatePetParams {
+  body: Draft;
+}
+
+/** Add a pet */
+export function useCreatePetMutation(
+  api: PetsApi,
+  options?: Omit<UseMutationOptions<Awaited<ReturnType<PetsApi['createPet']>>, Error, CreatePetParams>, 'mutationFn'>,
+) {
+  return useMutation({
+    mutationKey: ['createPet'],
+    mutationFn: (params: CreatePetParams) => api.createPet(params.body),
+    ...options,
+  });
+}
+
+/** Parameters of searchPets */
+export interface SearchPetsParams {
+  body: Criteria;
+}
+
+/** Query key of searchPets */
+export function searchPetsQueryKey(params: SearchPetsParams) {
+  return ['searchPets', params] as const;
+}
+
+/** Query options of searchPets, for prefetching or `useQueries` */
+export function searchPetsQueryOptions(api: PetsApi, params: SearchPetsParams) {
+  return queryOptions({
+    queryKey: searchPetsQueryKey(params),
+    queryFn: ({ signal }) => api.searchPets(params.body, { signal }),
+  });
+}
+
+/** Search pets by name */
+export function useSearchPetsQuery(
+  api: PetsApi,
+  params: SearchPetsParams,
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<PetsApi['searchPets']>>, Error, Awaited<ReturnType<PetsApi['searchPets']>>, ReturnType<typeof searchPetsQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...searchPetsQueryOptions(api, params), ...options });
+}
+
+/** Parameters of getPet */
+export interface GetPetParams {
+  petId: string;
+}
+
+/** Query key of getPet */
+export function getPetQueryKey(params: GetPetParams) {
+  return ['getPet', params] as const;
+}
+
+/** Query options of getPet, for prefetching or `useQueries` */
+export function getPetQueryOptions(api: PetsApi, params: GetPetParams) {
+  return queryOptions({
+    queryKey: getPetQueryKey(params),
+    queryFn: ({ signal }) => api.getPet(params.petId, { signal }),
+  });
+}
+
+/** Get a pet */
+export function useGetPetQuery(
+  api: PetsApi,
+  params: GetPetParams,
+  options?: Omit<UseQueryOptions<Awaited<ReturnType<PetsApi['getPet']>>, Error, Awaited<ReturnType<PetsApi['getPet']>>, ReturnType<typeof getPetQueryKey>>, 'queryKey' | 'queryFn'>,
+) {
+  return useQuery({ ...getPetQueryOptions(api, params), ...options });
+}
+
+/** Keys of the queries of the admin operations */
+const ADMIN_QUERY_KEYS: ReadonlyArray<unknown> = ['getStats'];
+
+/** Invalidate the cached queries of the admin operations, e.g. after a mutation */
+export function invalidateAdminQueries(queryClient: QueryClient): Promise<void> {
+  return queryClient.invalidateQueries({
+    predicate: (query) => ADMIN_QUERY_KEYS.includes(query.queryKey[0]),
+  });
+}
+
+/** Keys of the queries of the pets operations */
+const PETS_QUERY_KEYS: ReadonlyArray<unknown> = ['listPets', 'searchPets', 'getPet'];
+
+/** Invalidate the cached queries of the pets operations, e.g. after a mutation */
+export function invalidatePetsQueries(queryClient: QueryClient): Promise<void> {
+  return queryClient.invalidateQueries({
+    predicate: (query) => PETS_QUERY_KEYS.includes(query.queryKey[0]),
+  });
+}
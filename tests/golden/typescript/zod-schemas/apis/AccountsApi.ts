@@ -0,0 +1,102 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, validateItems, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Account, AccountFromJSON, AccountValidate } from '../models/Account';
+import { AccountSchema } from '../schemas';
+
+/** API client for accounts operations */
+
+export interface AccountsApiInterface {
+  /** List accounts */
+  listAccountsRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Array<Account>>>;
+  /** List accounts */
+  listAccounts: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Array<Account>>;
+  /** Get an account */
+  getAccountRaw: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Account>>;
+  /** Get an account */
+  getAccount: (id: string, initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Account>;
+}
+
+export class AccountsApi extends BaseAPI implements AccountsApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** List accounts */
+  async listAccountsRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Array<Account>>> {
+    // Build path with path parameters
+    let urlPath = `/accounts`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => (this.validateBody(validateItems(AccountValidate), this.validateResponse(AccountSchema.array(), jsonValue)) as Array<any>).map(AccountFromJSON));
+  }
+
+  /** List accounts */
+  async listAccounts(initOverrides?: InitOverrideFunction | RequestOptions): Promise<Array<Account>> {
+    const response = await this.listAccountsRaw(initOverrides);
+    return await response.value();
+  }
+
+  /** Get an account */
+  async getAccountRaw(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Account>> {
+    // Build path with path parameters
+    let urlPath = `/accounts/${id}`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => AccountFromJSON(this.validateBody(AccountValidate, this.validateResponse(AccountSchema, jsonValue))));
+  }
+
+  /** Get an account */
+  async getAccount(id: string, initOverrides?: InitOverrideFunction | RequestOptions): Promise<Account> {
+    const response = await this.getAccountRaw(id, initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
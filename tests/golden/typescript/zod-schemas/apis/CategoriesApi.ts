@@ -0,0 +1,66 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { BaseAPI, JSONApiResponse, VoidApiResponse, ResponseError, type Configuration, type InitOverrideFunction, type RequestOptions } from '../runtime/runtime';
+import { type Category, CategoryFromJSON, CategoryValidate } from '../models/Category';
+import { CategorySchema } from '../schemas';
+
+/** API client for categories operations */
+
+export interface CategoriesApiInterface {
+  /** Get the category tree */
+  getCategoriesRaw: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<JSONApiResponse<Category>>;
+  /** Get the category tree */
+  getCategories: (initOverrides?: InitOverrideFunction | RequestOptions) => Promise<Category>;
+}
+
+export class CategoriesApi extends BaseAPI implements CategoriesApiInterface {
+
+  /** Initialize the API client */
+  constructor(configuration?: Configuration) {
+    // Call BaseAPI constructor with provided configuration or default
+    super(configuration ?? DefaultConfig);
+  }
+
+  /** Get the category tree */
+  async getCategoriesRaw(initOverrides?: InitOverrideFunction | RequestOptions): Promise<JSONApiResponse<Category>> {
+    // Build path with path parameters
+    let urlPath = `/categories`;
+
+    // Build query parameters
+    const queryParameters: any = {};
+
+    // Build headers
+    const headerParameters: Record<string, string> = {
+      ...this.configuration?.headers,
+    };
+
+    // Add header parameters
+
+    // Make request
+    const response = await this.request({
+        path: urlPath,
+        method: 'GET',
+        headers: headerParameters,
+        query: queryParameters,
+    }, initOverrides);
+
+    return new JSONApiResponse(response, (jsonValue) => CategoryFromJSON(this.validateBody(CategoryValidate, this.validateResponse(CategorySchema, jsonValue))));
+  }
+
+  /** Get the category tree */
+  async getCategories(initOverrides?: InitOverrideFunction | RequestOptions): Promise<Category> {
+    const response = await this.getCategoriesRaw(initOverrides);
+    return await response.value();
+  }
+}
\ No newline at end of file
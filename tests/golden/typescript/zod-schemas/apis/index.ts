@@ -0,0 +1,2 @@
+export * from './AccountsApi';
+export * from './CategoriesApi';
\ No newline at end of file
@@ -0,0 +1,132 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { validateProperty, type ValidationViolation } from '../runtime/runtime';
+import { AccountStatus } from './AccountStatus';
+import { PaymentMethod, PaymentMethodValidate } from './PaymentMethod';
+
+export interface Account {
+age?: number,
+balance?: number,
+birthday?: string,
+createdAt: string,
+email: string,
+handle?: string,
+id: string,
+paymentMethod?: PaymentMethod,
+settings?: any,
+status: AccountStatus,
+tags?: Array<string>,
+website?: string | null
+}
+
+export function instanceOfAccount(value: object): value is Account {
+    if (!('createdAt' in value) || (value as any)['createdAt'] === undefined) return false;
+    if (!('email' in value) || (value as any)['email'] === undefined) return false;
+    if (!('id' in value) || (value as any)['id'] === undefined) return false;
+    if (!('status' in value) || (value as any)['status'] === undefined) return false;
+    return true;
+}
+
+export function AccountFromJSON(json: any): Account {
+    return AccountFromJSONTyped(json, false);
+}
+
+export function AccountFromJSONTyped(json: any, ignoreDiscriminator: boolean): Account {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'age': json['age'] ?? undefined,
+        'balance': json['balance'] ?? undefined,
+        'birthday': json['birthday'] ?? undefined,
+        'createdAt': json['createdAt'],
+        'email': json['email'],
+        'handle': json['handle'] ?? undefined,
+        'id': json['id'],
+        'paymentMethod': json['paymentMethod'] ?? undefined,
+        'settings': json['settings'] ?? undefined,
+        'status': json['status'],
+        'tags': json['tags'] ?? undefined,
+        'website': json['website'],
+    };
+}
+
+export function AccountToJSON(value?: Account | null): any {
+    return AccountToJSONTyped(value, false);
+}
+
+export function AccountToJSONTyped(value?: Account | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'age': value['age'],
+        'balance': value['balance'],
+        'birthday': value['birthday'],
+        'createdAt': value['createdAt'],
+        'email': value['email'],
+        'handle': value['handle'],
+        'id': value['id'],
+        'paymentMethod': value['paymentMethod'],
+        'settings': value['settings'],
+        'status': value['status'],
+        'tags': value['tags'],
+        'website': value['website'],
+    };
+}
+
+export const AccountPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+    'age': { maximum: 150, minimum: 0, exclusiveMinimum: true },
+    'balance': { minimum: 0, multipleOf: 0.01 },
+    'handle': { maxLength: 24, minLength: 3, pattern: "^[a-z0-9_]+$" },
+    'tags': { maxItems: 10 },
+};
+
+export function AccountValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['createdAt'] === undefined) {
+        violations.push({ path: `${path}.createdAt`, message: 'is required' });
+    }
+    if (value['email'] === undefined) {
+        violations.push({ path: `${path}.email`, message: 'is required' });
+    }
+    if (value['id'] === undefined) {
+        violations.push({ path: `${path}.id`, message: 'is required' });
+    }
+    if (value['status'] === undefined) {
+        violations.push({ path: `${path}.status`, message: 'is required' });
+    }
+    validateProperty(value['age'], AccountPropertyValidationAttributesMap['age'], `${path}.age`, violations);
+    validateProperty(value['balance'], AccountPropertyValidationAttributesMap['balance'], `${path}.balance`, violations);
+    validateProperty(value['handle'], AccountPropertyValidationAttributesMap['handle'], `${path}.handle`, violations);
+    validateProperty(value['paymentMethod'], AccountPropertyValidationAttributesMap['paymentMethod'], `${path}.paymentMethod`, violations, PaymentMethodValidate);
+    validateProperty(value['tags'], AccountPropertyValidationAttributesMap['tags'], `${path}.tags`, violations);
+}
\ No newline at end of file
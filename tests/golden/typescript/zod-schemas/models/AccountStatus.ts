@@ -0,0 +1,18 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+export enum AccountStatus {
+Active = "active",
+Suspended = "suspended",
+Closed = "closed"
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+
+export interface BankTransfer {
+iban: string, kind: "bank"
+}
+
+export function instanceOfBankTransfer(value: object): value is BankTransfer {
+    if (!('iban' in value) || (value as any)['iban'] === undefined) return false;
+    if (!('kind' in value) || (value as any)['kind'] === undefined) return false;
+    return true;
+}
+
+export function BankTransferFromJSON(json: any): BankTransfer {
+    return BankTransferFromJSONTyped(json, false);
+}
+
+export function BankTransferFromJSONTyped(json: any, ignoreDiscriminator: boolean): BankTransfer {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'iban': json['iban'],
+        'kind': json['kind'],
+    };
+}
+
+export function BankTransferToJSON(value?: BankTransfer | null): any {
+    return BankTransferToJSONTyped(value, false);
+}
+
+export function BankTransferToJSONTyped(value?: BankTransfer | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'iban': value['iban'],
+        'kind': value['kind'],
+    };
+}
+
+export const BankTransferPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+};
+
+export function BankTransferValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['iban'] === undefined) {
+        violations.push({ path: `${path}.iban`, message: 'is required' });
+    }
+    if (value['kind'] === undefined) {
+        violations.push({ path: `${path}.kind`, message: 'is required' });
+    }
+}
\ No newline at end of file
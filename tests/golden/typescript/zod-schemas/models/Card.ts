@@ -0,0 +1,84 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { validateProperty, type ValidationViolation } from '../runtime/runtime';
+
+export interface Card {
+kind: "card", last4: string
+}
+
+export function instanceOfCard(value: object): value is Card {
+    if (!('kind' in value) || (value as any)['kind'] === undefined) return false;
+    if (!('last4' in value) || (value as any)['last4'] === undefined) return false;
+    return true;
+}
+
+export function CardFromJSON(json: any): Card {
+    return CardFromJSONTyped(json, false);
+}
+
+export function CardFromJSONTyped(json: any, ignoreDiscriminator: boolean): Card {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'kind': json['kind'],
+        'last4': json['last4'],
+    };
+}
+
+export function CardToJSON(value?: Card | null): any {
+    return CardToJSONTyped(value, false);
+}
+
+export function CardToJSONTyped(value?: Card | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'kind': value['kind'],
+        'last4': value['last4'],
+    };
+}
+
+export const CardPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+    'last4': { pattern: "^\\d{4}$" },
+};
+
+export function CardValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['kind'] === undefined) {
+        violations.push({ path: `${path}.kind`, message: 'is required' });
+    }
+    if (value['last4'] === undefined) {
+        violations.push({ path: `${path}.last4`, message: 'is required' });
+    }
+    validateProperty(value['last4'], CardPropertyValidationAttributesMap['last4'], `${path}.last4`, violations);
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { validateProperty, type ValidationViolation } from '../runtime/runtime';
+
+export interface Category {
+children: Array<{ children: Array<Category>; name: string; parent: null | Category }>,
+name: string,
+parent?: null | { children: Array<Category>; name: string; parent: null | Category }
+}
+
+export function instanceOfCategory(value: object): value is Category {
+    if (!('children' in value) || (value as any)['children'] === undefined) return false;
+    if (!('name' in value) || (value as any)['name'] === undefined) return false;
+    return true;
+}
+
+export function CategoryFromJSON(json: any): Category {
+    return CategoryFromJSONTyped(json, false);
+}
+
+export function CategoryFromJSONTyped(json: any, ignoreDiscriminator: boolean): Category {
+    if (json == null) {
+        return json;
+    }
+    return {
+        'children': json['children'],
+        'name': json['name'],
+        'parent': json['parent'],
+    };
+}
+
+export function CategoryToJSON(value?: Category | null): any {
+    return CategoryToJSONTyped(value, false);
+}
+
+export function CategoryToJSONTyped(value?: Category | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    return {
+        'children': value['children'],
+        'name': value['name'],
+        'parent': value['parent'],
+    };
+}
+
+export const CategoryPropertyValidationAttributesMap: {
+    [property: string]: {
+        maxLength?: number,
+        minLength?: number,
+        pattern?: string,
+        maximum?: number,
+        exclusiveMaximum?: boolean,
+        minimum?: number,
+        exclusiveMinimum?: boolean,
+        multipleOf?: number,
+        maxItems?: number,
+        minItems?: number,
+        uniqueItems?: boolean
+    }
+} = {
+    'name': { minLength: 1 },
+};
+
+export function CategoryValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+        violations.push({ path, message: 'must be an object' });
+        return;
+    }
+    if (value['children'] === undefined) {
+        violations.push({ path: `${path}.children`, message: 'is required' });
+    }
+    if (value['name'] === undefined) {
+        violations.push({ path: `${path}.name`, message: 'is required' });
+    }
+    validateProperty(value['name'], CategoryPropertyValidationAttributesMap['name'], `${path}.name`, violations);
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * Zod Schemas API
+ *
+ * API whose components are validated with Zod schemas
+ *
+ * The version of the OpenAPI document: 1.0.0
+ *
+ * NOTE: This class is auto generated by openapi-nexus.
+ * Do not edit the class manually.
+ */
+
+import { type ValidationViolation } from '../runtime/runtime';
+import { BankTransfer, BankTransferFromJSONTyped, BankTransferToJSON, instanceOfBankTransfer, BankTransferValidate } from './BankTransfer';
+import { Card, CardFromJSONTyped, CardToJSON, instanceOfCard, CardValidate } from './Card';
+
+export type PaymentMethod = BankTransfer | Card
+
+export function instanceOfPaymentMethod(value: object): value is PaymentMethod {
+    switch ((value as any)['kind']) {
+        case "bank":
+            return instanceOfBankTransfer(value);
+        case "card":
+            return instanceOfCard(value);
+        default:
+            return false;
+    }
+}
+
+export function PaymentMethodFromJSON(json: any): PaymentMethod {
+    return PaymentMethodFromJSONTyped(json, false);
+}
+
+export function PaymentMethodFromJSONTyped(json: any, ignoreDiscriminator: boolean): PaymentMethod {
+    if (json == null) {
+        return json;
+    }
+    switch (json['kind']) {
+        case "bank":
+            return Object.assign({}, BankTransferFromJSONTyped(json, true), { 'kind': "bank" } as const);
+        case "card":
+            return Object.assign({}, CardFromJSONTyped(json, true), { 'kind': "card" } as const);
+        default:
+            return json;
+    }
+}
+
+export function PaymentMethodToJSON(value?: PaymentMethod | null): any {
+    return PaymentMethodToJSONTyped(value, false);
+}
+
+export function PaymentMethodToJSONTyped(value?: PaymentMethod | null, ignoreDiscriminator: boolean = false): any {
+    if (value == null) {
+        return value;
+    }
+    switch (value['kind']) {
+        case "bank":
+            return Object.assign({}, BankTransferToJSON(value), { 'kind': "bank" } as const);
+        case "card":
+            return Object.assign({}, CardToJSON(value), { 'kind': "card" } as const);
+        default:
+            return value;
+    }
+}
+
+export function PaymentMethodValidate(value: any, path: string, violations: ValidationViolation[]): void {
+    switch (value?.['kind']) {
+        case "bank":
+            return BankTransferValidate(value, path, violations);
+        case "card":
+            return CardValidate(value, path, violations);
+        default:
+            violations.push({ path: `${path}.kind`, message: 'does not select a member of PaymentMethod' });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+export * from './Account';
+export * from './AccountInput';
+export * from './AccountStatus';
+export * from './BankTransfer';
+export * from './Card';
+export * from './Category';
+export * from './PaymentMethod';
\ No newline at end of file
@@ -0,0 +1,57 @@
+// DO NOT EDIT - This file is automatically generated.
+// Any manual changes will be overwritten on the next generation.
+// To make changes, modify the source code and regenerate this file.
+
+import { z } from 'zod';
+
+export const AccountStatusSchema = z.enum(["active", "suspended", "closed"]);
+
+export type AccountStatus = z.infer<typeof AccountStatusSchema>;
+
+export const BankTransferSchema = z.object({
+  iban: z.string(),
+  kind: z.string(),
+});
+
+export type BankTransfer = z.infer<typeof BankTransferSchema>;
+
+export const CardSchema = z.object({
+  kind: z.string(),
+  last4: z.string().regex(new RegExp("^\\d{4}$")),
+});
+
+export type Card = z.infer<typeof CardSchema>;
+
+export const PaymentMethodSchema = z.discriminatedUnion("kind", [BankTransferSchema.extend({ kind: z.literal("bank") }), CardSchema.extend({ kind: z.literal("card") })]);
+
+export type PaymentMethod = z.infer<typeof PaymentMethodSchema>;
+
+export const AccountSchema = z.object({
+  age: z.number().int().lte(150).gt(0).optional(),
+  balance: z.number().gte(0).multipleOf(0.01).optional(),
+  birthday: z.string().date().optional(),
+  createdAt: z.string().datetime({ offset: true }),
+  email: z.string().email(),
+  handle: z.string().min(3).max(24).regex(new RegExp("^[a-z0-9_]+$")).optional(),
+  id: z.string().uuid(),
+  password: z.string().optional(),
+  paymentMethod: PaymentMethodSchema.optional(),
+  settings: z.record(z.string(), z.boolean()).optional(),
+  status: AccountStatusSchema,
+  tags: z.array(z.string()).max(10).optional(),
+  website: z.string().url().nullable().optional(),
+});
+
+export type Account = z.infer<typeof AccountSchema>;
+
+export type Category = {
+  children: Array<Category>;
+  name: string;
+  parent?: Category | null;
+};
+
+export const CategorySchema: z.ZodType<Category> = z.lazy(() => z.object({
+  children: z.array(CategorySchema),
+  name: z.string().min(1),
+  parent: z.union([CategorySchema, z.null()]).optional(),
+}));